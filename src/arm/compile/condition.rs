@@ -1,9 +1,51 @@
-use crate::{Arm, ClassPattern, InputStatePattern, Pattern, SequencePattern};
+use super::compile_byte_ranges;
+use super::utf8::compile_utf8_pattern;
+use crate::{
+    Arm, ClassPattern, InputStatePattern, MaskPattern, NamedClass, Pattern, SequenceElement,
+    SequencePattern,
+};
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::quote;
-use syn::{Ident, IntSuffix, LitInt};
+use syn::{Ident, IntSuffix, LitByteStr, LitInt};
 
-fn compile_class_pattern(pattern: ClassPattern) -> TokenStream2 {
+// NOTE: sorted sets of bytes are compiled into the ranges of consecutive bytes.
+fn compile_byte_set(bytes: impl IntoIterator<Item = u8>) -> TokenStream2 {
+    let mut ranges: Vec<(u8, u8)> = vec![];
+
+    for b in bytes {
+        match ranges.last_mut() {
+            Some(range) if range.1.checked_add(1) == Some(b) => range.1 = b,
+            _ => ranges.push((b, b)),
+        }
+    }
+
+    compile_byte_ranges(&ranges)
+}
+
+fn compile_named_class_pattern(class: &NamedClass) -> TokenStream2 {
+    compile_byte_set(class.bytes.iter().copied())
+}
+
+// NOTE: masks are compiled into the ranges if there are just a few of them (e.g.
+// `mask("110x_xxxx")` is `0xC0..=0xDF`), otherwise the byte is masked in the guard.
+const MAX_MASK_RANGES: usize = 4;
+
+fn compile_mask_pattern(pattern: MaskPattern) -> TokenStream2 {
+    let range_count = (0..=255u8)
+        .filter(|&b| pattern.matches(b) && (b == 0 || !pattern.matches(b - 1)))
+        .count();
+
+    if range_count <= MAX_MASK_RANGES {
+        compile_byte_set((0..=255u8).filter(|&b| pattern.matches(b)))
+    } else {
+        let mask = LitInt::new(pattern.mask.into(), IntSuffix::U8, Span::call_site());
+        let value = LitInt::new(pattern.value.into(), IntSuffix::U8, Span::call_site());
+
+        quote! { Some(b) if b & #mask == #value }
+    }
+}
+
+fn compile_class_pattern(pattern: &ClassPattern) -> TokenStream2 {
     use ClassPattern::*;
 
     match pattern {
@@ -14,9 +56,20 @@ fn compile_class_pattern(pattern: ClassPattern) -> TokenStream2 {
         Upper => quote! { Some(b'A'..=b'Z') },
         Digit => quote! { Some(b'0'..=b'9') },
         Xdigit => quote! { Some(b'0'..=b'9') | Some(b'a'..=b'f') | Some(b'A'..=b'F') },
+        Odigit => quote! { Some(b'0'..=b'7') },
+        Bdigit => quote! { Some(b'0') | Some(b'1') },
         Space => {
             quote! { Some(b' ') | Some(b'\n') | Some(b'\r') | Some(b'\t') | Some(b'\x0C') }
         }
+        Blank => quote! { Some(b' ') | Some(b'\t') },
+        Punct => quote! {
+            Some(b'!'..=b'/') | Some(b':'..=b'@') | Some(b'['..=b'`') | Some(b'{'..=b'~')
+        },
+        Graph => quote! { Some(b'!'..=b'~') },
+        Print => quote! { Some(b' '..=b'~') },
+        Cntrl => quote! { Some(0x00..=0x1f) | Some(0x7f) },
+        NonAscii => quote! { Some(0x80..=0xff) },
+        Named(class) => compile_named_class_pattern(class),
     }
 }
 
@@ -44,36 +97,74 @@ fn compile_state_enter_prelude(rhs: TokenStream2) -> TokenStream2 {
     }
 }
 
-// TODO: collect condition name
 fn compile_condition_pattern(condition: &str) -> TokenStream2 {
     let condition = Ident::new(condition, Span::call_site());
 
     quote! { Some(b) if self.#condition(b) }
 }
 
-// TODO OPTIMISATION: don't buffer if there are no markers in RHS
-fn compile_sequence_pattern(pattern: &SequencePattern) -> TokenStream2 {
-    /*
-    let pos = input.pos();
-    let available = input.len() - pos;
-
-    if available < $pattern_len {
-        if !input.is_last() && input[pos..].eq|eq_ignore_ascii_case($pattern[..available]) {
-            self.pins.__sequence_matching_start.start(Some(pos));
-            return LoopTermination::Break;
+// NOTE: case of the ASCII letters is folded into the set of the element's bytes.
+fn compile_sequence_element(element: &SequenceElement, ignore_case: bool) -> TokenStream2 {
+    let ranges = compile_byte_set((0..=255u8).filter(|&b| element.matches(b, ignore_case)));
+
+    quote! { match Some(b) { #ranges => true, _ => false } }
+}
+
+fn compile_sequence_matcher(pattern: &SequencePattern) -> TokenStream2 {
+    let input = gen_parser_intrinsics!(input);
+
+    match pattern.literal_bytes() {
+        Some(bytes) => {
+            let match_sequence = gen_parser_intrinsics!(match_sequence);
+            let bytes = LitByteStr::new(&bytes, Span::call_site());
+            let ignore_case = pattern.ignore_case;
+
+            quote! { #match_sequence(#input, #bytes, #ignore_case) }
+        }
+        None => {
+            let match_sequence_with = gen_parser_intrinsics!(match_sequence_with);
+            let len = pattern.len();
+
+            let element_arms = pattern
+                .elements
+                .iter()
+                .enumerate()
+                .filter(|(_, e)| **e != SequenceElement::Any)
+                .map(|(i, e)| {
+                    let element_matches = compile_sequence_element(e, pattern.ignore_case);
+
+                    quote! { #i => #element_matches, }
+                });
+
+            quote! {
+                #match_sequence_with(#input, #len, |i, b| match i {
+                    #(#element_arms)*
+                    _ => true,
+                })
+            }
         }
-    } else if input[pos..pos+$pattern_len].eq|eq_ignore_ascii_case(pattern) {
-        #RHS
     }
+}
 
-    self.markers.__sequence_matching_start = None;
+// TODO OPTIMISATION: don't buffer if there are no markers in RHS
+fn compile_sequence_pattern(pattern: &SequencePattern, rhs: TokenStream2) -> TokenStream2 {
+    let block_input = gen_parser_intrinsics!(block_input);
+    let matcher = compile_sequence_matcher(pattern);
 
-    */
-    quote! {}
+    // NOTE: if the sequence is interrupted by the end of the chunk we
+    // block the rest of the input, so it will be fed to us again along
+    // with the next chunk.
+    quote! {
+        Some(_) if match #matcher {
+            SequenceMatch::Full => true,
+            SequenceMatch::Partial => #block_input,
+            SequenceMatch::Mismatch => false,
+        } => { #rhs }
+    }
 }
 
 impl Arm {
-    fn compile_condition(&self, rhs: TokenStream2) -> TokenStream2 {
+    pub(super) fn compile_condition(&self, rhs: TokenStream2) -> TokenStream2 {
         use Pattern::*;
 
         macro_rules! match_arm {
@@ -85,12 +176,25 @@ impl Arm {
 
         match self.pattern {
             StateEnter => compile_state_enter_prelude(rhs),
-            Byte(b) => match_arm!(LitInt::new(b.into(), IntSuffix::U8, Span::call_site())),
-            Class(c) => match_arm!(compile_class_pattern(c)),
+            Byte {
+                byte,
+                ignore_case: false,
+            } => {
+                let b = LitInt::new(byte.into(), IntSuffix::U8, Span::call_site());
+
+                match_arm!(quote! { Some(#b) })
+            }
+            Byte { byte, ignore_case } => match_arm!(compile_byte_set(
+                (0..=255u8).filter(|&b| SequenceElement::Byte(byte).matches(b, ignore_case))
+            )),
+            Range(r) => match_arm!(compile_byte_set((0..=255u8).filter(|&b| r.matches(b)))),
+            Mask(m) => match_arm!(compile_mask_pattern(m)),
+            Class(ref c) => match_arm!(compile_class_pattern(c)),
             InputState(s) => match_arm!(compile_input_state_pattern(s)),
             Condition(ref c) => match_arm!(compile_condition_pattern(c)),
-            Any => match_arm!(quote! { _ }),
-            Sequence(ref s) => compile_sequence_pattern(s),
+            Any => match_arm!(quote! { Some(_) }),
+            Sequence(ref s) => compile_sequence_pattern(s, rhs),
+            Unicode(_) | InvalidUtf8 => compile_utf8_pattern(&self.pattern, rhs),
         }
     }
 }
@@ -103,9 +207,9 @@ mod tests {
 
     macro_rules! compile {
         ($($t:tt)*) => {
-            parse_ok!($($t)*)
-                .compile_condition(quote! { __RHS__ })
-                .to_string()
+            crate::helpers::test_helpers::to_code_str(
+                parse_ok!($($t)*).compile_condition(quote! { __RHS__ })
+            )
         };
     }
 
@@ -116,7 +220,7 @@ mod tests {
                 _ => __RHS__.
             },
             code_str! {
-                _ => { __RHS__ }
+                Some(_) => { __RHS__ }
             }
         );
     }
@@ -128,7 +232,58 @@ mod tests {
                 'a' => __RHS__.
             },
             code_str! {
-                97u8 => { __RHS__ }
+                Some(97u8) => { __RHS__ }
+            }
+        );
+
+        assert_eq!(
+            compile! {
+                'x'|i => __RHS__.
+            },
+            code_str! {
+                Some(88u8) | Some(120u8) => { __RHS__ }
+            }
+        );
+    }
+
+    #[test]
+    fn compile_range_pattern_arm() {
+        assert_eq!(
+            compile! {
+                'a'..='f' => __RHS__.
+            },
+            code_str! {
+                Some(97u8..=102u8) => { __RHS__ }
+            }
+        );
+
+        assert_eq!(
+            compile! {
+                '0'..='B'|i => __RHS__.
+            },
+            code_str! {
+                Some(48u8..=66u8) | Some(97u8..=98u8) => { __RHS__ }
+            }
+        );
+    }
+
+    #[test]
+    fn compile_mask_pattern_arm() {
+        assert_eq!(
+            compile! {
+                0xE0/0xC0 => __RHS__.
+            },
+            code_str! {
+                Some(192u8..=223u8) => { __RHS__ }
+            }
+        );
+
+        assert_eq!(
+            compile! {
+                0x0F/0x00 => __RHS__.
+            },
+            code_str! {
+                Some(b) if b & 15u8 == 0u8 => { __RHS__ }
             }
         );
     }
@@ -218,6 +373,135 @@ mod tests {
                 Some(b' ') | Some(b'\n') | Some(b'\r') | Some(b'\t') | Some(b'\x0C') => { __RHS__ }
             }
         );
+
+        assert_eq!(
+            compile! {
+                odigit => __RHS__.
+            },
+            code_str! {
+                Some(b'0'..=b'7') => { __RHS__ }
+            }
+        );
+
+        assert_eq!(
+            compile! {
+                bdigit => __RHS__.
+            },
+            code_str! {
+                Some(b'0') | Some(b'1') => { __RHS__ }
+            }
+        );
+
+        assert_eq!(
+            compile! {
+                blank => __RHS__.
+            },
+            code_str! {
+                Some(b' ') | Some(b'\t') => { __RHS__ }
+            }
+        );
+
+        assert_eq!(
+            compile! {
+                punct => __RHS__.
+            },
+            code_str! {
+                Some(b'!'..=b'/') | Some(b':'..=b'@') | Some(b'['..=b'`') | Some(b'{'..=b'~') => {
+                    __RHS__
+                }
+            }
+        );
+
+        assert_eq!(
+            compile! {
+                graph => __RHS__.
+            },
+            code_str! {
+                Some(b'!'..=b'~') => { __RHS__ }
+            }
+        );
+
+        assert_eq!(
+            compile! {
+                print => __RHS__.
+            },
+            code_str! {
+                Some(b' '..=b'~') => { __RHS__ }
+            }
+        );
+
+        assert_eq!(
+            compile! {
+                cntrl => __RHS__.
+            },
+            code_str! {
+                Some(0x00..=0x1f) | Some(0x7f) => { __RHS__ }
+            }
+        );
+
+        assert_eq!(
+            compile! {
+                non_ascii => __RHS__.
+            },
+            code_str! {
+                Some(0x80..=0xff) => { __RHS__ }
+            }
+        );
+    }
+
+    #[test]
+    fn compile_named_class_pattern() {
+        let class = ClassPattern::Named(NamedClass {
+            name: "ident_start".into(),
+            bytes: vec![b'$', b'A', b'B', b'C', b'_', b'a', b'b'],
+        });
+
+        assert_eq!(
+            crate::helpers::test_helpers::to_code_str(compile_class_pattern(&class)),
+            code_str! {
+                Some(36u8) | Some(65u8..=67u8) | Some(95u8) | Some(97u8..=98u8)
+            }
+        );
+    }
+
+    #[test]
+    fn compile_sequence_pattern_arm() {
+        assert_eq!(
+            compile! {
+                "foo"|i => __RHS__.
+            },
+            code_str! {
+                Some(_) if match self.__match_sequence(input, b"foo", true) {
+                    SequenceMatch::Full => true,
+                    SequenceMatch::Partial => return Ok(input.len() - self.__pos),
+                    SequenceMatch::Mismatch => false,
+                } => { __RHS__ }
+            }
+        );
+    }
+
+    #[test]
+    fn compile_sequence_with_elements_pattern_arm() {
+        assert_eq!(
+            compile! {
+                ['<', _, 'a'..='c', bdigit]|i => __RHS__.
+            },
+            code_str! {
+                Some(_) if match self.__match_sequence_with(input, 4usize, |i, b| match i {
+                    0usize => match Some(b) { Some(60u8) => true, _ => false },
+                    2usize => match Some(b) {
+                        Some(65u8..=67u8) | Some(97u8..=99u8) => true,
+                        _ => false
+                    },
+                    3usize => match Some(b) { Some(48u8..=49u8) => true, _ => false },
+                    _ => true,
+                }) {
+                    SequenceMatch::Full => true,
+                    SequenceMatch::Partial => return Ok(input.len() - self.__pos),
+                    SequenceMatch::Mismatch => false,
+                } => { __RHS__ }
+            }
+        );
     }
 
     #[test]
@@ -1 +1,92 @@
 mod condition;
+mod rhs;
+mod utf8;
+
+pub(crate) use self::utf8::compile_utf8_helpers;
+
+use crate::compile::{ArmCtx, Consumption};
+use crate::graphviz::EdgeId;
+use crate::{Arm, Pattern, State};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{Ident, IntSuffix, LitInt};
+
+fn compile_byte_ranges(ranges: &[(u8, u8)]) -> TokenStream2 {
+    let ranges = ranges.iter().map(|&(start, end)| {
+        let lit = |b: u8| LitInt::new(b.into(), IntSuffix::U8, Span::call_site());
+        let (start_lit, end_lit) = (lit(start), lit(end));
+
+        if start == end {
+            quote! { Some(#start_lit) }
+        } else {
+            quote! { Some(#start_lit..=#end_lit) }
+        }
+    });
+
+    quote! { #(#ranges)|* }
+}
+
+impl Arm {
+    pub(crate) fn consumption(&self) -> Consumption {
+        use Pattern::*;
+
+        match self.pattern {
+            StateEnter => Consumption::Nothing,
+            Byte { .. } | Range(_) | Mask(_) | Class(_) | Condition(_) | Any => Consumption::Byte,
+            InputState(_) => Consumption::InputState,
+            Sequence(ref s) if s.lookahead => Consumption::Nothing,
+            Sequence(ref s) => Consumption::Bytes(s.len()),
+            Unicode(_) | InvalidUtf8 => Consumption::CodePoint,
+        }
+    }
+
+    fn compile_tracer_hook(&self, state_name: &str) -> TokenStream2 {
+        let state = Ident::new(state_name, Span::call_site());
+        let pattern = self.pattern.to_string();
+        let actions = gen_parser_intrinsics!(actions);
+        let offset = gen_parser_intrinsics!(offset);
+
+        quote! { #actions.on_arm_match(State::#state, #pattern, #offset); }
+    }
+
+    // NOTE: the arm's body without the pattern, so it can be placed
+    // into the arms of the table dispatch.
+    pub(crate) fn compile_body(
+        &self,
+        state_name: &str,
+        edge: EdgeId,
+        tracer: bool,
+        states: &[State],
+    ) -> TokenStream2 {
+        let ctx = ArmCtx {
+            state_name,
+            consumption: self.consumption(),
+            edge: Some(edge),
+            tracer,
+            states,
+        };
+
+        let rhs = self.rhs.compile(&ctx);
+
+        if tracer {
+            let tracer_hook = self.compile_tracer_hook(state_name);
+
+            quote! {
+                #tracer_hook
+                #rhs
+            }
+        } else {
+            rhs
+        }
+    }
+
+    pub(crate) fn compile(
+        &self,
+        state_name: &str,
+        edge: EdgeId,
+        tracer: bool,
+        states: &[State],
+    ) -> TokenStream2 {
+        self.compile_condition(self.compile_body(state_name, edge, tracer, states))
+    }
+}
@@ -0,0 +1,113 @@
+use crate::compile::ArmCtx;
+use crate::graphviz::EdgeId;
+use crate::{ArmRhs, ConditionBranch};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::Ident;
+
+impl ConditionBranch {
+    fn compile(&self, ctx: &ArmCtx) -> TokenStream2 {
+        let condition = Ident::new(&self.condition, Span::call_site());
+        let directives = self.directives.compile(ctx);
+
+        quote! {
+            if self.#condition() {
+                #directives
+            }
+        }
+    }
+}
+
+impl ArmRhs {
+    pub(crate) fn compile(&self, ctx: &ArmCtx) -> TokenStream2 {
+        match self {
+            ArmRhs::Directives(directives) => directives.compile(ctx),
+            ArmRhs::Condition {
+                if_branch,
+                else_if_branches,
+                else_branch,
+            } => {
+                let branch_ctx = |branch| ArmCtx {
+                    edge: ctx.edge.map(|e| EdgeId { branch, ..e }),
+                    ..*ctx
+                };
+
+                let if_branch = if_branch.compile(&branch_ctx(0));
+
+                let else_if_branches = else_if_branches
+                    .iter()
+                    .enumerate()
+                    .map(|(i, b)| b.compile(&branch_ctx(i + 1)));
+
+                let else_branch = else_branch.compile(&branch_ctx(else_if_branches.len() + 1));
+
+                quote! {
+                    #if_branch
+                    #(else #else_if_branches)*
+                    else {
+                        #else_branch
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compile::Consumption;
+
+    curry_parse_macros!($ArmRhs);
+
+    macro_rules! compile {
+        ($($t:tt)*) => {
+            crate::helpers::test_helpers::to_code_str(parse_ok!($($t)*).compile(&ArmCtx {
+                state_name: "foo_state",
+                consumption: Consumption::Byte,
+                edge: None,
+                tracer: false,
+                states: &[],
+            }))
+        };
+    }
+
+    #[test]
+    fn compile_directives() {
+        assert_eq!(
+            compile! { foo. },
+            code_str! {
+                self.foo();
+                self.__pos += 1;
+            }
+        );
+    }
+
+    #[test]
+    fn compile_condition() {
+        assert_eq!(
+            compile! {
+                if cond1 {
+                    foo.
+                } else if cond2 {
+                    --> bar_state.
+                } else {
+                    baz.
+                }
+            },
+            code_str! {
+                if self.cond1() {
+                    self.foo();
+                    self.__pos += 1;
+                } else if self.cond2() {
+                    self.__state = State::bar_state;
+                    self.__state_enter = true;
+                    self.__pos += 1;
+                } else {
+                    self.baz();
+                    self.__pos += 1;
+                }
+            }
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{Pattern, UnicodeProperty};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::Ident;
+
+impl UnicodeProperty {
+    fn ranges_table(self) -> Ident {
+        let name = format!("__{}_RANGES", self.to_string().to_uppercase());
+
+        Ident::new(&name, Span::call_site())
+    }
+}
+
+// NOTE: evaluates to the number of bytes matched by the pattern or to `0` if
+// the pattern doesn't match. If the code point is interrupted by the end of
+// the chunk we block the rest of the input, like we do for sequences.
+pub(super) fn compile_utf8_pattern(pattern: &Pattern, rhs: TokenStream2) -> TokenStream2 {
+    let input = gen_parser_intrinsics!(input);
+    let pos = gen_parser_intrinsics!(pos);
+    let is_last_input = gen_parser_intrinsics!(is_last_input);
+    let decode_utf8 = gen_parser_intrinsics!(decode_utf8);
+    let block_input = gen_parser_intrinsics!(block_input);
+    let code_point_len = gen_parser_intrinsics!(code_point_len);
+
+    let matched = match pattern {
+        Pattern::Unicode(property) => {
+            let ranges = property.ranges_table();
+
+            quote! { Utf8Sequence::CodePoint(c, len) if __has_code_point(#ranges, c) => len, }
+        }
+        _ => quote! { Utf8Sequence::Invalid(len) => len, },
+    };
+
+    quote! {
+        Some(_) if {
+            #code_point_len = match #decode_utf8(&#input[#pos..], #is_last_input) {
+                #matched
+                Utf8Sequence::Blocked => #block_input,
+                _ => 0,
+            };
+
+            #code_point_len > 0
+        } => { #rhs }
+    }
+}
+
+/// Compiles the UTF-8 decoder and the sorted code point ranges of the
+/// properties that are searched by the `unicode(...)` patterns.
+pub(crate) fn compile_utf8_helpers(properties: &[UnicodeProperty]) -> TokenStream2 {
+    let tables = properties.iter().map(|property| {
+        let name = property.ranges_table();
+        let ranges = property
+            .code_point_ranges()
+            .iter()
+            .map(|&(start, end)| quote! { (#start, #end) });
+
+        quote! { static #name: &[(u32, u32)] = &[#(#ranges),*]; }
+    });
+
+    // NOTE: malformed sequences are consumed by their maximal valid prefixes
+    // (or a single byte), the same way `String::from_utf8_lossy` replaces them.
+    let decoder = quote! {
+        enum Utf8Sequence {
+            CodePoint(u32, usize),
+            Invalid(usize),
+            Blocked,
+        }
+
+        fn __decode_utf8(bytes: &[u8], is_last_input: bool) -> Utf8Sequence {
+            let first = bytes[0];
+
+            let (len, mut code_point, mut next) = match first {
+                0x00..=0x7f => return Utf8Sequence::CodePoint(u32::from(first), 1),
+                0xc2..=0xdf => (2, u32::from(first & 0x1f), (0x80, 0xbf)),
+                0xe0 => (3, 0, (0xa0, 0xbf)),
+                0xed => (3, 0xd, (0x80, 0x9f)),
+                0xe1..=0xef => (3, u32::from(first & 0x0f), (0x80, 0xbf)),
+                0xf0 => (4, 0, (0x90, 0xbf)),
+                0xf4 => (4, 0x4, (0x80, 0x8f)),
+                0xf1..=0xf3 => (4, u32::from(first & 0x07), (0x80, 0xbf)),
+                _ => return Utf8Sequence::Invalid(1),
+            };
+
+            for depth in 1..len {
+                match bytes.get(depth) {
+                    Some(&b) if b >= next.0 && b <= next.1 => {
+                        code_point = (code_point << 6) | u32::from(b & 0x3f);
+                        next = (0x80, 0xbf);
+                    }
+                    Some(_) => return Utf8Sequence::Invalid(depth),
+                    None if is_last_input => return Utf8Sequence::Invalid(depth),
+                    None => return Utf8Sequence::Blocked,
+                }
+            }
+
+            Utf8Sequence::CodePoint(code_point, len)
+        }
+    };
+
+    let search = quote! {
+        fn __has_code_point(ranges: &[(u32, u32)], c: u32) -> bool {
+            ranges
+                .binary_search_by(|&(start, end)| {
+                    if end < c {
+                        std::cmp::Ordering::Less
+                    } else if start > c {
+                        std::cmp::Ordering::Greater
+                    } else {
+                        std::cmp::Ordering::Equal
+                    }
+                })
+                .is_ok()
+        }
+    };
+
+    quote! {
+        #decoder
+        #search
+        #(#tables)*
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test_helpers::to_code_str;
+
+    #[test]
+    fn compile_unicode_pattern() {
+        assert_eq!(
+            to_code_str(compile_utf8_pattern(
+                &Pattern::Unicode(UnicodeProperty::XidStart),
+                quote! { foo(); }
+            )),
+            code_str! {
+                Some(_) if {
+                    code_point_len = match __decode_utf8(&input[self.__pos..], self.__is_last_input) {
+                        Utf8Sequence::CodePoint(c, len) if __has_code_point(__XID_START_RANGES, c) => len,
+                        Utf8Sequence::Blocked => return Ok(input.len() - self.__pos),
+                        _ => 0,
+                    };
+
+                    code_point_len > 0
+                } => { foo(); }
+            }
+        );
+    }
+
+    #[test]
+    fn compile_invalid_utf8_pattern() {
+        assert_eq!(
+            to_code_str(compile_utf8_pattern(
+                &Pattern::InvalidUtf8,
+                quote! { foo(); }
+            )),
+            code_str! {
+                Some(_) if {
+                    code_point_len = match __decode_utf8(&input[self.__pos..], self.__is_last_input) {
+                        Utf8Sequence::Invalid(len) => len,
+                        Utf8Sequence::Blocked => return Ok(input.len() - self.__pos),
+                        _ => 0,
+                    };
+
+                    code_point_len > 0
+                } => { foo(); }
+            }
+        );
+    }
+
+    #[test]
+    fn compile_ranges_table() {
+        let code = to_code_str(compile_utf8_helpers(&[UnicodeProperty::WhiteSpace]));
+
+        assert!(code.contains(concat![
+            "static __WHITE_SPACE_RANGES : & [ ( u32 , u32 ) ] = ",
+            "& [ ( 9u32 , 13u32 ) , ( 32u32 , 32u32 ) , ( 133u32 , 133u32 ) ,"
+        ]));
+    }
+}
@@ -0,0 +1,204 @@
+use super::*;
+use std::fmt::{self, Display, Formatter};
+
+// NOTE: patterns are displayed in the grammar syntax, so they can
+// be used in diagnostics and graph labels.
+fn fmt_byte(b: u8) -> String {
+    if b.is_ascii_graphic() || b == b' ' {
+        format!("{:?}", b as char)
+    } else {
+        format!("0x{:02X}", b)
+    }
+}
+
+impl Display for ClassPattern {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        use ClassPattern::*;
+
+        let name = match self {
+            Alnum => "alnum",
+            Alpha => "alpha",
+            Ascii => "ascii",
+            Lower => "lower",
+            Upper => "upper",
+            Digit => "digit",
+            Xdigit => "xdigit",
+            Odigit => "odigit",
+            Bdigit => "bdigit",
+            Space => "space",
+            Blank => "blank",
+            Punct => "punct",
+            Graph => "graph",
+            Print => "print",
+            Cntrl => "cntrl",
+            NonAscii => "non_ascii",
+            Named(class) => &class.name,
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+impl Display for UnicodeProperty {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        use UnicodeProperty::*;
+
+        let name = match self {
+            XidStart => "XID_Start",
+            XidContinue => "XID_Continue",
+            Alphabetic => "Alphabetic",
+            Lowercase => "Lowercase",
+            Uppercase => "Uppercase",
+            WhiteSpace => "White_Space",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+impl Display for InputStatePattern {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            InputStatePattern::Eoc => write!(f, "eoc"),
+            InputStatePattern::Eof => write!(f, "eof"),
+        }
+    }
+}
+
+impl Display for RangePattern {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", fmt_byte(self.start))?;
+
+        if self.start != self.end {
+            write!(f, "..={}", fmt_byte(self.end))?;
+        }
+
+        if self.ignore_case {
+            write!(f, "|i")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for MaskPattern {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let digits = (0..8)
+            .rev()
+            .map(|i| match (self.mask >> i & 1, self.value >> i & 1) {
+                (0, _) => 'x',
+                (_, 0) => '0',
+                _ => '1',
+            })
+            .collect::<String>();
+
+        write!(f, "mask(\"{}_{}\")", &digits[..4], &digits[4..])
+    }
+}
+
+impl Display for SequenceElement {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            SequenceElement::Byte(b) => write!(f, "{}", fmt_byte(*b)),
+            SequenceElement::Range(start, end) => {
+                write!(f, "{}..={}", fmt_byte(*start), fmt_byte(*end))
+            }
+            SequenceElement::Class(c) => write!(f, "{}", c),
+            SequenceElement::Any => write!(f, "_"),
+        }
+    }
+}
+
+impl Display for SequencePattern {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.lookahead {
+            write!(f, "peek ")?;
+        }
+
+        match self.literal_bytes() {
+            Some(ref bytes) if bytes.iter().all(|&b| b.is_ascii_graphic() || b == b' ') => {
+                write!(f, "{:?}", String::from_utf8_lossy(bytes))?;
+            }
+            _ => {
+                let elements = self
+                    .elements
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>();
+
+                write!(f, "[{}]", elements.join(", "))?;
+            }
+        }
+
+        if self.ignore_case {
+            write!(f, "|i")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Pattern::StateEnter => write!(f, "-->"),
+            Pattern::Byte { byte, ignore_case } => {
+                write!(f, "{}", fmt_byte(*byte))?;
+
+                if *ignore_case {
+                    write!(f, "|i")?;
+                }
+
+                Ok(())
+            }
+            Pattern::Range(r) => write!(f, "{}", r),
+            Pattern::Mask(m) => write!(f, "{}", m),
+            Pattern::Class(c) => write!(f, "{}", c),
+            Pattern::InputState(s) => write!(f, "{}", s),
+            Pattern::Condition(c) => write!(f, "if {}", c),
+            Pattern::Sequence(s) => write!(f, "{}", s),
+            Pattern::Unicode(p) => write!(f, "unicode({})", p),
+            Pattern::InvalidUtf8 => write!(f, "invalid_utf8"),
+            Pattern::Any => write!(f, "_"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    curry_parse_macros!($Pattern);
+
+    #[test]
+    fn display() {
+        assert_eq!(parse_ok! { --> }.to_string(), "-->");
+        assert_eq!(parse_ok! { 'a' }.to_string(), "'a'");
+        assert_eq!(parse_ok! { '\'' }.to_string(), "'\\''");
+        assert_eq!(parse_ok! { 0x1f }.to_string(), "0x1F");
+        assert_eq!(parse_ok! { 'a'..=0x7A }.to_string(), "'a'..='z'");
+        assert_eq!(parse_ok! { 'x'|i }.to_string(), "'x'|i");
+        assert_eq!(
+            parse_ok! { mask("110x_xxxx") }.to_string(),
+            "mask(\"110x_xxxx\")"
+        );
+        assert_eq!(parse_ok! { 0x0F/0x01 }.to_string(), "mask(\"xxxx_0001\")");
+        assert_eq!(parse_ok! { alpha }.to_string(), "alpha");
+        assert_eq!(parse_ok! { eof }.to_string(), "eof");
+        assert_eq!(parse_ok! { if foo }.to_string(), "if foo");
+        assert_eq!(parse_ok! { "foo"|i }.to_string(), "\"foo\"|i");
+        assert_eq!(parse_ok! { "a\"b" }.to_string(), "\"a\\\"b\"");
+        assert_eq!(parse_ok! { ['a', 0x00] }.to_string(), "['a', 0x00]");
+        assert_eq!(
+            parse_ok! { ['<', '/', alpha, 'a'..='f', _]|i }.to_string(),
+            "['<', '/', alpha, 'a'..='f', _]|i"
+        );
+        assert_eq!(
+            parse_ok! { unicode(XID_Start) }.to_string(),
+            "unicode(XID_Start)"
+        );
+        assert_eq!(parse_ok! { invalid_utf8 }.to_string(), "invalid_utf8");
+        assert_eq!(parse_ok! { peek "-->" }.to_string(), "peek \"-->\"");
+        assert_eq!(parse_ok! { _ }.to_string(), "_");
+    }
+}
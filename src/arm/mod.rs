@@ -1,9 +1,25 @@
 mod compile;
+mod display;
 mod parse;
+mod utf8;
+
+pub(crate) use self::compile::compile_utf8_helpers;
+pub(crate) use self::parse::{resolve_class_declarations, ClassDeclaration};
+pub(crate) use self::utf8::Utf8Match;
 
 use crate::Directives;
+use std::iter::once;
+use unicode_xid::UnicodeXID;
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+/// Byte class declared in the grammar (e.g. `class ident_start = alpha | '_';`).
+#[derive(Debug, PartialEq, Clone)]
+pub struct NamedClass {
+    pub name: String,
+    /// Sorted bytes of the class.
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub enum ClassPattern {
     Alnum,
     Alpha,
@@ -12,7 +28,27 @@ pub enum ClassPattern {
     Upper,
     Digit,
     Xdigit,
+    Odigit,
+    Bdigit,
     Space,
+    Blank,
+    Punct,
+    Graph,
+    Print,
+    Cntrl,
+    NonAscii,
+    Named(NamedClass),
+}
+
+/// Unicode property of the code points matched by the `unicode(...)` pattern.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum UnicodeProperty {
+    XidStart,
+    XidContinue,
+    Alphabetic,
+    Lowercase,
+    Uppercase,
+    WhiteSpace,
 }
 
 #[derive(Debug, PartialEq, Copy, Clone)]
@@ -21,20 +57,56 @@ pub enum InputStatePattern {
     Eof,
 }
 
-#[derive(PartialEq, Debug)]
+/// Element of the sequence pattern that matches a single byte.
+#[derive(Debug, PartialEq, Clone)]
+pub enum SequenceElement {
+    Byte(u8),
+    Range(u8, u8),
+    Class(ClassPattern),
+    Any,
+}
+
+#[derive(PartialEq, Debug, Clone)]
 pub struct SequencePattern {
-    pub bytes: Vec<u8>,
+    pub elements: Vec<SequenceElement>,
     pub ignore_case: bool,
+    /// Lookahead sequences are matched without consuming the input.
+    pub lookahead: bool,
+}
+
+/// Range of bytes: `'a'..='f'`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct RangePattern {
+    pub start: u8,
+    pub end: u8,
+    pub ignore_case: bool,
+}
+
+/// Byte that has the `value` bits under the `mask`: `mask("110x_xxxx")` or `0xE0/0xC0`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MaskPattern {
+    pub mask: u8,
+    pub value: u8,
 }
 
 #[derive(Debug, PartialEq)]
 pub enum Pattern {
     StateEnter,
-    Byte(u8),
+    /// Single byte: `'a'` or, with the ignore case flag, `'x'|i`.
+    Byte {
+        byte: u8,
+        ignore_case: bool,
+    },
+    Range(RangePattern),
+    Mask(MaskPattern),
     Class(ClassPattern),
     InputState(InputStatePattern),
     Condition(String),
     Sequence(SequencePattern),
+    /// Code point of the UTF-8 input that has the property.
+    Unicode(UnicodeProperty),
+    /// Malformed UTF-8 sequence.
+    InvalidUtf8,
     Any,
 }
 
@@ -59,3 +131,127 @@ pub struct Arm {
     pub pattern: Pattern,
     pub rhs: ArmRhs,
 }
+
+impl ClassPattern {
+    pub fn matches(&self, b: u8) -> bool {
+        use ClassPattern::*;
+
+        match self {
+            Alnum => b.is_ascii_alphanumeric(),
+            Alpha => b.is_ascii_alphabetic(),
+            Ascii => b.is_ascii(),
+            Lower => b.is_ascii_lowercase(),
+            Upper => b.is_ascii_uppercase(),
+            Digit => b.is_ascii_digit(),
+            Xdigit => b.is_ascii_hexdigit(),
+            Odigit => matches!(b, b'0'..=b'7'),
+            Bdigit => matches!(b, b'0' | b'1'),
+            Space => b.is_ascii_whitespace(),
+            Blank => matches!(b, b' ' | b'\t'),
+            Punct => b.is_ascii_punctuation(),
+            Graph => b.is_ascii_graphic(),
+            Print => b.is_ascii_graphic() || b == b' ',
+            Cntrl => b.is_ascii_control(),
+            NonAscii => !b.is_ascii(),
+            Named(class) => class.bytes.binary_search(&b).is_ok(),
+        }
+    }
+}
+
+impl SequenceElement {
+    pub fn matches(&self, b: u8, ignore_case: bool) -> bool {
+        let matches = |b: u8| match self {
+            SequenceElement::Byte(e) => *e == b,
+            SequenceElement::Range(start, end) => (*start..=*end).contains(&b),
+            SequenceElement::Class(class) => class.matches(b),
+            SequenceElement::Any => true,
+        };
+
+        if ignore_case && b.is_ascii_alphabetic() {
+            matches(b.to_ascii_lowercase()) || matches(b.to_ascii_uppercase())
+        } else {
+            matches(b)
+        }
+    }
+}
+
+impl RangePattern {
+    pub fn matches(self, b: u8) -> bool {
+        SequenceElement::Range(self.start, self.end).matches(b, self.ignore_case)
+    }
+}
+
+impl MaskPattern {
+    pub fn matches(self, b: u8) -> bool {
+        b & self.mask == self.value
+    }
+}
+
+impl SequencePattern {
+    pub fn from_bytes(bytes: &[u8], ignore_case: bool) -> Self {
+        SequencePattern {
+            elements: bytes.iter().map(|&b| SequenceElement::Byte(b)).collect(),
+            ignore_case,
+            lookahead: false,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns bytes of the sequence if all its elements are single bytes.
+    pub fn literal_bytes(&self) -> Option<Vec<u8>> {
+        self.elements
+            .iter()
+            .map(|e| match e {
+                SequenceElement::Byte(b) => Some(*b),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Checks if the bytes match the sequence's elements. Only the first
+    /// `min(bytes.len(), self.len())` elements are compared.
+    pub fn matches(&self, bytes: &[u8]) -> bool {
+        self.elements
+            .iter()
+            .zip(bytes)
+            .all(|(e, &b)| e.matches(b, self.ignore_case))
+    }
+}
+
+impl UnicodeProperty {
+    pub fn contains(self, c: char) -> bool {
+        use UnicodeProperty::*;
+
+        match self {
+            XidStart => UnicodeXID::is_xid_start(c),
+            XidContinue => UnicodeXID::is_xid_continue(c),
+            Alphabetic => c.is_alphabetic(),
+            Lowercase => c.is_lowercase(),
+            Uppercase => c.is_uppercase(),
+            WhiteSpace => c.is_whitespace(),
+        }
+    }
+}
+
+impl ArmRhs {
+    pub fn directives(&self) -> Vec<&Directives> {
+        match self {
+            ArmRhs::Directives(directives) => vec![directives],
+            ArmRhs::Condition {
+                if_branch,
+                else_if_branches,
+                else_branch,
+            } => once(&if_branch.directives)
+                .chain(else_if_branches.iter().map(|b| &b.directives))
+                .chain(once(else_branch))
+                .collect(),
+        }
+    }
+}
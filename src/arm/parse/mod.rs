@@ -2,20 +2,54 @@ mod condition_branch;
 mod patterns;
 mod rhs;
 
+pub(crate) use self::patterns::{resolve_class_declarations, ClassDeclaration};
+
 use super::*;
 use syn::parse::{Parse, ParseStream};
-use syn::{Result as ParseResult, Token};
+use syn::{Error as ParseError, Result as ParseResult, Token};
 
-impl Parse for Arm {
-    fn parse(input: ParseStream) -> ParseResult<Self> {
-        let pattern = input.parse::<Pattern>()?;
+const ERR_LOOKAHEAD_WITHOUT_TRANSITION: &str = concat![
+    "lookahead sequence doesn't consume the input, so each branch of its arm",
+    " should transition to another state"
+];
+
+impl Arm {
+    /// Parses the arm which pattern can refer to the classes declared in the grammar.
+    pub(crate) fn parse_with_classes(
+        input: ParseStream,
+        classes: &[NamedClass],
+    ) -> ParseResult<Self> {
+        let pattern_span = input.cursor().span();
+        let pattern = Pattern::parse_with_classes(input, classes)?;
 
         input.parse::<Token! { => }>()?;
 
-        Ok(Arm {
-            pattern,
-            rhs: input.parse()?,
-        })
+        let rhs = input.parse::<ArmRhs>()?;
+
+        // NOTE: otherwise the arm would match the same input over and over again.
+        if let Pattern::Sequence(SequencePattern {
+            lookahead: true, ..
+        }) = pattern
+        {
+            if rhs
+                .directives()
+                .iter()
+                .any(|d| d.state_transition.is_none())
+            {
+                return Err(ParseError::new(
+                    pattern_span,
+                    ERR_LOOKAHEAD_WITHOUT_TRANSITION,
+                ));
+            }
+        }
+
+        Ok(Arm { pattern, rhs })
+    }
+}
+
+impl Parse for Arm {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        Self::parse_with_classes(input, &[])
     }
 }
 
@@ -31,7 +65,10 @@ mod tests {
         assert_eq!(
             parse_ok! { 'a' => foo, --> baz_state. },
             Arm {
-                pattern: Pattern::Byte(b'a'),
+                pattern: Pattern::Byte {
+                    byte: b'a',
+                    ignore_case: false,
+                },
                 rhs: ArmRhs::Directives(Directives {
                     action_calls: vec![act!("foo")],
                     state_transition: Some(StateTransition {
@@ -71,7 +108,10 @@ mod tests {
                 }
             },
             Arm {
-                pattern: Pattern::Byte(b'z'),
+                pattern: Pattern::Byte {
+                    byte: b'z',
+                    ignore_case: false,
+                },
                 rhs: ArmRhs::Condition {
                     if_branch: ConditionBranch {
                         condition: "cond".into(),
@@ -90,6 +130,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_lookahead_arm() {
+        assert_eq!(
+            parse_ok! { peek "-->" => foo, --> bar_state. },
+            Arm {
+                pattern: Pattern::Sequence(SequencePattern {
+                    lookahead: true,
+                    ..SequencePattern::from_bytes(b"-->", false)
+                }),
+                rhs: ArmRhs::Directives(Directives {
+                    action_calls: vec![act!("foo")],
+                    state_transition: Some(StateTransition {
+                        target: "bar_state".into(),
+                        dynamic: false,
+                        epsilon_move: false
+                    })
+                })
+            }
+        );
+    }
+
+    #[test]
+    fn lookahead_without_transition_error() {
+        assert_eq!(
+            parse_err! { peek "-->" => foo. },
+            ERR_LOOKAHEAD_WITHOUT_TRANSITION
+        );
+
+        assert_eq!(
+            parse_err! {
+                peek ['<', alpha] => if cond {
+                    --> foo_state.
+                } else {
+                    bar.
+                }
+            },
+            ERR_LOOKAHEAD_WITHOUT_TRANSITION
+        );
+    }
+
     #[test]
     fn invalid_connector_token_error() {
         assert_eq!(parse_err! { "foo"|i =< bar. }, "expected `=>`");
@@ -1,10 +1,11 @@
 use std::convert::TryFrom;
 use syn::parse::{Parse, ParseStream};
-use syn::{Error as ParseError, LitChar, LitInt, Result as ParseResult};
+use syn::{Error as ParseError, LitByte, LitChar, LitInt, Result as ParseResult};
 
 pub(super) const ERR_CHAR_IS_NOT_ASCII: &str = concat![
     "character should be in the ASCII range.",
-    " For bigger byte values use numeric representation (e.g. 0x1F)"
+    " For bigger byte values use numeric or byte literal",
+    " representation (e.g. 0x1F, b'\\x1F')"
 ];
 
 pub(super) const ERR_INT_IS_OUT_OF_BOUNDS: &str =
@@ -40,6 +41,8 @@ impl Parse for BytePattern {
 
         if lookahead.peek(LitChar) {
             Self::parse_from_char_literal(input)
+        } else if lookahead.peek(LitByte) {
+            input.parse::<LitByte>().map(|l| BytePattern(l.value()))
         } else if lookahead.peek(LitInt) {
             Self::parse_from_int_literal(input)
         } else {
@@ -61,6 +64,13 @@ mod tests {
         assert_eq!(parse_ok! { '0' }, BytePattern(0x30));
     }
 
+    #[test]
+    fn parse_byte_literal() {
+        assert_eq!(parse_ok! { b'a' }, BytePattern(0x61));
+        assert_eq!(parse_ok! { b'\x00' }, BytePattern(0x00));
+        assert_eq!(parse_ok! { b'\xFF' }, BytePattern(0xff));
+    }
+
     #[test]
     fn non_ascii_char_literal_error() {
         assert_eq!(parse_err! { '£' }, ERR_CHAR_IS_NOT_ASCII);
@@ -84,7 +94,7 @@ mod tests {
     fn unexpected_token_error() {
         assert_eq!(
             parse_err! { -3 },
-            "expected character literal or integer literal"
+            "expected one of: character literal, byte literal, integer literal"
         );
     }
 }
@@ -0,0 +1,420 @@
+use super::byte::BytePattern;
+use super::*;
+
+const ERR_EXPECTED_CLASS_KEYWORD: &str = "expected `class`";
+
+const ERR_DUPLICATE_CLASS: &str = "class is already declared for the grammar";
+
+const ERR_RESERVED_CLASS_NAME: &str =
+    "name of the builtin pattern can't be used for the class declaration";
+
+const ERR_UNKNOWN_CLASS: &str = "unknown class";
+
+const ERR_CYCLIC_CLASS: &str = "cyclic class declaration";
+
+#[derive(PartialEq, Debug)]
+enum ClassItem {
+    Name(Ident),
+    Byte(u8),
+}
+
+/// Class declaration of the grammar: `class name = alpha | '_' | 0x24;`.
+#[derive(PartialEq, Debug)]
+pub(crate) struct ClassDeclaration {
+    name: Ident,
+    items: Vec<ClassItem>,
+}
+
+impl Parse for ClassItem {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        if input.peek(Ident) {
+            input.parse().map(ClassItem::Name)
+        } else {
+            input.parse::<BytePattern>().map(|b| ClassItem::Byte(b.0))
+        }
+    }
+}
+
+impl Parse for ClassDeclaration {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        let keyword = input.parse::<Ident>()?;
+
+        if keyword != "class" {
+            return Err(ParseError::new_spanned(keyword, ERR_EXPECTED_CLASS_KEYWORD));
+        }
+
+        let name = input.parse::<Ident>()?;
+        let mut items = vec![];
+
+        input.parse::<Token! { = }>()?;
+
+        loop {
+            items.push(input.parse::<ClassItem>()?);
+
+            if !parse_if_present!(input, { | }) {
+                break;
+            }
+        }
+
+        input.parse::<Token! { ; }>()?;
+
+        Ok(ClassDeclaration { name, items })
+    }
+}
+
+impl ClassDeclaration {
+    /// Checks if the input starts with the class declaration.
+    pub(crate) fn peek(input: ParseStream) -> bool {
+        input.fork().parse::<Ident>().is_ok_and(|i| i == "class") && input.peek2(Ident)
+    }
+}
+
+// NOTE: Levenshtein distance between the strings.
+fn edit_distance(s1: &str, s2: &str) -> usize {
+    let s2 = s2.chars().collect::<Vec<_>>();
+    let mut row = (0..=s2.len()).collect::<Vec<_>>();
+
+    for (i, c1) in s1.chars().enumerate() {
+        let mut diag = row[0];
+
+        row[0] = i + 1;
+
+        for (j, &c2) in s2.iter().enumerate() {
+            let substitution = diag + usize::from(c1 != c2);
+
+            diag = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diag + 1);
+        }
+    }
+
+    row[s2.len()]
+}
+
+/// Returns the candidate that is the closest to the misspelled name, if any.
+pub(super) fn closest_name<'c>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'c str>,
+) -> Option<&'c str> {
+    let max_distance = name.len().div_ceil(3);
+
+    candidates
+        .into_iter()
+        .map(|c| (edit_distance(name, c), c))
+        .filter(|&(d, _)| d <= max_distance)
+        .min_by_key(|&(d, _)| d)
+        .map(|(_, c)| c)
+}
+
+pub(super) fn with_did_you_mean(msg: &str, suggestion: Option<&str>) -> String {
+    match suggestion {
+        Some(s) => format!("{}, did you mean `{}`?", msg, s),
+        None => msg.into(),
+    }
+}
+
+struct Resolver<'d> {
+    declarations: &'d [ClassDeclaration],
+    resolved: Vec<Option<Vec<u8>>>,
+    stack: Vec<usize>,
+}
+
+impl<'d> Resolver<'d> {
+    fn resolve_name(&mut self, name: &Ident) -> ParseResult<Vec<u8>> {
+        let s = name.to_string();
+
+        if let Ok(class) = s.parse::<ClassPattern>() {
+            return Ok((0..=255u8).filter(|&b| class.matches(b)).collect());
+        }
+
+        match self.declarations.iter().position(|d| d.name == s) {
+            Some(idx) => self.resolve(idx),
+            None => {
+                let declared = self
+                    .declarations
+                    .iter()
+                    .map(|d| d.name.to_string())
+                    .collect::<Vec<_>>();
+
+                let suggestion = closest_name(
+                    &s,
+                    BUILTIN_CLASSES
+                        .iter()
+                        .map(|&(n, _)| n)
+                        .chain(declared.iter().map(String::as_str)),
+                );
+
+                Err(ParseError::new_spanned(
+                    name,
+                    with_did_you_mean(ERR_UNKNOWN_CLASS, suggestion),
+                ))
+            }
+        }
+    }
+
+    fn resolve(&mut self, idx: usize) -> ParseResult<Vec<u8>> {
+        if let Some(ref bytes) = self.resolved[idx] {
+            return Ok(bytes.clone());
+        }
+
+        let declaration = &self.declarations[idx];
+
+        if let Some(start) = self.stack.iter().position(|&i| i == idx) {
+            let cycle = self.stack[start..]
+                .iter()
+                .chain(Some(&idx))
+                .map(|&i| self.declarations[i].name.to_string())
+                .collect::<Vec<_>>();
+
+            return Err(ParseError::new_spanned(
+                &declaration.name,
+                format!("{}: {}", ERR_CYCLIC_CLASS, cycle.join(" -> ")),
+            ));
+        }
+
+        self.stack.push(idx);
+
+        let mut bytes = vec![];
+
+        for item in &declaration.items {
+            match item {
+                ClassItem::Byte(b) => bytes.push(*b),
+                ClassItem::Name(name) => bytes.extend(self.resolve_name(name)?),
+            }
+        }
+
+        self.stack.pop();
+
+        bytes.sort_unstable();
+        bytes.dedup();
+
+        self.resolved[idx] = Some(bytes.clone());
+
+        Ok(bytes)
+    }
+}
+
+/// Resolves the class declarations of the grammar into the sets of bytes.
+/// Declarations can refer to the builtin classes and to each other in any order.
+pub(crate) fn resolve_class_declarations(
+    declarations: &[ClassDeclaration],
+) -> ParseResult<Vec<NamedClass>> {
+    for (i, declaration) in declarations.iter().enumerate() {
+        let name = declaration.name.to_string();
+
+        if name.parse::<ClassPattern>().is_ok()
+            || name.parse::<InputStatePattern>().is_ok()
+            || PATTERN_KEYWORDS.contains(&name.as_str())
+        {
+            return Err(ParseError::new_spanned(
+                &declaration.name,
+                ERR_RESERVED_CLASS_NAME,
+            ));
+        }
+
+        if declarations[..i].iter().any(|d| d.name == name) {
+            return Err(ParseError::new_spanned(
+                &declaration.name,
+                ERR_DUPLICATE_CLASS,
+            ));
+        }
+    }
+
+    let mut resolver = Resolver {
+        declarations,
+        resolved: vec![None; declarations.len()],
+        stack: vec![],
+    };
+
+    (0..declarations.len())
+        .map(|i| {
+            Ok(NamedClass {
+                name: declarations[i].name.to_string(),
+                bytes: resolver.resolve(i)?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grammar, Pattern};
+
+    curry_parse_macros!($Grammar);
+
+    fn class_patterns(grammar: &Grammar) -> Vec<&ClassPattern> {
+        grammar.states[0]
+            .arms
+            .iter()
+            .filter_map(|a| match a.pattern {
+                Pattern::Class(ref c) => Some(c),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parse_class_declarations() {
+        let grammar = parse_ok! {
+            TestGrammar = {
+                class ident = ident_start | digit;
+                type Error = Foo;
+                class ident_start = lower | '_' | 0x24;
+
+                foo_state:
+                    ident_start => foo.
+                    ident => bar.
+                    alpha => baz.
+            }
+        };
+
+        let ident_start = ClassPattern::Named(NamedClass {
+            name: "ident_start".into(),
+            bytes: b"$_abcdefghijklmnopqrstuvwxyz".to_vec(),
+        });
+
+        let ident = ClassPattern::Named(NamedClass {
+            name: "ident".into(),
+            bytes: b"$0123456789_abcdefghijklmnopqrstuvwxyz".to_vec(),
+        });
+
+        assert_eq!(
+            class_patterns(&grammar),
+            vec![&ident_start, &ident, &ClassPattern::Alpha]
+        );
+
+        assert_eq!(
+            grammar
+                .classes
+                .iter()
+                .map(|c| c.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["ident", "ident_start"]
+        );
+    }
+
+    #[test]
+    fn class_keyword_error() {
+        assert_eq!(
+            syn::parse_str::<ClassDeclaration>("klass foo = 'a';")
+                .unwrap_err()
+                .to_string(),
+            ERR_EXPECTED_CLASS_KEYWORD
+        );
+    }
+
+    #[test]
+    fn cyclic_class_declaration_error() {
+        assert_eq!(
+            parse_err! {
+                TestGrammar = {
+                    class foo = bar | 'a';
+                    class bar = digit | baz;
+                    class baz = foo;
+
+                    foo_state:
+                        foo => foo.
+                }
+            },
+            "cyclic class declaration: foo -> bar -> baz -> foo"
+        );
+    }
+
+    #[test]
+    fn unknown_class_error() {
+        assert_eq!(
+            parse_err! {
+                TestGrammar = {
+                    class ident_start = alpha | '_';
+                    class ident = ident_strat | digit;
+
+                    foo_state:
+                        ident => foo.
+                }
+            },
+            "unknown class, did you mean `ident_start`?"
+        );
+
+        assert_eq!(
+            parse_err! {
+                TestGrammar = {
+                    class ident = foobar | digit;
+
+                    foo_state:
+                        ident => foo.
+                }
+            },
+            ERR_UNKNOWN_CLASS
+        );
+    }
+
+    #[test]
+    fn unknown_named_class_pattern_error() {
+        assert_eq!(
+            parse_err! {
+                TestGrammar = {
+                    class ident_start = alpha | '_';
+
+                    foo_state:
+                        ident_stat => foo.
+                }
+            },
+            "unknown pattern, did you mean `ident_start`?"
+        );
+    }
+
+    #[test]
+    fn duplicate_class_error() {
+        assert_eq!(
+            parse_err! {
+                TestGrammar = {
+                    class foo = 'a';
+                    class foo = 'b';
+
+                    foo_state:
+                        foo => foo.
+                }
+            },
+            ERR_DUPLICATE_CLASS
+        );
+    }
+
+    #[test]
+    fn reserved_class_name_error() {
+        assert_eq!(
+            parse_err! {
+                TestGrammar = {
+                    class alpha = 'a';
+
+                    foo_state:
+                        alpha => foo.
+                }
+            },
+            ERR_RESERVED_CLASS_NAME
+        );
+
+        assert_eq!(
+            parse_err! {
+                TestGrammar = {
+                    class eof = 'a';
+
+                    foo_state:
+                        eof => foo.
+                }
+            },
+            ERR_RESERVED_CLASS_NAME
+        );
+
+        assert_eq!(
+            parse_err! {
+                TestGrammar = {
+                    class unicode = 'a';
+
+                    foo_state:
+                        'a' => foo.
+                }
+            },
+            ERR_RESERVED_CLASS_NAME
+        );
+    }
+}
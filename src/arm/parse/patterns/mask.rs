@@ -0,0 +1,129 @@
+use super::*;
+use syn::parenthesized;
+
+const ERR_INVALID_MASK_DIGITS: &str = concat![
+    "bit mask should have 8 binary digits, where `x` is a don't-care bit",
+    " (e.g. mask(\"110x_xxxx\"))"
+];
+
+const ERR_VALUE_OUTSIDE_MASK: &str =
+    "value of the bit mask has bits that are not in the mask, so the pattern never matches";
+
+/// Parses digits of the bit mask with `x` don't-care bits (e.g. `110x_xxxx`).
+fn parse_binary_mask(digits: &str) -> Option<MaskPattern> {
+    let digits = digits.chars().filter(|&c| c != '_').collect::<Vec<_>>();
+
+    if digits.len() != 8 {
+        return None;
+    }
+
+    digits
+        .iter()
+        .try_fold(MaskPattern { mask: 0, value: 0 }, |m, &d| {
+            let (mask_bit, value_bit) = match d {
+                '0' => (1, 0),
+                '1' => (1, 1),
+                'x' => (0, 0),
+                _ => return None,
+            };
+
+            Some(MaskPattern {
+                mask: m.mask << 1 | mask_bit,
+                value: m.value << 1 | value_bit,
+            })
+        })
+}
+
+/// Parses the digits of the `mask("...")` pattern.
+pub(super) fn parse_mask_digits(input: ParseStream) -> ParseResult<MaskPattern> {
+    let parens_content;
+
+    parenthesized!(parens_content in input);
+
+    let lit = parens_content.parse::<LitStr>()?;
+
+    parse_binary_mask(&lit.value())
+        .ok_or_else(|| ParseError::new_spanned(lit, ERR_INVALID_MASK_DIGITS))
+}
+
+impl MaskPattern {
+    /// Checks if the input starts with the `mask/value` pair.
+    pub(super) fn peek(input: ParseStream) -> bool {
+        input.peek(LitInt) && input.peek2(Token! { / })
+    }
+}
+
+impl Parse for MaskPattern {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        let mask = input.parse::<BytePattern>()?.0;
+
+        input.parse::<Token! { / }>()?;
+
+        let value_span = input.cursor().span();
+        let value = input.parse::<BytePattern>()?.0;
+
+        if value & !mask != 0 {
+            Err(ParseError::new(value_span, ERR_VALUE_OUTSIDE_MASK))
+        } else {
+            Ok(MaskPattern { mask, value })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    curry_parse_macros!($Pattern);
+
+    #[test]
+    fn parse_mask_digits() {
+        assert_eq!(
+            parse_ok! { mask("110x_xxxx") },
+            Pattern::Mask(MaskPattern {
+                mask: 0xe0,
+                value: 0xc0
+            })
+        );
+
+        assert_eq!(
+            parse_ok! { mask("x1x0xx01") },
+            Pattern::Mask(MaskPattern {
+                mask: 0x53,
+                value: 0x41
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_mask_digits_error() {
+        assert_eq!(parse_err! { mask("110x_xxx") }, ERR_INVALID_MASK_DIGITS);
+        assert_eq!(parse_err! { mask("110x_xxxx_x") }, ERR_INVALID_MASK_DIGITS);
+        assert_eq!(parse_err! { mask("110x_xxx2") }, ERR_INVALID_MASK_DIGITS);
+        assert_eq!(parse_err! { mask(110) }, "expected string literal");
+    }
+
+    #[test]
+    fn parse_mask_value_pair() {
+        assert_eq!(
+            parse_ok! { 0xE0/0xC0 },
+            Pattern::Mask(MaskPattern {
+                mask: 0xe0,
+                value: 0xc0
+            })
+        );
+
+        assert_eq!(
+            parse_ok! { 0x0F/b'\x00' },
+            Pattern::Mask(MaskPattern {
+                mask: 0x0f,
+                value: 0x00
+            })
+        );
+    }
+
+    #[test]
+    fn value_outside_mask_error() {
+        assert_eq!(parse_err! { 0xE0/0xC1 }, ERR_VALUE_OUTSIDE_MASK);
+    }
+}
@@ -1,33 +1,65 @@
 mod byte;
+mod class_declaration;
+mod mask;
+mod range;
 mod sequence;
+mod unicode;
 
 use syn::parse::{Parse, ParseStream};
 use syn::token::Bracket;
-use syn::{Error as ParseError, Ident, LitChar, LitInt, LitStr, Result as ParseResult, Token};
+use syn::{
+    Error as ParseError, Ident, LitByte, LitByteStr, LitChar, LitInt, LitStr,
+    Result as ParseResult, Token,
+};
 
 use self::byte::BytePattern;
+use self::class_declaration::{closest_name, with_did_you_mean};
+use self::mask::parse_mask_digits;
+use self::range::parse_byte_range;
+use self::unicode::parse_unicode_property;
 use super::*;
 use std::str::FromStr;
 
+pub(crate) use self::class_declaration::{resolve_class_declarations, ClassDeclaration};
+
 const ERR_UNKNOWN_PATTERN: &str = "unknown pattern";
 
+const ERR_UNSUPPORTED_FLAG: &str =
+    "unsupported pattern flag. Only ignore case flag (`i`) is currently supported";
+
+const BUILTIN_CLASSES: &[(&str, ClassPattern)] = &[
+    ("alnum", ClassPattern::Alnum),
+    ("alpha", ClassPattern::Alpha),
+    ("ascii", ClassPattern::Ascii),
+    ("lower", ClassPattern::Lower),
+    ("upper", ClassPattern::Upper),
+    ("digit", ClassPattern::Digit),
+    ("xdigit", ClassPattern::Xdigit),
+    ("odigit", ClassPattern::Odigit),
+    ("bdigit", ClassPattern::Bdigit),
+    ("space", ClassPattern::Space),
+    ("blank", ClassPattern::Blank),
+    ("punct", ClassPattern::Punct),
+    ("graph", ClassPattern::Graph),
+    ("print", ClassPattern::Print),
+    ("cntrl", ClassPattern::Cntrl),
+    ("non_ascii", ClassPattern::NonAscii),
+];
+
+const INPUT_STATES: &[&str] = &["eoc", "eof"];
+
+// NOTE: identifiers of the patterns that are not classes or input states.
+const PATTERN_KEYWORDS: &[&str] = &["unicode", "invalid_utf8", "mask", "peek"];
+
 impl FromStr for ClassPattern {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        use ClassPattern::*;
-
-        match s {
-            "alnum" => Ok(Alnum),
-            "alpha" => Ok(Alpha),
-            "ascii" => Ok(Ascii),
-            "lower" => Ok(Lower),
-            "upper" => Ok(Upper),
-            "digit" => Ok(Digit),
-            "xdigit" => Ok(Xdigit),
-            "space" => Ok(Space),
-            _ => Err(()),
-        }
+        BUILTIN_CLASSES
+            .iter()
+            .find(|&&(name, _)| name == s)
+            .map(|(_, class)| class.clone())
+            .ok_or(())
     }
 }
 
@@ -45,30 +77,98 @@ impl FromStr for InputStatePattern {
     }
 }
 
+/// Parses the flag of the pattern (e.g. `|i`), if present.
+fn parse_flag(input: ParseStream) -> ParseResult<Option<Ident>> {
+    if !parse_if_present!(input, { | }) {
+        return Ok(None);
+    }
+
+    let flag = input.parse::<Ident>()?;
+
+    if flag == "i" {
+        Ok(Some(flag))
+    } else {
+        Err(ParseError::new_spanned(flag, ERR_UNSUPPORTED_FLAG))
+    }
+}
+
+fn class_from_name(name: &str, classes: &[NamedClass]) -> Option<ClassPattern> {
+    name.parse::<ClassPattern>().ok().or_else(|| {
+        classes
+            .iter()
+            .find(|c| c.name == name)
+            .cloned()
+            .map(ClassPattern::Named)
+    })
+}
+
 impl Pattern {
-    fn parse_from_ident(input: ParseStream) -> ParseResult<Self> {
+    fn parse_from_ident(input: ParseStream, classes: &[NamedClass]) -> ParseResult<Self> {
         let ident = input.parse::<Ident>()?;
         let s = ident.to_string();
 
-        s.parse::<ClassPattern>()
+        match s.as_str() {
+            "unicode" => return parse_unicode_property(input).map(Pattern::Unicode),
+            "invalid_utf8" => return Ok(Pattern::InvalidUtf8),
+            "mask" => return parse_mask_digits(input).map(Pattern::Mask),
+            "peek" => {
+                return SequencePattern::parse_with_classes(input, classes).map(|s| {
+                    Pattern::Sequence(SequencePattern {
+                        lookahead: true,
+                        ..s
+                    })
+                })
+            }
+            _ => (),
+        }
+
+        class_from_name(&s, classes)
+            .ok_or(())
             .map(Pattern::Class)
             .or_else(|_| s.parse::<InputStatePattern>().map(Pattern::InputState))
-            .map_err(|_| ParseError::new_spanned(ident, ERR_UNKNOWN_PATTERN))
+            .map_err(|_| {
+                let suggestion = closest_name(
+                    &s,
+                    BUILTIN_CLASSES
+                        .iter()
+                        .map(|&(n, _)| n)
+                        .chain(INPUT_STATES.iter().cloned())
+                        .chain(PATTERN_KEYWORDS.iter().cloned())
+                        .chain(classes.iter().map(|c| c.name.as_str())),
+                );
+
+                ParseError::new_spanned(ident, with_did_you_mean(ERR_UNKNOWN_PATTERN, suggestion))
+            })
     }
 }
 
-impl Parse for Pattern {
-    fn parse(input: ParseStream) -> ParseResult<Self> {
+impl Pattern {
+    /// Parses the pattern that can refer to the classes declared in the grammar.
+    pub(crate) fn parse_with_classes(
+        input: ParseStream,
+        classes: &[NamedClass],
+    ) -> ParseResult<Self> {
         let lookahead = input.lookahead1();
 
         if parse3_if_present!(input, { - }, { - }, { > }) {
             Ok(Pattern::StateEnter)
-        } else if lookahead.peek(LitChar) || lookahead.peek(LitInt) {
-            input.parse::<BytePattern>().map(|b| Pattern::Byte(b.0))
-        } else if lookahead.peek(LitStr) || lookahead.peek(Bracket) {
-            input.parse::<SequencePattern>().map(Pattern::Sequence)
+        } else if MaskPattern::peek(input) {
+            input.parse::<MaskPattern>().map(Pattern::Mask)
+        } else if lookahead.peek(LitChar) || lookahead.peek(LitByte) || lookahead.peek(LitInt) {
+            input.parse::<RangePattern>().map(|r| {
+                if r.start == r.end {
+                    Pattern::Byte {
+                        byte: r.start,
+                        ignore_case: r.ignore_case,
+                    }
+                } else {
+                    Pattern::Range(r)
+                }
+            })
+        } else if lookahead.peek(LitStr) || lookahead.peek(LitByteStr) || lookahead.peek(Bracket) {
+            SequencePattern::parse_with_classes(input, classes).map(Pattern::Sequence)
         } else if lookahead.peek(Ident) {
-            Self::parse_from_ident(input)
+            Self::parse_from_ident(input, classes)
         } else if lookahead.peek(Token! { _ }) {
             input.parse::<Token! { _ }>()?;
 
@@ -84,37 +184,90 @@ impl Parse for Pattern {
     }
 }
 
+impl Parse for Pattern {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        Self::parse_with_classes(input, &[])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use syn::parse::Parser;
 
     curry_parse_macros!($Pattern);
 
     #[test]
     fn parse_byte_pattern() {
-        assert_eq!(parse_ok! { 'a' }, Pattern::Byte(0x61));
-        assert_eq!(parse_ok! { 0x61 }, Pattern::Byte(0x61));
+        let byte = |byte, ignore_case| Pattern::Byte { byte, ignore_case };
+
+        assert_eq!(parse_ok! { 'a' }, byte(0x61, false));
+        assert_eq!(parse_ok! { 0x61 }, byte(0x61, false));
+        assert_eq!(parse_ok! { 'x'|i }, byte(b'x', true));
     }
 
     #[test]
-    fn parse_seq_pattern() {
+    fn parse_range_pattern() {
         assert_eq!(
-            parse_ok! { "FooBar"|i },
-            Pattern::Sequence(SequencePattern {
-                bytes: vec![0x46, 0x6f, 0x6f, 0x42, 0x61, 0x72],
-                ignore_case: true
+            parse_ok! { 'a'..='f' },
+            Pattern::Range(RangePattern {
+                start: b'a',
+                end: b'f',
+                ignore_case: false
             })
         );
 
         assert_eq!(
-            parse_ok! { [1, 2, 0x03]|i },
-            Pattern::Sequence(SequencePattern {
-                bytes: vec![0x01, 0x02, 0x03],
+            parse_ok! { 'a'..='a'|i },
+            Pattern::Byte {
+                byte: b'a',
                 ignore_case: true
+            }
+        );
+    }
+
+    #[test]
+    fn parse_mask_pattern() {
+        assert_eq!(
+            parse_ok! { 0xE0/0xC0 },
+            Pattern::Mask(MaskPattern {
+                mask: 0xe0,
+                value: 0xc0
             })
         );
     }
 
+    #[test]
+    fn parse_byte_literal_pattern() {
+        assert_eq!(
+            parse_ok! { b'\x00' },
+            Pattern::Byte {
+                byte: 0x00,
+                ignore_case: false
+            }
+        );
+        assert_eq!(
+            parse_ok! { b"\xEF\xBB\xBF" },
+            Pattern::Sequence(SequencePattern::from_bytes(&[0xef, 0xbb, 0xbf], false))
+        );
+    }
+
+    #[test]
+    fn parse_seq_pattern() {
+        assert_eq!(
+            parse_ok! { "FooBar"|i },
+            Pattern::Sequence(SequencePattern::from_bytes(
+                &[0x46, 0x6f, 0x6f, 0x42, 0x61, 0x72],
+                true
+            ))
+        );
+
+        assert_eq!(
+            parse_ok! { [1, 2, 0x03]|i },
+            Pattern::Sequence(SequencePattern::from_bytes(&[0x01, 0x02, 0x03], true))
+        );
+    }
+
     #[test]
     fn parse_class_pattern() {
         assert_eq!(parse_ok! { alnum }, Pattern::Class(ClassPattern::Alnum));
@@ -124,7 +277,18 @@ mod tests {
         assert_eq!(parse_ok! { upper }, Pattern::Class(ClassPattern::Upper));
         assert_eq!(parse_ok! { digit }, Pattern::Class(ClassPattern::Digit));
         assert_eq!(parse_ok! { xdigit }, Pattern::Class(ClassPattern::Xdigit));
+        assert_eq!(parse_ok! { odigit }, Pattern::Class(ClassPattern::Odigit));
+        assert_eq!(parse_ok! { bdigit }, Pattern::Class(ClassPattern::Bdigit));
         assert_eq!(parse_ok! { space }, Pattern::Class(ClassPattern::Space));
+        assert_eq!(parse_ok! { blank }, Pattern::Class(ClassPattern::Blank));
+        assert_eq!(parse_ok! { punct }, Pattern::Class(ClassPattern::Punct));
+        assert_eq!(parse_ok! { graph }, Pattern::Class(ClassPattern::Graph));
+        assert_eq!(parse_ok! { print }, Pattern::Class(ClassPattern::Print));
+        assert_eq!(parse_ok! { cntrl }, Pattern::Class(ClassPattern::Cntrl));
+        assert_eq!(
+            parse_ok! { non_ascii },
+            Pattern::Class(ClassPattern::NonAscii)
+        );
     }
 
     #[test]
@@ -164,13 +328,40 @@ mod tests {
         assert_eq!(parse_err! { foobar }, ERR_UNKNOWN_PATTERN);
     }
 
+    #[test]
+    fn unknown_pattern_suggestion() {
+        assert_eq!(
+            parse_err! { alpah },
+            "unknown pattern, did you mean `alpha`?"
+        );
+
+        assert_eq!(parse_err! { eos }, "unknown pattern, did you mean `eoc`?");
+    }
+
+    #[test]
+    fn parse_named_class_pattern() {
+        let classes = vec![NamedClass {
+            name: "ident_start".into(),
+            bytes: vec![b'_'],
+        }];
+
+        let parse = |input: ParseStream| Pattern::parse_with_classes(input, &classes);
+
+        assert_eq!(
+            parse.parse_str("ident_start").unwrap(),
+            Pattern::Class(ClassPattern::Named(classes[0].clone()))
+        );
+
+        assert_eq!(parse_err! { ident_start }, ERR_UNKNOWN_PATTERN);
+    }
+
     #[test]
     fn unexpected_token_error() {
         assert_eq!(
             parse_err! { -3 },
             concat![
-                "expected one of: character literal, integer literal, ",
-                "string literal, square brackets, identifier, `_`, `if`"
+                "expected one of: character literal, byte literal, integer literal, ",
+                "string literal, byte string literal, square brackets, identifier, `_`, `if`"
             ]
         );
     }
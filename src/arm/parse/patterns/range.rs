@@ -0,0 +1,115 @@
+use super::*;
+
+pub(super) const ERR_EMPTY_RANGE: &str = "byte range should not be empty";
+
+const ERR_IGNORE_CASE_WITHOUT_LETTERS: &str = concat![
+    "ignore case flag (`i`) has no effect, since the pattern",
+    " doesn't match any ASCII letters"
+];
+
+/// Parses the byte (`'a'`) or the range of bytes (`'a'..='f'`). Single bytes
+/// are returned as the ranges that start and end with the byte.
+pub(super) fn parse_byte_range(input: ParseStream) -> ParseResult<(u8, u8)> {
+    let start = input.parse::<BytePattern>()?.0;
+
+    if !parse_if_present!(input, { ..= }) {
+        return Ok((start, start));
+    }
+
+    let end_span = input.cursor().span();
+    let end = input.parse::<BytePattern>()?.0;
+
+    if start > end {
+        Err(ParseError::new(end_span, ERR_EMPTY_RANGE))
+    } else {
+        Ok((start, end))
+    }
+}
+
+impl Parse for RangePattern {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        let (start, end) = parse_byte_range(input)?;
+
+        let ignore_case = match parse_flag(input)? {
+            Some(flag) if !(start..=end).any(|b| b.is_ascii_alphabetic()) => {
+                return Err(ParseError::new_spanned(
+                    flag,
+                    ERR_IGNORE_CASE_WITHOUT_LETTERS,
+                ));
+            }
+            Some(_) => true,
+            None => false,
+        };
+
+        Ok(RangePattern {
+            start,
+            end,
+            ignore_case,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    curry_parse_macros!($RangePattern);
+
+    #[test]
+    fn parse_range() {
+        assert_eq!(
+            parse_ok! { 'a'..='f' },
+            RangePattern {
+                start: b'a',
+                end: b'f',
+                ignore_case: false
+            }
+        );
+
+        assert_eq!(
+            parse_ok! { 0x00..=b'\x1F' },
+            RangePattern {
+                start: 0x00,
+                end: 0x1f,
+                ignore_case: false
+            }
+        );
+    }
+
+    #[test]
+    fn parse_ignore_case_flag() {
+        assert_eq!(
+            parse_ok! { 'x'|i },
+            RangePattern {
+                start: b'x',
+                end: b'x',
+                ignore_case: true
+            }
+        );
+
+        assert_eq!(
+            parse_ok! { '0'..='F'|i },
+            RangePattern {
+                start: b'0',
+                end: b'F',
+                ignore_case: true
+            }
+        );
+    }
+
+    #[test]
+    fn empty_range_error() {
+        assert_eq!(parse_err! { 'f'..='a' }, ERR_EMPTY_RANGE);
+    }
+
+    #[test]
+    fn ignore_case_without_letters_error() {
+        assert_eq!(parse_err! { '-'|i }, ERR_IGNORE_CASE_WITHOUT_LETTERS);
+        assert_eq!(parse_err! { '0'..='9'|i }, ERR_IGNORE_CASE_WITHOUT_LETTERS);
+    }
+
+    #[test]
+    fn unsupported_flag_error() {
+        assert_eq!(parse_err! { 'a'..='z'|s }, ERR_UNSUPPORTED_FLAG);
+    }
+}
@@ -1,84 +1,175 @@
 use super::*;
+use proc_macro2::Span;
 use syn::parse::{Parse, ParseStream};
 use syn::token::Bracket;
-use syn::{bracketed, Error as ParseError, Ident, LitStr, Result as ParseResult, Token};
+use syn::{
+    bracketed, Error as ParseError, Ident, LitByteStr, LitStr, Result as ParseResult, Token,
+};
 
-const ERR_STR_IS_NOT_ASCII: &str = concat![
-    "characters in string sequence pattern should be in the ASCII range.",
-    " Use array sequence patterns instead (e.g. ['f', 0x00, 'O'])"
+const ERR_IGNORE_CASE_IS_NOT_ASCII: &str = concat![
+    "ignore case flag (`i`) can be used only with the sequences of ASCII bytes.",
+    " Non-ASCII characters of the string are matched as their UTF-8 bytes"
 ];
 
-const ERR_UNSUPPORTED_FLAG: &str =
-    "unsupported sequence flag. Only ignore case flag (`i`) is currently supported";
+const ERR_UNKNOWN_SEQUENCE_CLASS: &str = "unknown class in sequence pattern";
 
-fn parse_ignore_case_flag(input: ParseStream) -> ParseResult<bool> {
-    if input.lookahead1().peek(Token! { | }) {
-        input.parse::<Token! { | }>()?;
+const ERR_EMPTY_SEQUENCE: &str = "sequence pattern should not be empty";
 
-        let flag_ident = input.parse::<Ident>()?;
-        let flag = flag_ident.to_string();
+// NOTE: case of the non-ASCII bytes is ambiguous (they can be parts of the
+// UTF-8 characters or single bytes of some other encoding), so ignore case
+// matching is supported only for the ASCII sequences.
+fn parse_ignore_case_flag(input: ParseStream, elements: &[SequenceElement]) -> ParseResult<bool> {
+    match parse_flag(input)? {
+        Some(flag) if !elements.iter().all(is_ascii_element) => {
+            Err(ParseError::new_spanned(flag, ERR_IGNORE_CASE_IS_NOT_ASCII))
+        }
+        Some(_) => Ok(true),
+        None => Ok(false),
+    }
+}
+
+fn is_ascii_element(element: &SequenceElement) -> bool {
+    match element {
+        SequenceElement::Byte(b) => b.is_ascii(),
+        SequenceElement::Range(start, end) => start.is_ascii() && end.is_ascii(),
+        SequenceElement::Class(_) | SequenceElement::Any => true,
+    }
+}
+
+fn parse_class_element(input: ParseStream, classes: &[NamedClass]) -> ParseResult<SequenceElement> {
+    let ident = input.parse::<Ident>()?;
+    let s = ident.to_string();
+
+    match class_from_name(&s, classes) {
+        Some(class) => Ok(SequenceElement::Class(class)),
+        None => {
+            let suggestion = closest_name(
+                &s,
+                BUILTIN_CLASSES
+                    .iter()
+                    .map(|&(n, _)| n)
+                    .chain(classes.iter().map(|c| c.name.as_str())),
+            );
+
+            Err(ParseError::new_spanned(
+                ident,
+                with_did_you_mean(ERR_UNKNOWN_SEQUENCE_CLASS, suggestion),
+            ))
+        }
+    }
+}
 
-        if flag == "i" {
-            Ok(true)
+impl SequenceElement {
+    fn parse_with_classes(input: ParseStream, classes: &[NamedClass]) -> ParseResult<Self> {
+        if input.peek(Token! { _ }) {
+            input.parse::<Token! { _ }>()?;
+
+            Ok(SequenceElement::Any)
+        } else if input.peek(Ident) {
+            parse_class_element(input, classes)
         } else {
-            Err(ParseError::new_spanned(flag_ident, ERR_UNSUPPORTED_FLAG))
+            match parse_byte_range(input)? {
+                (start, end) if start == end => Ok(SequenceElement::Byte(start)),
+                (start, end) => Ok(SequenceElement::Range(start, end)),
+            }
         }
-    } else {
-        Ok(false)
     }
 }
 
 impl SequencePattern {
-    fn parse_from_str_literal(input: ParseStream) -> ParseResult<Self> {
-        let lit = input.parse::<LitStr>()?;
-        let string = lit.value();
-
-        if string.is_ascii() {
-            Ok(SequencePattern {
-                bytes: string.into_bytes(),
-                ignore_case: parse_ignore_case_flag(input)?,
-            })
-        } else {
-            Err(ParseError::new_spanned(lit, ERR_STR_IS_NOT_ASCII))
+    // NOTE: empty sequence matches without consuming the input,
+    // so the parser would loop forever in the state with it.
+    fn parse_with_flag(
+        input: ParseStream,
+        span: Span,
+        elements: Vec<SequenceElement>,
+    ) -> ParseResult<Self> {
+        if elements.is_empty() {
+            return Err(ParseError::new(span, ERR_EMPTY_SEQUENCE));
         }
+
+        Ok(SequencePattern {
+            ignore_case: parse_ignore_case_flag(input, &elements)?,
+            elements,
+            lookahead: false,
+        })
+    }
+
+    // NOTE: non-ASCII characters are matched as their UTF-8 encoding.
+    fn parse_from_str_literal(input: ParseStream) -> ParseResult<Self> {
+        let span = input.cursor().span();
+        let bytes = input.parse::<LitStr>()?.value().into_bytes();
+
+        Self::parse_with_flag(
+            input,
+            span,
+            SequencePattern::from_bytes(&bytes, false).elements,
+        )
     }
 
-    fn parse_from_array(input: ParseStream) -> ParseResult<Self> {
+    fn parse_from_byte_str_literal(input: ParseStream) -> ParseResult<Self> {
+        let span = input.cursor().span();
+        let bytes = input.parse::<LitByteStr>()?.value();
+
+        Self::parse_with_flag(
+            input,
+            span,
+            SequencePattern::from_bytes(&bytes, false).elements,
+        )
+    }
+
+    fn parse_from_array(input: ParseStream, classes: &[NamedClass]) -> ParseResult<Self> {
+        let span = input.cursor().span();
         let brackets_content;
+        let mut elements = vec![];
 
         bracketed!(brackets_content in input);
 
-        let bytes = brackets_content
-            .parse_terminated::<_, Token! { , }>(BytePattern::parse)?
-            .iter()
-            .map(|p| p.0)
-            .collect();
+        while !brackets_content.is_empty() {
+            elements.push(SequenceElement::parse_with_classes(
+                &brackets_content,
+                classes,
+            )?);
 
-        Ok(SequencePattern {
-            bytes,
-            ignore_case: parse_ignore_case_flag(input)?,
-        })
+            if !brackets_content.is_empty() {
+                brackets_content.parse::<Token! { , }>()?;
+            }
+        }
+
+        Self::parse_with_flag(input, span, elements)
     }
-}
 
-impl Parse for SequencePattern {
-    fn parse(input: ParseStream) -> ParseResult<Self> {
+    /// Parses the sequence that can refer to the classes declared in the grammar.
+    pub(crate) fn parse_with_classes(
+        input: ParseStream,
+        classes: &[NamedClass],
+    ) -> ParseResult<Self> {
         let lookahead = input.lookahead1();
 
         if lookahead.peek(LitStr) {
             Self::parse_from_str_literal(input)
+        } else if lookahead.peek(LitByteStr) {
+            Self::parse_from_byte_str_literal(input)
         } else if lookahead.peek(Bracket) {
-            Self::parse_from_array(input)
+            Self::parse_from_array(input, classes)
         } else {
             Err(lookahead.error())
         }
     }
 }
 
+impl Parse for SequencePattern {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        Self::parse_with_classes(input, &[])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::byte::{ERR_CHAR_IS_NOT_ASCII, ERR_INT_IS_OUT_OF_BOUNDS};
+    use super::range::ERR_EMPTY_RANGE;
     use super::*;
+    use syn::parse::Parser;
 
     curry_parse_macros!($SequencePattern);
 
@@ -86,46 +177,132 @@ mod tests {
     fn parse_str_literal() {
         assert_eq!(
             parse_ok! { "FooBar" },
-            SequencePattern {
-                bytes: vec![0x46, 0x6f, 0x6f, 0x42, 0x61, 0x72],
-                ignore_case: false
-            }
+            SequencePattern::from_bytes(&[0x46, 0x6f, 0x6f, 0x42, 0x61, 0x72], false)
         );
 
         assert_eq!(
             parse_ok! { "QuXQuZ"|i },
-            SequencePattern {
-                bytes: vec![0x51, 0x75, 0x58, 0x51, 0x75, 0x5a],
-                ignore_case: true
-            }
+            SequencePattern::from_bytes(&[0x51, 0x75, 0x58, 0x51, 0x75, 0x5a], true)
+        );
+    }
+
+    #[test]
+    fn parse_utf8_str_literal() {
+        assert_eq!(
+            parse_ok! { "\u{feff}" },
+            SequencePattern::from_bytes(&[0xef, 0xbb, 0xbf], false)
+        );
+
+        assert_eq!(
+            parse_ok! { "Foo™" },
+            SequencePattern::from_bytes(&[0x46, 0x6f, 0x6f, 0xe2, 0x84, 0xa2], false)
         );
     }
 
     #[test]
-    fn parse_non_ascii_char_literal_error() {
-        assert_eq!(parse_err! { "Foo™Bar" }, ERR_STR_IS_NOT_ASCII);
-        assert_eq!(parse_err! { "💖 yo 💖" }, ERR_STR_IS_NOT_ASCII);
+    fn parse_byte_str_literal() {
+        assert_eq!(
+            parse_ok! { b"\xEF\xBB\xBF" },
+            SequencePattern::from_bytes(&[0xef, 0xbb, 0xbf], false)
+        );
+
+        assert_eq!(
+            parse_ok! { b"Foo\x00"|i },
+            SequencePattern::from_bytes(&[0x46, 0x6f, 0x6f, 0x00], true)
+        );
+    }
+
+    #[test]
+    fn non_ascii_ignore_case_error() {
+        assert_eq!(parse_err! { "Foo™"|i }, ERR_IGNORE_CASE_IS_NOT_ASCII);
+        assert_eq!(parse_err! { b"\xFF"|i }, ERR_IGNORE_CASE_IS_NOT_ASCII);
+        assert_eq!(parse_err! { ['f', 0x80]|i }, ERR_IGNORE_CASE_IS_NOT_ASCII);
     }
 
     #[test]
     fn parse_array() {
         assert_eq!(
             parse_ok! { [0x46, 'o', 111u64, 0x42, 'a', 0x72] },
+            SequencePattern::from_bytes(&[0x46, 0x6f, 0x6f, 0x42, 0x61, 0x72], false)
+        );
+
+        assert_eq!(
+            parse_ok! { [0x51, 'u', 0x58, 0x51, 'u', 0x5a]|i },
+            SequencePattern::from_bytes(&[0x51, 0x75, 0x58, 0x51, 0x75, 0x5a], true)
+        );
+    }
+
+    #[test]
+    fn parse_array_with_elements() {
+        assert_eq!(
+            parse_ok! { ['<', '/', alpha, 'a'..='f', 0x00..=0x1f, _]|i },
             SequencePattern {
-                bytes: vec![0x46, 0x6f, 0x6f, 0x42, 0x61, 0x72],
-                ignore_case: false
+                elements: vec![
+                    SequenceElement::Byte(b'<'),
+                    SequenceElement::Byte(b'/'),
+                    SequenceElement::Class(ClassPattern::Alpha),
+                    SequenceElement::Range(b'a', b'f'),
+                    SequenceElement::Range(0x00, 0x1f),
+                    SequenceElement::Any,
+                ],
+                ignore_case: true,
+                lookahead: false
             }
         );
+    }
+
+    #[test]
+    fn parse_array_with_named_class() {
+        let classes = vec![NamedClass {
+            name: "ident_start".into(),
+            bytes: vec![b'_'],
+        }];
+
+        let parse = |input: ParseStream| SequencePattern::parse_with_classes(input, &classes);
 
         assert_eq!(
-            parse_ok! { [0x51, 'u', 0x58, 0x51, 'u', 0x5a]|i },
+            parse.parse_str("['$', ident_start]").unwrap(),
             SequencePattern {
-                bytes: vec![0x51, 0x75, 0x58, 0x51, 0x75, 0x5a],
-                ignore_case: true
+                elements: vec![
+                    SequenceElement::Byte(b'$'),
+                    SequenceElement::Class(ClassPattern::Named(classes[0].clone())),
+                ],
+                ignore_case: false,
+                lookahead: false
             }
         );
     }
 
+    #[test]
+    fn empty_range_error() {
+        assert_eq!(parse_err! { ['f'..='a'] }, ERR_EMPTY_RANGE);
+    }
+
+    #[test]
+    fn empty_sequence_error() {
+        assert_eq!(parse_err! { "" }, ERR_EMPTY_SEQUENCE);
+        assert_eq!(parse_err! { b""|i }, ERR_EMPTY_SEQUENCE);
+        assert_eq!(parse_err! { [] }, ERR_EMPTY_SEQUENCE);
+    }
+
+    #[test]
+    fn unknown_class_error() {
+        assert_eq!(
+            parse_err! { ['<', alhpa] },
+            "unknown class in sequence pattern, did you mean `alpha`?"
+        );
+
+        assert_eq!(parse_err! { ['<', foobar] }, ERR_UNKNOWN_SEQUENCE_CLASS);
+    }
+
+    #[test]
+    fn non_ascii_range_ignore_case_error() {
+        assert_eq!(
+            parse_err! { ['f', 0x70..=0x90]|i },
+            ERR_IGNORE_CASE_IS_NOT_ASCII
+        );
+    }
+
     #[test]
     fn non_ascii_char_in_array_error() {
         assert_eq!(parse_err! { ['f', '🐼', 0x51]|i }, ERR_CHAR_IS_NOT_ASCII);
@@ -153,7 +330,7 @@ mod tests {
     fn unexpected_token_error() {
         assert_eq!(
             parse_err! { -3 },
-            "expected string literal or square brackets"
+            "expected one of: string literal, byte string literal, square brackets"
         );
     }
 }
@@ -0,0 +1,90 @@
+use super::*;
+use syn::parenthesized;
+
+const ERR_UNKNOWN_UNICODE_PROPERTY: &str = concat![
+    "unknown Unicode property. Supported properties: XID_Start, XID_Continue, ",
+    "Alphabetic, Lowercase, Uppercase, White_Space"
+];
+
+const UNICODE_PROPERTIES: &[(&str, UnicodeProperty)] = &[
+    ("XID_Start", UnicodeProperty::XidStart),
+    ("XID_Continue", UnicodeProperty::XidContinue),
+    ("Alphabetic", UnicodeProperty::Alphabetic),
+    ("Lowercase", UnicodeProperty::Lowercase),
+    ("Uppercase", UnicodeProperty::Uppercase),
+    ("White_Space", UnicodeProperty::WhiteSpace),
+];
+
+impl FromStr for UnicodeProperty {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        UNICODE_PROPERTIES
+            .iter()
+            .find(|&&(name, _)| name == s)
+            .map(|&(_, property)| property)
+            .ok_or(())
+    }
+}
+
+/// Parses the property of the `unicode(...)` pattern.
+pub(super) fn parse_unicode_property(input: ParseStream) -> ParseResult<UnicodeProperty> {
+    let parens_content;
+
+    parenthesized!(parens_content in input);
+
+    let ident = parens_content.parse::<Ident>()?;
+    let s = ident.to_string();
+
+    s.parse::<UnicodeProperty>().map_err(|_| {
+        let suggestion = closest_name(&s, UNICODE_PROPERTIES.iter().map(|&(n, _)| n));
+
+        ParseError::new_spanned(
+            ident,
+            with_did_you_mean(ERR_UNKNOWN_UNICODE_PROPERTY, suggestion),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    curry_parse_macros!($Pattern);
+
+    #[test]
+    fn parse_unicode_pattern() {
+        assert_eq!(
+            parse_ok! { unicode(XID_Start) },
+            Pattern::Unicode(UnicodeProperty::XidStart)
+        );
+
+        assert_eq!(
+            parse_ok! { unicode(White_Space) },
+            Pattern::Unicode(UnicodeProperty::WhiteSpace)
+        );
+
+        assert_eq!(parse_ok! { invalid_utf8 }, Pattern::InvalidUtf8);
+    }
+
+    #[test]
+    fn unknown_unicode_property_error() {
+        assert_eq!(parse_err! { unicode(Emoji) }, ERR_UNKNOWN_UNICODE_PROPERTY);
+
+        assert_eq!(
+            parse_err! { unicode(XID_Strat) },
+            format!(
+                "{}, did you mean `XID_Start`?",
+                ERR_UNKNOWN_UNICODE_PROPERTY
+            )
+        );
+    }
+
+    #[test]
+    fn unicode_pattern_without_property_error() {
+        assert_eq!(
+            parse_err! { unicode },
+            "unexpected end of input, expected parentheses"
+        );
+    }
+}
@@ -0,0 +1,322 @@
+use super::*;
+use std::char;
+use std::sync::OnceLock;
+
+const CONTINUATION_BYTES: (u8, u8) = (0x80, 0xbf);
+
+#[derive(PartialEq)]
+enum Coverage {
+    Nothing,
+    Partial,
+    Everything,
+}
+
+impl UnicodeProperty {
+    /// Returns the sorted ranges of the code points with the property. Ranges are
+    /// computed once per process, since scanning all the code points is slow.
+    pub(crate) fn code_point_ranges(self) -> &'static [(u32, u32)] {
+        static RANGES: [OnceLock<Vec<(u32, u32)>>; 6] = [const { OnceLock::new() }; 6];
+
+        RANGES[self as usize].get_or_init(|| {
+            let mut ranges: Vec<(u32, u32)> = vec![];
+
+            for c in (0..=char::MAX as u32).filter_map(char::from_u32) {
+                if !self.contains(c) {
+                    continue;
+                }
+
+                match ranges.last_mut() {
+                    Some(range) if range.1 + 1 == c as u32 => range.1 = c as u32,
+                    _ => ranges.push((c as u32, c as u32)),
+                }
+            }
+
+            ranges
+        })
+    }
+}
+
+fn coverage(ranges: &[(u32, u32)], start: u32, end: u32) -> Coverage {
+    let first = ranges.partition_point(|r| r.1 < start);
+    let mut count = 0;
+
+    for &(range_start, range_end) in ranges[first..].iter().take_while(|r| r.0 <= end) {
+        count += range_end.min(end) - range_start.max(start) + 1;
+    }
+
+    match count {
+        0 => Coverage::Nothing,
+        c if c == end - start + 1 => Coverage::Everything,
+        _ => Coverage::Partial,
+    }
+}
+
+// NOTE: valid UTF-8 sequence read so far. Code point is
+// `None` if the outcome of the matching doesn't depend on it.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+struct Utf8Prefix {
+    code_point: Option<u32>,
+    len: usize,
+    depth: usize,
+    next: (u8, u8),
+}
+
+enum Utf8Step {
+    /// Number of the matched bytes or `None` if the pattern doesn't match.
+    Outcome(Option<usize>),
+    Continue(Utf8Prefix),
+}
+
+/// Result of the pattern matching at the current position of the input.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub(crate) enum Utf8Match {
+    Matched(usize),
+    Mismatched,
+    /// The outcome depends on the bytes of the next chunk.
+    Blocked,
+}
+
+/// Matches code points of the UTF-8 input: code points with the property
+/// for `unicode(...)` patterns and malformed sequences for `invalid_utf8`.
+/// Malformed sequences are consumed by their maximal valid prefixes (or
+/// a single byte), the same way `String::from_utf8_lossy` replaces them.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub(crate) struct Utf8Matcher {
+    property: Option<UnicodeProperty>,
+}
+
+impl Pattern {
+    pub(crate) fn utf8_matcher(&self) -> Option<Utf8Matcher> {
+        match *self {
+            Pattern::Unicode(property) => Some(Utf8Matcher {
+                property: Some(property),
+            }),
+            Pattern::InvalidUtf8 => Some(Utf8Matcher { property: None }),
+            _ => None,
+        }
+    }
+}
+
+impl Utf8Matcher {
+    fn invalid(self, depth: usize) -> Option<usize> {
+        match self.property {
+            Some(_) => None,
+            None => Some(depth.max(1)),
+        }
+    }
+
+    fn prefix(self, mut prefix: Utf8Prefix) -> Utf8Step {
+        let (property, code_point) = match (self.property, prefix.code_point) {
+            (Some(property), Some(code_point)) => (property, code_point),
+            _ if prefix.depth == prefix.len => {
+                return Utf8Step::Outcome(self.property.map(|_| prefix.len));
+            }
+            _ => return Utf8Step::Continue(prefix),
+        };
+
+        let rest_bits = 6 * (prefix.len - prefix.depth) as u32;
+        let end = code_point | ((1 << rest_bits) - 1);
+
+        match coverage(property.code_point_ranges(), code_point, end) {
+            Coverage::Nothing => Utf8Step::Outcome(None),
+            Coverage::Everything if prefix.depth == prefix.len => {
+                Utf8Step::Outcome(Some(prefix.len))
+            }
+            Coverage::Everything => {
+                prefix.code_point = None;
+                Utf8Step::Continue(prefix)
+            }
+            Coverage::Partial => Utf8Step::Continue(prefix),
+        }
+    }
+
+    fn step(self, prefix: Option<Utf8Prefix>, b: u8) -> Utf8Step {
+        let code_point = self.property.map(|_| 0);
+
+        let (len, bits, next) = match (prefix, b) {
+            (Some(p), _) if b < p.next.0 || b > p.next.1 => {
+                return Utf8Step::Outcome(self.invalid(p.depth));
+            }
+            (Some(p), _) => {
+                let shift = 6 * (p.len - p.depth - 1);
+                let bits = u32::from(b & 0x3f) << shift;
+
+                return self.prefix(Utf8Prefix {
+                    code_point: p.code_point.map(|c| c | bits),
+                    depth: p.depth + 1,
+                    next: CONTINUATION_BYTES,
+                    ..p
+                });
+            }
+            (None, 0x00..=0x7f) => (1, u32::from(b), (0, 0)),
+            (None, 0xc2..=0xdf) => (2, u32::from(b & 0x1f) << 6, CONTINUATION_BYTES),
+            (None, 0xe0) => (3, 0, (0xa0, 0xbf)),
+            // NOTE: surrogates are not valid code points.
+            (None, 0xed) => (3, 0xd << 12, (0x80, 0x9f)),
+            (None, 0xe1..=0xef) => (3, u32::from(b & 0x0f) << 12, CONTINUATION_BYTES),
+            (None, 0xf0) => (4, 0, (0x90, 0xbf)),
+            (None, 0xf4) => (4, 0x4 << 18, (0x80, 0x8f)),
+            (None, 0xf1..=0xf3) => (4, u32::from(b & 0x07) << 18, CONTINUATION_BYTES),
+            (None, _) => return Utf8Step::Outcome(self.invalid(0)),
+        };
+
+        self.prefix(Utf8Prefix {
+            code_point: code_point.map(|_| bits),
+            len,
+            depth: 1,
+            next,
+        })
+    }
+
+    fn end_of_input(self, prefix: Option<Utf8Prefix>) -> Option<usize> {
+        prefix.and_then(|p| self.invalid(p.depth))
+    }
+
+    /// Matches the pattern at the start of the input.
+    pub(crate) fn matches(self, input: &[u8], is_last_input: bool) -> Utf8Match {
+        let mut prefix = None;
+
+        let outcome = loop {
+            let depth = prefix.map_or(0, |p: Utf8Prefix| p.depth);
+
+            match input.get(depth) {
+                Some(&b) => match self.step(prefix, b) {
+                    Utf8Step::Outcome(outcome) => break outcome,
+                    Utf8Step::Continue(p) => prefix = Some(p),
+                },
+                None if !is_last_input && prefix.is_some() => return Utf8Match::Blocked,
+                None => break self.end_of_input(prefix),
+            }
+        };
+
+        match outcome {
+            Some(len) => Utf8Match::Matched(len),
+            None => Utf8Match::Mismatched,
+        }
+    }
+
+    /// Checks if the pattern can match input that starts with the byte.
+    pub(crate) fn may_match(self, b: u8) -> bool {
+        !matches!(self.step(None, b), Utf8Step::Outcome(None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matcher(pattern: Pattern) -> Utf8Matcher {
+        pattern.utf8_matcher().unwrap()
+    }
+
+    #[test]
+    fn match_unicode_property() {
+        let xid_start = matcher(Pattern::Unicode(UnicodeProperty::XidStart));
+
+        assert_eq!(xid_start.matches(b"a", true), Utf8Match::Matched(1));
+        assert_eq!(xid_start.matches(b"1", true), Utf8Match::Mismatched);
+        assert_eq!(
+            xid_start.matches("é!".as_bytes(), true),
+            Utf8Match::Matched(2)
+        );
+        assert_eq!(
+            xid_start.matches("中".as_bytes(), true),
+            Utf8Match::Matched(3)
+        );
+        assert_eq!(
+            xid_start.matches("𠀀".as_bytes(), true),
+            Utf8Match::Matched(4)
+        );
+        assert_eq!(
+            xid_start.matches("€".as_bytes(), true),
+            Utf8Match::Mismatched
+        );
+        assert_eq!(xid_start.matches(&[0xe4, 0xb8], false), Utf8Match::Blocked);
+        assert_eq!(
+            xid_start.matches(&[0xe4, 0xb8], true),
+            Utf8Match::Mismatched
+        );
+        assert_eq!(
+            xid_start.matches(&[0xed, 0xa0, 0x80], true),
+            Utf8Match::Mismatched
+        );
+    }
+
+    #[test]
+    fn match_invalid_utf8() {
+        let invalid_utf8 = matcher(Pattern::InvalidUtf8);
+
+        assert_eq!(invalid_utf8.matches(b"a", true), Utf8Match::Mismatched);
+        assert_eq!(
+            invalid_utf8.matches("中".as_bytes(), true),
+            Utf8Match::Mismatched
+        );
+        assert_eq!(
+            invalid_utf8.matches(&[0xff, 0x41], true),
+            Utf8Match::Matched(1)
+        );
+        assert_eq!(
+            invalid_utf8.matches(&[0xc0, 0x80], true),
+            Utf8Match::Matched(1)
+        );
+        assert_eq!(
+            invalid_utf8.matches(&[0xe4, 0xb8, 0x41], true),
+            Utf8Match::Matched(2)
+        );
+        assert_eq!(
+            invalid_utf8.matches(&[0xed, 0xa0, 0x80], true),
+            Utf8Match::Matched(1)
+        );
+        assert_eq!(
+            invalid_utf8.matches(&[0xf0, 0x9f], false),
+            Utf8Match::Blocked
+        );
+        assert_eq!(
+            invalid_utf8.matches(&[0xf0, 0x9f], true),
+            Utf8Match::Matched(2)
+        );
+    }
+
+    #[test]
+    fn matches_lossy_decoding() {
+        let invalid_utf8 = matcher(Pattern::InvalidUtf8);
+        let alphabetic = matcher(Pattern::Unicode(UnicodeProperty::Alphabetic));
+
+        let inputs: &[&[u8]] = &[
+            b"\xf0\x9f\x98\x80\xe2\x82\xac",
+            b"\xf4\x90\x80\x80\xe0\x80\x80\xf0\x9f\x98",
+            b"\xc3\xa9\xc3\x28\xa0\xa1\xe2\x28\xa1\xf0\x28\x8c\xbc",
+        ];
+
+        for input in inputs {
+            let mut decoded = String::new();
+            let mut pos = 0;
+
+            while pos < input.len() {
+                if let Utf8Match::Matched(len) = invalid_utf8.matches(&input[pos..], true) {
+                    decoded.push(char::REPLACEMENT_CHARACTER);
+                    pos += len;
+                } else {
+                    let c = String::from_utf8_lossy(&input[pos..])
+                        .chars()
+                        .next()
+                        .unwrap();
+
+                    assert_eq!(
+                        alphabetic.matches(&input[pos..], true),
+                        if c.is_alphabetic() {
+                            Utf8Match::Matched(c.len_utf8())
+                        } else {
+                            Utf8Match::Mismatched
+                        }
+                    );
+
+                    decoded.push(c);
+                    pos += c.len_utf8();
+                }
+            }
+
+            assert_eq!(decoded, String::from_utf8_lossy(input));
+        }
+    }
+}
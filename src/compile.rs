@@ -1,3 +1,5 @@
+use crate::graphviz::EdgeId;
+use crate::State;
 use proc_macro2::TokenStream as TokenStream2;
 
 // NOTE: contains compilation code that involves intrinsics
@@ -12,8 +14,126 @@ macro_rules! gen_parser_intrinsics {
     (is_last_input) => {
         quote::quote! { self.__is_last_input }
     };
+
+    (state) => {
+        quote::quote! { self.__state }
+    };
+
+    (pins) => {
+        quote::quote! { self.__pins }
+    };
+
+    (input) => {
+        quote::quote! { input }
+    };
+
+    (pos) => {
+        quote::quote! { self.__pos }
+    };
+
+    (offset) => {
+        quote::quote! { (self.__chunk_offset + self.__pos) }
+    };
+
+    (current_byte) => {
+        quote::quote! { ch }
+    };
+
+    (parsing_error) => {
+        quote::quote! { self.__error }
+    };
+
+    (match_sequence) => {
+        quote::quote! { self.__match_sequence }
+    };
+
+    (pin_slice) => {
+        quote::quote! { self.__pin_slice }
+    };
+
+    (match_sequence_with) => {
+        quote::quote! { self.__match_sequence_with }
+    };
+
+    (end_of_input) => {
+        quote::quote! { return Ok(0) }
+    };
+
+    (block_input) => {
+        quote::quote! { return Ok(input.len() - self.__pos) }
+    };
+
+    (actions) => {
+        quote::quote! { self.actions }
+    };
+
+    (skip_until) => {
+        quote::quote! { self.__skip_until }
+    };
+
+    (skip_by_table) => {
+        quote::quote! { self.__skip_by_table }
+    };
+
+    (record_step) => {
+        quote::quote! { self.__record_step }
+    };
+
+    (record_action_call) => {
+        quote::quote! { self.__record_action_call }
+    };
+
+    (sequence_arm) => {
+        quote::quote! { sequence_arm }
+    };
+
+    (code_point_len) => {
+        quote::quote! { code_point_len }
+    };
+
+    (decode_utf8) => {
+        quote::quote! { __decode_utf8 }
+    };
+
+    (tokens) => {
+        quote::quote! { self.__tokens }
+    };
+
+    (is_suspended) => {
+        quote::quote! { self.__is_suspended }
+    };
+
+    (emit_token) => {
+        quote::quote! { self.__emit_token }
+    };
 }
 
 pub trait Compile {
     fn compile(&self) -> TokenStream2;
 }
+
+// NOTE: describes how much input is consumed by an arm's pattern.
+// Directives need it to produce code that advances the input.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub(crate) enum Consumption {
+    Nothing,
+    Byte,
+    Bytes(usize),
+    /// Code point or malformed sequence of the UTF-8 input.
+    CodePoint,
+    InputState,
+}
+
+#[derive(Copy, Clone)]
+pub(crate) struct ArmCtx<'s> {
+    pub state_name: &'s str,
+    pub consumption: Consumption,
+    /// Graph edge of the arm's branch that is recorded by the tracing
+    /// parsers (`None` for the implicit fallback arms).
+    pub edge: Option<EdgeId>,
+    /// Generate calls of the `Tracer` hooks.
+    pub tracer: bool,
+    /// States of the grammar: code of the trivial states is inlined into
+    /// the transitions to them (empty to disable inlining).
+    pub states: &'s [State],
+}
@@ -0,0 +1,551 @@
+use super::*;
+use crate::compile::{ArmCtx, Consumption};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::Ident;
+
+impl ActionArg {
+    fn compile_value(&self) -> TokenStream2 {
+        match self {
+            ActionArg::Lit(lit) => quote! { #lit },
+            ActionArg::Expr { expr, .. } => quote! { #expr },
+            ActionArg::Byte => gen_parser_intrinsics!(current_byte),
+            ActionArg::Offset => gen_parser_intrinsics!(offset),
+            ActionArg::Pin(name) => {
+                let name = Ident::new(name, Span::call_site());
+                let input = gen_parser_intrinsics!(input);
+                let pins = gen_parser_intrinsics!(pins);
+                let pin_slice = gen_parser_intrinsics!(pin_slice);
+
+                quote! { #pin_slice(#input, #pins.#name) }
+            }
+        }
+    }
+}
+
+// NOTE: actions are called through `DerefMut` of the parser, so the arguments
+// that are read from the parser are evaluated before the call.
+fn compile_user_defined_call(name: &str, args: &[ActionArg]) -> TokenStream2 {
+    let name = Ident::new(name, Span::call_site());
+
+    if args
+        .iter()
+        .all(|a| matches!(a, ActionArg::Lit(_) | ActionArg::Expr { .. }))
+    {
+        let args = args.iter().map(ActionArg::compile_value);
+
+        return quote! { self.#name(#(#args),*) };
+    }
+
+    let arg_names = (0..args.len())
+        .map(|i| Ident::new(&format!("arg{}", i), Span::call_site()))
+        .collect::<Vec<_>>();
+
+    let arg_values = args.iter().map(ActionArg::compile_value);
+    let let_names = arg_names.iter();
+    let call_args = arg_names.iter();
+
+    quote! {{
+        #(let #let_names = #arg_values;)*
+
+        self.#name(#(#call_args),*)
+    }}
+}
+
+impl ActionCall {
+    fn compile_tracer_hook(&self, ctx: &ArmCtx) -> TokenStream2 {
+        if ctx.tracer {
+            let actions = gen_parser_intrinsics!(actions);
+            let offset = gen_parser_intrinsics!(offset);
+            let record_action_call = gen_parser_intrinsics!(record_action_call);
+            let call = self.to_string();
+
+            quote! {
+                #actions.on_action_call(#call, #offset);
+                #record_action_call(#call);
+            }
+        } else {
+            quote! {}
+        }
+    }
+
+    fn compile(&self, ctx: &ArmCtx, consumed_len: &TokenStream2) -> TokenStream2 {
+        let tracer_hook = self.compile_tracer_hook(ctx);
+
+        let call = match self {
+            ActionCall::UserDefined {
+                name,
+                args,
+                with_error_check,
+            } => {
+                let call = compile_user_defined_call(name, args);
+
+                if *with_error_check {
+                    let parsing_error = gen_parser_intrinsics!(parsing_error);
+                    let ch = gen_parser_intrinsics!(current_byte);
+                    let state_name = ctx.state_name;
+
+                    quote! {
+                        if let Err(e) = #call {
+                            return Err(#parsing_error(e, #state_name, #ch));
+                        }
+                    }
+                } else {
+                    quote! { #call; }
+                }
+            }
+            ActionCall::Pin(name) => {
+                let name = Ident::new(name, Span::call_site());
+                let pins = gen_parser_intrinsics!(pins);
+                let offset = gen_parser_intrinsics!(offset);
+
+                quote! { #pins.#name = Some(#offset); }
+            }
+            ActionCall::Emit { kind, pin } => {
+                let kind = Ident::new(kind, Span::call_site());
+                let emit_token = gen_parser_intrinsics!(emit_token);
+                let offset = gen_parser_intrinsics!(offset);
+
+                let start = match pin {
+                    Some(name) => {
+                        let name = Ident::new(name, Span::call_site());
+                        let pins = gen_parser_intrinsics!(pins);
+
+                        quote! { #pins.#name.unwrap_or(#offset) }
+                    }
+                    None => offset.clone(),
+                };
+
+                quote! {
+                    #emit_token(Token::#kind {
+                        start: #start,
+                        end: #offset + #consumed_len,
+                    });
+                }
+            }
+        };
+
+        quote! {
+            #tracer_hook
+            #call
+        }
+    }
+}
+
+impl StateTransition {
+    fn compile(&self) -> TokenStream2 {
+        let state = gen_parser_intrinsics!(state);
+        let state_enter_flag = gen_parser_intrinsics!(state_enter_flag);
+        let target = Ident::new(&self.target, Span::call_site());
+
+        let target = if self.dynamic {
+            quote! { self.#target() }
+        } else {
+            quote! { State::#target }
+        };
+
+        quote! {
+            #state = #target;
+            #state_enter_flag = true;
+        }
+    }
+}
+
+impl Directives {
+    // NOTE: code of the trivial target state is inlined after the input is consumed,
+    // so it sees the same input position as the next iteration of the parsing loop.
+    // Transitions on the input state are not inlined, since the parser stops
+    // on them and target state code is not executed if the input has ended.
+    // Inlining of the state into itself would just unroll the loop.
+    fn compile_inlined_target(&self, ctx: &ArmCtx) -> TokenStream2 {
+        let target = match self.state_transition {
+            Some(ref t) if !t.dynamic && ctx.consumption != Consumption::InputState => &t.target,
+            _ => return quote! {},
+        };
+
+        if *target == ctx.state_name {
+            return quote! {};
+        }
+
+        match ctx.states.iter().position(|s| s.name == *target) {
+            Some(idx) if ctx.states[idx].is_trivial() => {
+                ctx.states[idx].compile_inlined(idx, ctx.tracer)
+            }
+            _ => quote! {},
+        }
+    }
+
+    fn is_epsilon_move(&self) -> bool {
+        self.state_transition
+            .as_ref()
+            .is_some_and(|t| t.epsilon_move)
+    }
+
+    // NOTE: number of bytes that the arm consumes, emitted tokens end after them.
+    fn compile_consumed_len(&self, ctx: &ArmCtx) -> TokenStream2 {
+        match ctx.consumption {
+            _ if self.is_epsilon_move() => quote! { 0usize },
+            Consumption::Byte => quote! { 1usize },
+            Consumption::Bytes(count) => quote! { #count },
+            Consumption::CodePoint => gen_parser_intrinsics!(code_point_len),
+            Consumption::Nothing | Consumption::InputState => quote! { 0usize },
+        }
+    }
+
+    fn compile_epilogue(&self, ctx: &ArmCtx) -> TokenStream2 {
+        let pos = gen_parser_intrinsics!(pos);
+        let inlined_target = self.compile_inlined_target(ctx);
+        let epsilon_move = self.is_epsilon_move();
+
+        match ctx.consumption {
+            // NOTE: state enter arms don't consume input, but if they switch
+            // the state we need to restart the loop in the new state.
+            Consumption::Nothing if self.state_transition.is_some() => quote! {
+                #inlined_target
+                continue;
+            },
+            Consumption::Nothing => quote! {},
+            _ if epsilon_move => inlined_target,
+            Consumption::Byte => quote! {
+                #pos += 1;
+                #inlined_target
+            },
+            Consumption::Bytes(count) => quote! {
+                #pos += #count;
+                #inlined_target
+            },
+            Consumption::CodePoint => {
+                let code_point_len = gen_parser_intrinsics!(code_point_len);
+
+                quote! {
+                    #pos += #code_point_len;
+                    #inlined_target
+                }
+            }
+            Consumption::InputState => {
+                let end_of_input = gen_parser_intrinsics!(end_of_input);
+
+                quote! { #end_of_input; }
+            }
+        }
+    }
+
+    // NOTE: steps are recorded only with the `tracer` feature,
+    // so the generated parsers don't pay for tracing by default.
+    fn compile_step_recording(ctx: &ArmCtx) -> TokenStream2 {
+        match ctx.edge {
+            Some(edge) if ctx.tracer => {
+                let record_step = gen_parser_intrinsics!(record_step);
+                let arm = edge.arm;
+                let branch = edge.branch;
+
+                quote! { #record_step(#arm, #branch); }
+            }
+            _ => quote! {},
+        }
+    }
+
+    pub(crate) fn compile(&self, ctx: &ArmCtx) -> TokenStream2 {
+        let step_recording = Self::compile_step_recording(ctx);
+        let consumed_len = self.compile_consumed_len(ctx);
+        let action_calls = self
+            .action_calls
+            .iter()
+            .map(|c| c.compile(ctx, &consumed_len));
+        let state_transition = self.state_transition.as_ref().map(|t| t.compile());
+        let epilogue = self.compile_epilogue(ctx);
+
+        quote! {
+            #step_recording
+            #(#action_calls)*
+            #state_transition
+            #epilogue
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::State;
+
+    curry_parse_macros!($Directives);
+
+    macro_rules! compile {
+        ($consumption:expr, { $($t:tt)* }) => {
+            crate::helpers::test_helpers::to_code_str(parse_ok!($($t)*).compile(&ArmCtx {
+                state_name: "foo_state",
+                consumption: $consumption,
+                edge: None,
+                tracer: false,
+                states: &[],
+            }))
+        };
+    }
+
+    #[test]
+    fn compile_action_calls() {
+        assert_eq!(
+            compile!(Consumption::Byte, { foo, bar("baz", 42). }),
+            code_str! {
+                self.foo();
+                self.bar("baz", 42);
+                self.__pos += 1;
+            }
+        );
+    }
+
+    #[test]
+    fn compile_action_call_with_built_in_args() {
+        assert_eq!(
+            compile!(Consumption::Byte, { foo(@byte, 1, @offset, @pin(bar))?. }),
+            code_str! {
+                if let Err(e) = {
+                    let arg0 = ch;
+                    let arg1 = 1;
+                    let arg2 = (self.__chunk_offset + self.__pos);
+                    let arg3 = self.__pin_slice(input, self.__pins.bar);
+
+                    self.foo(arg0, arg1, arg2, arg3)
+                } {
+                    return Err(self.__error(e, "foo_state", ch));
+                }
+                self.__pos += 1;
+            }
+        );
+    }
+
+    #[test]
+    fn compile_action_call_with_expr_args() {
+        assert_eq!(
+            compile!(Consumption::Byte, { foo(Kind::Start: Kind, (MAX as u8 + 1): u8, @byte). }),
+            code_str! {
+                {
+                    let arg0 = Kind::Start;
+                    let arg1 = (MAX as u8 + 1);
+                    let arg2 = ch;
+
+                    self.foo(arg0, arg1, arg2)
+                };
+                self.__pos += 1;
+            }
+        );
+
+        assert_eq!(
+            compile!(Consumption::Byte, { set_mode(Mode::RawText: Mode)?. }),
+            code_str! {
+                if let Err(e) = self.set_mode(Mode::RawText) {
+                    return Err(self.__error(e, "foo_state", ch));
+                }
+
+                self.__pos += 1;
+            }
+        );
+    }
+
+    #[test]
+    fn compile_emit() {
+        assert_eq!(
+            compile!(Consumption::Bytes(3), { @emit(Foo), @emit(Bar, baz). }),
+            code_str! {
+                self.__emit_token(Token::Foo {
+                    start: (self.__chunk_offset + self.__pos),
+                    end: (self.__chunk_offset + self.__pos) + 3usize,
+                });
+                self.__emit_token(Token::Bar {
+                    start: self.__pins.baz.unwrap_or((self.__chunk_offset + self.__pos)),
+                    end: (self.__chunk_offset + self.__pos) + 3usize,
+                });
+                self.__pos += 3usize;
+            }
+        );
+
+        assert_eq!(
+            compile!(Consumption::Byte, { @emit(Foo, baz), move --> bar_state. }),
+            code_str! {
+                self.__emit_token(Token::Foo {
+                    start: self.__pins.baz.unwrap_or((self.__chunk_offset + self.__pos)),
+                    end: (self.__chunk_offset + self.__pos) + 0usize,
+                });
+                self.__state = State::bar_state;
+                self.__state_enter = true;
+            }
+        );
+    }
+
+    #[test]
+    fn compile_fallible_action_call() {
+        assert_eq!(
+            compile!(Consumption::Byte, { foo(1)?. }),
+            code_str! {
+                if let Err(e) = self.foo(1) {
+                    return Err(self.__error(e, "foo_state", ch));
+                }
+
+                self.__pos += 1;
+            }
+        );
+    }
+
+    #[test]
+    fn compile_pin() {
+        assert_eq!(
+            compile!(Consumption::Byte, { @pin(bar). }),
+            code_str! {
+                self.__pins.bar = Some((self.__chunk_offset + self.__pos));
+                self.__pos += 1;
+            }
+        );
+    }
+
+    #[test]
+    fn compile_state_transition() {
+        assert_eq!(
+            compile!(Consumption::Byte, { foo, --> bar_state. }),
+            code_str! {
+                self.foo();
+                self.__state = State::bar_state;
+                self.__state_enter = true;
+                self.__pos += 1;
+            }
+        );
+
+        assert_eq!(
+            compile!(Consumption::Byte, { --> dyn bar_state. }),
+            code_str! {
+                self.__state = self.bar_state();
+                self.__state_enter = true;
+                self.__pos += 1;
+            }
+        );
+    }
+
+    #[test]
+    fn compile_epsilon_move() {
+        assert_eq!(
+            compile!(Consumption::Byte, { move --> bar_state. }),
+            code_str! {
+                self.__state = State::bar_state;
+                self.__state_enter = true;
+            }
+        );
+
+        assert_eq!(
+            compile!(Consumption::InputState, { move --> bar_state. }),
+            code_str! {
+                self.__state = State::bar_state;
+                self.__state_enter = true;
+            }
+        );
+    }
+
+    #[test]
+    fn compile_input_consumption() {
+        assert_eq!(
+            compile!(Consumption::Nothing, { foo. }),
+            code_str! { self.foo(); }
+        );
+
+        assert_eq!(
+            compile!(Consumption::Nothing, { --> bar_state. }),
+            code_str! {
+                self.__state = State::bar_state;
+                self.__state_enter = true;
+                continue;
+            }
+        );
+
+        assert_eq!(
+            compile!(Consumption::Bytes(3), { foo. }),
+            code_str! {
+                self.foo();
+                self.__pos += 3usize;
+            }
+        );
+
+        assert_eq!(
+            compile!(Consumption::CodePoint, { foo. }),
+            code_str! {
+                self.foo();
+                self.__pos += code_point_len;
+            }
+        );
+
+        assert_eq!(
+            compile!(Consumption::InputState, { foo. }),
+            code_str! {
+                self.foo();
+                return Ok(0);
+            }
+        );
+    }
+
+    #[test]
+    fn compile_inlined_target() {
+        let states = [
+            parse!(<State>, {
+                bar_state:
+                    --> => @pin(bar), --> foo_state.
+            })
+            .unwrap(),
+            parse!(<State>, {
+                baz_state:
+                    eof => baz.
+                    _ => move --> foo_state.
+            })
+            .unwrap(),
+        ];
+
+        let compile = |consumption, directives: Directives| {
+            crate::helpers::test_helpers::to_code_str(directives.compile(&ArmCtx {
+                state_name: "foo_state",
+                consumption,
+                edge: None,
+                tracer: false,
+                states: &states,
+            }))
+        };
+
+        assert_eq!(
+            compile(Consumption::Byte, parse_ok! { foo, --> bar_state. }),
+            code_str! {
+                self.foo();
+                self.__state = State::bar_state;
+                self.__state_enter = true;
+                self.__pos += 1;
+
+                {
+                    #[allow(unused_variables)]
+                    let ch = input.get(self.__pos).cloned();
+
+                    if self.__state_enter {
+                        self.__state_enter = false;
+                        self.__pins.bar = Some((self.__chunk_offset + self.__pos));
+                        self.__state = State::foo_state;
+                        self.__state_enter = true;
+                        continue;
+                    }
+                }
+            }
+        );
+
+        assert_eq!(
+            compile(Consumption::Byte, parse_ok! { foo, --> baz_state. }),
+            code_str! {
+                self.foo();
+                self.__state = State::baz_state;
+                self.__state_enter = true;
+                self.__pos += 1;
+            }
+        );
+
+        assert_eq!(
+            compile(Consumption::InputState, parse_ok! { --> bar_state. }),
+            code_str! {
+                self.__state = State::bar_state;
+                self.__state_enter = true;
+                return Ok(0);
+            }
+        );
+    }
+}
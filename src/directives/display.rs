@@ -0,0 +1,120 @@
+use super::*;
+use quote::ToTokens;
+use std::fmt::{self, Display, Formatter};
+
+impl Display for ActionArg {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ActionArg::Lit(lit) => write!(f, "{}", lit.into_token_stream()),
+            ActionArg::Expr { expr, .. } if matches!(**expr, Expr::Cast(_)) => {
+                write!(f, "{}", expr.into_token_stream())
+            }
+            ActionArg::Expr { expr, ty } => write!(
+                f,
+                "{}: {}",
+                expr.into_token_stream(),
+                ty.into_token_stream()
+            ),
+            ActionArg::Byte => write!(f, "@byte"),
+            ActionArg::Offset => write!(f, "@offset"),
+            ActionArg::Pin(name) => write!(f, "@pin({})", name),
+        }
+    }
+}
+
+impl Display for ActionCall {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ActionCall::UserDefined {
+                name,
+                args,
+                with_error_check,
+            } => {
+                write!(f, "{}", name)?;
+
+                if !args.is_empty() {
+                    let args = args.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+                    write!(f, "({})", args.join(", "))?;
+                }
+
+                if *with_error_check {
+                    write!(f, "?")?;
+                }
+
+                Ok(())
+            }
+            ActionCall::Pin(name) => write!(f, "@pin({})", name),
+            ActionCall::Emit { kind, pin: None } => write!(f, "@emit({})", kind),
+            ActionCall::Emit {
+                kind,
+                pin: Some(pin),
+            } => write!(f, "@emit({}, {})", kind, pin),
+        }
+    }
+}
+
+impl Display for StateTransition {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.epsilon_move {
+            write!(f, "move ")?;
+        }
+
+        write!(f, "-->")?;
+
+        if self.dynamic {
+            write!(f, " dyn")?;
+        }
+
+        write!(f, " {}", self.target)
+    }
+}
+
+impl Display for Directives {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let items = self
+            .action_calls
+            .iter()
+            .map(ToString::to_string)
+            .chain(self.state_transition.iter().map(ToString::to_string))
+            .collect::<Vec<_>>();
+
+        write!(f, "{}.", items.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    curry_parse_macros!($Directives);
+
+    #[test]
+    fn display() {
+        assert_eq!(parse_ok! { . }.to_string(), ".");
+        assert_eq!(
+            parse_ok! { foo, bar("baz", 1, b'a')?, @pin(qux). }.to_string(),
+            "foo, bar(\"baz\", 1, b'a')?, @pin(qux)."
+        );
+        assert_eq!(
+            parse_ok! { @pin(foo), bar(@byte, @offset, @pin(foo)). }.to_string(),
+            "@pin(foo), bar(@byte, @offset, @pin(foo))."
+        );
+        assert_eq!(
+            parse_ok! { @emit(Foo), @emit(Bar, baz). }.to_string(),
+            "@emit(Foo), @emit(Bar, baz)."
+        );
+        assert_eq!(
+            parse_ok! { foo(Kind::Start: Kind, -1: i8, MAX as u8). }.to_string(),
+            "foo(Kind :: Start: Kind, - 1: i8, MAX as u8)."
+        );
+        assert_eq!(
+            parse_ok! { foo, --> bar_state. }.to_string(),
+            "foo, --> bar_state."
+        );
+        assert_eq!(
+            parse_ok! { move --> dyn next_state. }.to_string(),
+            "move --> dyn next_state."
+        );
+    }
+}
@@ -1,6 +1,8 @@
+mod compile;
+mod display;
 mod parse;
 
-use syn::Lit;
+use syn::{Expr, Lit, Type};
 
 #[derive(PartialEq, Debug)]
 pub struct StateTransition {
@@ -15,12 +17,37 @@ pub struct Directives {
     pub state_transition: Option<StateTransition>,
 }
 
+/// Argument of the action call. Built-in arguments (`@...`)
+/// are filled in by the parser at the time of the call.
+#[derive(Debug, PartialEq)]
+pub enum ActionArg {
+    Lit(Lit),
+    /// Constant Rust expression with the declared type (e.g. `Mode::RawText: Mode`
+    /// or `MAX as u8`) that is forwarded to the generated call as is.
+    Expr {
+        expr: Box<Expr>,
+        ty: Box<Type>,
+    },
+    /// Current byte or `None` at the end of the input (`@byte`).
+    Byte,
+    /// Offset of the current byte in the whole input (`@offset`).
+    Offset,
+    /// Input from the pinned offset to the current byte (`@pin(name)`).
+    Pin(String),
+}
+
 #[derive(Debug, PartialEq)]
 pub enum ActionCall {
     UserDefined {
         name: String,
-        args: Vec<Lit>,
+        args: Vec<ActionArg>,
         with_error_check: bool,
     },
     Pin(String),
+    /// Emits the token that starts at the pinned offset (or at the current byte
+    /// if the pin is not specified) and ends after the input matched by the arm.
+    Emit {
+        kind: String,
+        pin: Option<String>,
+    },
 }
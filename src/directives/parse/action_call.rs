@@ -1,7 +1,7 @@
 use super::*;
 use syn::parse::{Parse, ParseStream};
 use syn::token::Paren;
-use syn::{parenthesized, Error as ParseError, Ident, Lit, Result as ParseResult, Token};
+use syn::{parenthesized, Error as ParseError, Expr, Ident, Result as ParseResult, Token};
 
 const ERR_EMPTY_ARGS: &str = concat![
     "expected at least one action argument (action calls ",
@@ -10,15 +10,62 @@ const ERR_EMPTY_ARGS: &str = concat![
 
 const ERR_TOO_MANY_ARGS: &str = "too many arguments";
 const ERR_UNKNOWN_BUILT_IN: &str = "unknown built-in directive";
+const ERR_UNKNOWN_BUILT_IN_ARG: &str = "unknown built-in argument";
 
-fn parse_args(input: ParseStream) -> ParseResult<Vec<Lit>> {
+const ERR_EXPR_ARG_WITHOUT_TYPE: &str = concat![
+    "type of the expression argument should be declared (e.g. `Mode::RawText: Mode`), ",
+    "since it defines the action's signature"
+];
+
+impl Parse for ActionArg {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        if !parse_if_present!(input, { @ }) {
+            return parse_expr_arg(input);
+        }
+
+        let name_ident = input.parse::<Ident>()?;
+
+        match name_ident.to_string().as_str() {
+            "byte" => Ok(ActionArg::Byte),
+            "offset" => Ok(ActionArg::Offset),
+            "pin" => parse_pin_name(input).map(ActionArg::Pin),
+            _ => Err(ParseError::new_spanned(
+                name_ident,
+                ERR_UNKNOWN_BUILT_IN_ARG,
+            )),
+        }
+    }
+}
+
+// NOTE: types of the expressions can't be reliably inferred from their syntax,
+// so they are declared unless the expression is a literal or a cast.
+fn parse_expr_arg(input: ParseStream) -> ParseResult<ActionArg> {
+    match input.parse::<Expr>()? {
+        Expr::Lit(e) => Ok(ActionArg::Lit(e.lit)),
+        Expr::Type(e) => Ok(ActionArg::Expr {
+            expr: e.expr,
+            ty: e.ty,
+        }),
+        Expr::Cast(e) => {
+            let ty = e.ty.clone();
+
+            Ok(ActionArg::Expr {
+                expr: Box::new(Expr::Cast(e)),
+                ty,
+            })
+        }
+        e => Err(ParseError::new_spanned(e, ERR_EXPR_ARG_WITHOUT_TYPE)),
+    }
+}
+
+fn parse_args(input: ParseStream) -> ParseResult<Vec<ActionArg>> {
     if input.peek(Paren) {
         let parens_content;
 
         parenthesized!(parens_content in input);
 
         let args = parens_content
-            .parse_terminated::<_, Token! { , }>(Lit::parse)?
+            .parse_terminated::<_, Token! { , }>(ActionArg::parse)?
             .into_iter()
             .collect::<Vec<_>>();
 
@@ -46,6 +93,26 @@ fn parse_pin_name(input: ParseStream) -> ParseResult<String> {
     }
 }
 
+fn parse_emit(input: ParseStream) -> ParseResult<ActionCall> {
+    let parens_content;
+
+    parenthesized!(parens_content in input);
+
+    let kind = parens_content.parse::<Ident>()?.to_string();
+
+    let pin = if parse_if_present!(parens_content, { , }) {
+        Some(parens_content.parse::<Ident>()?.to_string())
+    } else {
+        None
+    };
+
+    if parens_content.is_empty() {
+        Ok(ActionCall::Emit { kind, pin })
+    } else {
+        Err(parens_content.error(ERR_TOO_MANY_ARGS))
+    }
+}
+
 impl Parse for ActionCall {
     fn parse(input: ParseStream) -> ParseResult<Self> {
         let built_in = parse_if_present!(input, { @ });
@@ -55,6 +122,7 @@ impl Parse for ActionCall {
         if built_in {
             match name.as_str() {
                 "pin" => parse_pin_name(input).map(ActionCall::Pin),
+                "emit" => parse_emit(input),
                 _ => Err(ParseError::new_spanned(name_ident, ERR_UNKNOWN_BUILT_IN)),
             }
         } else {
@@ -112,15 +180,95 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_built_in_args() {
+        assert_eq!(
+            parse_ok! { foo(@byte, 42, @offset, @pin(bar))? },
+            ActionCall::UserDefined {
+                name: "foo".into(),
+                args: vec![
+                    ActionArg::Byte,
+                    lit!(42),
+                    ActionArg::Offset,
+                    ActionArg::Pin("bar".into())
+                ],
+                with_error_check: true
+            }
+        );
+    }
+
+    #[test]
+    fn parse_expr_args() {
+        assert_eq!(
+            parse_ok! { foo(Kind::Start: Kind, 1, MAX as u8, u32::count_ones(3): u32) },
+            ActionCall::UserDefined {
+                name: "foo".into(),
+                args: vec![
+                    ActionArg::Expr {
+                        expr: Box::new(parse!(<syn::Expr>, { Kind::Start }).unwrap()),
+                        ty: Box::new(parse!(<syn::Type>, { Kind }).unwrap())
+                    },
+                    lit!(1),
+                    ActionArg::Expr {
+                        expr: Box::new(parse!(<syn::Expr>, { MAX as u8 }).unwrap()),
+                        ty: Box::new(parse!(<syn::Type>, { u8 }).unwrap())
+                    },
+                    ActionArg::Expr {
+                        expr: Box::new(parse!(<syn::Expr>, { u32::count_ones(3) }).unwrap()),
+                        ty: Box::new(parse!(<syn::Type>, { u32 }).unwrap())
+                    }
+                ],
+                with_error_check: false
+            }
+        );
+    }
+
+    #[test]
+    fn expr_arg_without_type_error() {
+        assert_eq!(parse_err! { foo(Kind::Start) }, ERR_EXPR_ARG_WITHOUT_TYPE);
+        assert_eq!(parse_err! { foo(1, Some(2)) }, ERR_EXPR_ARG_WITHOUT_TYPE);
+        assert_eq!(parse_err! { foo(MAX as u8 + 1) }, ERR_EXPR_ARG_WITHOUT_TYPE);
+    }
+
+    #[test]
+    fn unknown_built_in_arg_error() {
+        assert_eq!(parse_err! { foo(@bar) }, ERR_UNKNOWN_BUILT_IN_ARG);
+    }
+
+    #[test]
+    fn too_many_args_for_built_in_arg_error() {
+        assert_eq!(parse_err! { foo(@pin(bar, baz)) }, ERR_TOO_MANY_ARGS);
+    }
+
     #[test]
     fn parse_built_in() {
         assert_eq!(parse_ok! { @pin(foo) }, ActionCall::Pin("foo".into()));
         assert_eq!(parse_ok! { @pin(bar) }, ActionCall::Pin("bar".into()));
     }
 
+    #[test]
+    fn parse_emit() {
+        assert_eq!(
+            parse_ok! { @emit(Foo) },
+            ActionCall::Emit {
+                kind: "Foo".into(),
+                pin: None
+            }
+        );
+
+        assert_eq!(
+            parse_ok! { @emit(Bar, start) },
+            ActionCall::Emit {
+                kind: "Bar".into(),
+                pin: Some("start".into())
+            }
+        );
+    }
+
     #[test]
     fn too_many_args_for_built_in_error() {
         assert_eq!(parse_err! { @pin(foo, bar) }, ERR_TOO_MANY_ARGS);
+        assert_eq!(parse_err! { @emit(Foo, bar, baz) }, ERR_TOO_MANY_ARGS);
     }
 
     #[test]
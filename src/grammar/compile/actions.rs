@@ -0,0 +1,239 @@
+use crate::{ActionArg, ActionCall, ArmRhs, Directives, Grammar, Pattern, StateTransition};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use std::collections::HashSet;
+use std::iter::once;
+use syn::{FloatSuffix, Ident, IntSuffix, Lit};
+
+fn compile_int_type(suffix: IntSuffix) -> TokenStream2 {
+    use IntSuffix::*;
+
+    match suffix {
+        I8 => quote! { i8 },
+        I16 => quote! { i16 },
+        I32 | None => quote! { i32 },
+        I64 => quote! { i64 },
+        I128 => quote! { i128 },
+        Isize => quote! { isize },
+        U8 => quote! { u8 },
+        U16 => quote! { u16 },
+        U32 => quote! { u32 },
+        U64 => quote! { u64 },
+        U128 => quote! { u128 },
+        Usize => quote! { usize },
+    }
+}
+
+fn compile_lit_type(arg: &Lit) -> TokenStream2 {
+    match arg {
+        Lit::Str(_) => quote! { &'static str },
+        Lit::ByteStr(_) => quote! { &'static [u8] },
+        Lit::Byte(_) => quote! { u8 },
+        Lit::Char(_) => quote! { char },
+        Lit::Int(i) => compile_int_type(i.suffix()),
+        Lit::Float(f) if f.suffix() == FloatSuffix::F32 => quote! { f32 },
+        Lit::Float(_) => quote! { f64 },
+        Lit::Bool(_) => quote! { bool },
+        Lit::Verbatim(_) => quote! { compile_error!("unsupported action argument literal") },
+    }
+}
+
+fn compile_arg_type(arg: &ActionArg) -> TokenStream2 {
+    match arg {
+        ActionArg::Lit(lit) => compile_lit_type(lit),
+        ActionArg::Expr { ty, .. } => quote! { #ty },
+        ActionArg::Byte => quote! { Option<u8> },
+        ActionArg::Offset => quote! { usize },
+        ActionArg::Pin(_) => quote! { Option<&[u8]> },
+    }
+}
+
+// NOTE: the first occurrence of the action defines its signature. Calls
+// with inconsistent arguments or error checks will be reported by the
+// Rust compiler as mismatches with the trait method.
+#[derive(Default)]
+struct ActionsTrait {
+    names: HashSet<String>,
+    methods: Vec<TokenStream2>,
+}
+
+impl ActionsTrait {
+    fn add_method(&mut self, name: &str, compile_signature: impl FnOnce(Ident) -> TokenStream2) {
+        if self.names.insert(name.to_string()) {
+            let name = Ident::new(name, Span::call_site());
+
+            self.methods.push(compile_signature(name));
+        }
+    }
+
+    fn add_directives_methods(&mut self, directives: &Directives) {
+        for call in &directives.action_calls {
+            if let ActionCall::UserDefined {
+                name,
+                args,
+                with_error_check,
+            } = call
+            {
+                self.add_method(name, |name| {
+                    let arg_names = (0..args.len())
+                        .map(|i| Ident::new(&format!("arg{}", i), Span::call_site()));
+
+                    let arg_types = args.iter().map(compile_arg_type);
+
+                    let ret = if *with_error_check {
+                        quote! { -> Result<(), Error> }
+                    } else {
+                        quote! {}
+                    };
+
+                    quote! { fn #name(&mut self #(, #arg_names: #arg_types)*) #ret; }
+                });
+            }
+        }
+
+        if directives
+            .action_calls
+            .iter()
+            .any(|c| matches!(c, ActionCall::Emit { .. }))
+        {
+            self.add_method("on_token", |name| {
+                quote! { fn #name(&mut self, token: Token); }
+            });
+        }
+
+        if let Some(StateTransition {
+            target,
+            dynamic: true,
+            ..
+        }) = &directives.state_transition
+        {
+            self.add_method(target, |name| quote! { fn #name(&self) -> State; });
+        }
+    }
+}
+
+pub fn compile_actions_trait(grammar: &Grammar, tracer: bool) -> TokenStream2 {
+    let mut actions = ActionsTrait::default();
+
+    for arm in grammar.states.iter().flat_map(|s| s.arms.iter()) {
+        if let Pattern::Condition(ref condition) = arm.pattern {
+            actions.add_method(condition, |name| quote! { fn #name(&self, b: u8) -> bool; });
+        }
+
+        if let ArmRhs::Condition {
+            if_branch,
+            else_if_branches,
+            ..
+        } = &arm.rhs
+        {
+            for branch in once(if_branch).chain(else_if_branches) {
+                actions.add_method(&branch.condition, |name| {
+                    quote! { fn #name(&self) -> bool; }
+                });
+            }
+        }
+
+        for directives in arm.rhs.directives() {
+            actions.add_directives_methods(directives);
+        }
+    }
+
+    let methods = actions.methods;
+    let supertrait = if tracer {
+        quote! { : Tracer }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        pub trait Actions #supertrait {
+            #(#methods)*
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test_helpers::to_code_str;
+
+    curry_parse_macros!($Grammar);
+
+    #[test]
+    fn compile() {
+        assert_eq!(
+            to_code_str(compile_actions_trait(
+                &parse_ok! {
+                    TestGrammar = {
+                        foo_state:
+                            if is_foo => foo(1, "bar"), @pin(baz), --> dyn next_state.
+                            'a' => qux(@byte, @offset, @pin(baz)), @emit(Qux).
+                            'b' => @emit(Quux, baz).
+                            _ => if is_bar {
+                                bar(b'a', 'c', 2.5, 3usize, true)?.
+                            } else {
+                                foo(2, "qux").
+                            }
+                    }
+                },
+                false
+            )),
+            code_str! {
+                pub trait Actions {
+                    fn is_foo(&self, b: u8) -> bool;
+                    fn foo(&mut self, arg0: i32, arg1: &'static str);
+                    fn next_state(&self) -> State;
+                    fn qux(&mut self, arg0: Option<u8>, arg1: usize, arg2: Option<&[u8]>);
+                    fn on_token(&mut self, token: Token);
+                    fn is_bar(&self) -> bool;
+                    fn bar(&mut self, arg0: u8, arg1: char, arg2: f64, arg3: usize, arg4: bool)
+                        -> Result<(), Error>;
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn compile_expr_arg_types() {
+        assert_eq!(
+            to_code_str(compile_actions_trait(
+                &parse_ok! {
+                    TestGrammar = {
+                        foo_state:
+                            'a' => foo(Kind::Start: Kind, ::ns::Mode::RAW: ::ns::Mode).
+                            'b' => bar(MAX as u16, -1: i8, Some(2): Option<u8>).
+                            _ => baz(usize::count_ones(3): u32, consts::PI: f64).
+                    }
+                },
+                false
+            )),
+            code_str! {
+                pub trait Actions {
+                    fn foo(&mut self, arg0: Kind, arg1: ::ns::Mode);
+                    fn bar(&mut self, arg0: u16, arg1: i8, arg2: Option<u8>);
+                    fn baz(&mut self, arg0: u32, arg1: f64);
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn compile_with_tracer() {
+        assert_eq!(
+            to_code_str(compile_actions_trait(
+                &parse_ok! {
+                    TestGrammar = {
+                        foo_state:
+                            _ => foo.
+                    }
+                },
+                true
+            )),
+            code_str! {
+                pub trait Actions: Tracer {
+                    fn foo(&mut self);
+                }
+            }
+        );
+    }
+}
@@ -0,0 +1,823 @@
+mod actions;
+mod scan;
+mod tokens;
+mod tracer;
+mod tracing;
+
+use self::actions::compile_actions_trait;
+use self::scan::compile_delimiter_search;
+use self::tokens::{compile_suspension_check, compile_tokens_iterator};
+use self::tracer::{compile_chunk_resume_hook, compile_chunk_suspend_hook, compile_tracer_trait};
+use self::tracing::compile_tracing;
+use super::*;
+use crate::{compile_utf8_helpers, ActionCall, Compile, Pattern};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use std::collections::BTreeSet;
+use syn::Ident;
+
+impl Grammar {
+    fn compile_state_enum(&self) -> TokenStream2 {
+        let names = self
+            .states
+            .iter()
+            .map(|s| Ident::new(&s.name, Span::call_site()))
+            .collect::<Vec<_>>();
+
+        let names = &names;
+        let name_strs = self.states.iter().map(|s| &s.name);
+
+        quote! {
+            #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+            pub enum State {
+                #(#names),*
+            }
+
+            impl State {
+                pub fn name(self) -> &'static str {
+                    match self {
+                        #(State::#names => #name_strs),*
+                    }
+                }
+            }
+        }
+    }
+
+    fn compile_pins(&self) -> TokenStream2 {
+        let names = self
+            .states
+            .iter()
+            .flat_map(|s| s.arms.iter())
+            .flat_map(|a| a.rhs.directives())
+            .flat_map(|d| d.action_calls.iter())
+            .filter_map(|c| match c {
+                ActionCall::Pin(name) => Some(name.as_str()),
+                _ => None,
+            })
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(|n| Ident::new(n, Span::call_site()));
+
+        quote! {
+            #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+            pub struct Pins {
+                #(pub #names: Option<usize>),*
+            }
+        }
+    }
+
+    fn token_kinds(&self) -> BTreeSet<&str> {
+        self.states
+            .iter()
+            .flat_map(|s| s.arms.iter())
+            .flat_map(|a| a.rhs.directives())
+            .flat_map(|d| d.action_calls.iter())
+            .filter_map(|c| match c {
+                ActionCall::Emit { kind, .. } => Some(kind.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // NOTE: the decoder and the code point tables are generated only for
+    // the grammars with UTF-8 patterns, since the tables are large.
+    fn compile_utf8_decoding(&self) -> TokenStream2 {
+        let patterns = self
+            .states
+            .iter()
+            .flat_map(|s| s.arms.iter())
+            .map(|a| &a.pattern)
+            .filter(|p| matches!(p, Pattern::Unicode(_) | Pattern::InvalidUtf8))
+            .collect::<Vec<_>>();
+
+        if patterns.is_empty() {
+            return quote! {};
+        }
+
+        let mut properties = vec![];
+
+        for pattern in patterns {
+            if let Pattern::Unicode(property) = *pattern {
+                if !properties.contains(&property) {
+                    properties.push(property);
+                }
+            }
+        }
+
+        compile_utf8_helpers(&properties)
+    }
+
+    fn compile_released_byte_count(&self) -> TokenStream2 {
+        let pins = gen_parser_intrinsics!(pins);
+        let pins = self
+            .sliced_pins()
+            .into_iter()
+            .map(|n| {
+                let name = Ident::new(n, Span::call_site());
+
+                quote! { #pins.#name }
+            })
+            .collect::<Vec<_>>();
+
+        if pins.is_empty() {
+            return quote! { consumed_byte_count };
+        }
+
+        quote! {
+            [#(#pins),*]
+                .iter()
+                .flatten()
+                .fold(consumed_byte_count, |count, &pin| count.min(pin - self.__chunk_offset))
+        }
+    }
+
+    fn compile_token_enum(&self) -> TokenStream2 {
+        let kinds = self.token_kinds();
+
+        if kinds.is_empty() {
+            return quote! {};
+        }
+
+        let kind_strs = kinds.iter().collect::<Vec<_>>();
+        let kinds = kinds
+            .iter()
+            .map(|k| Ident::new(k, Span::call_site()))
+            .collect::<Vec<_>>();
+
+        let kinds = &kinds;
+
+        quote! {
+            /// Token emitted by the parser. Offsets are counted from the start of the input.
+            #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+            pub enum Token {
+                #(#kinds { start: usize, end: usize }),*
+            }
+
+            impl Token {
+                pub fn name(self) -> &'static str {
+                    match self {
+                        #(Token::#kinds { .. } => #kind_strs),*
+                    }
+                }
+
+                pub fn start(self) -> usize {
+                    match self {
+                        #(Token::#kinds { start, .. })|* => start
+                    }
+                }
+
+                pub fn end(self) -> usize {
+                    match self {
+                        #(Token::#kinds { end, .. })|* => end
+                    }
+                }
+            }
+        }
+    }
+
+    fn compile_error_type(&self) -> TokenStream2 {
+        let error_type = match self.error_type {
+            Some(ref t) => quote! { #t },
+            None => quote! { ::std::convert::Infallible },
+        };
+
+        quote! {
+            pub type Error = #error_type;
+
+            #[derive(Debug)]
+            pub struct ParsingError {
+                pub state: &'static str,
+                pub offset: usize,
+                pub byte: Option<u8>,
+                pub error: Error,
+            }
+
+            // NOTE: the error is formatted with `Debug`, since
+            // it is the only trait required from the error type.
+            impl std::fmt::Display for ParsingError {
+                fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    write!(f, "parsing error in `{}` at offset {} ", self.state, self.offset)?;
+
+                    match self.byte {
+                        Some(b) => write!(f, "(byte 0x{:02x})", b)?,
+                        None => write!(f, "(end of input)")?,
+                    }
+
+                    write!(f, ": {:?}", self.error)
+                }
+            }
+
+            impl std::error::Error for ParsingError {}
+        }
+    }
+
+    fn compile_parsing_loop(&self, tracer: bool) -> TokenStream2 {
+        let ch = gen_parser_intrinsics!(current_byte);
+        let input = gen_parser_intrinsics!(input);
+        let pos = gen_parser_intrinsics!(pos);
+        let state = gen_parser_intrinsics!(state);
+        let states = self
+            .states
+            .iter()
+            .enumerate()
+            .map(|(i, s)| s.compile(i, tracer, &self.states));
+
+        let suspension_check = if self.token_kinds().is_empty() {
+            quote! {}
+        } else {
+            compile_suspension_check()
+        };
+
+        quote! {
+            fn __run(&mut self, #input: &[u8]) -> Result<usize, ParsingError> {
+                loop {
+                    #suspension_check
+
+                    let #ch = #input.get(#pos).cloned();
+
+                    match #state {
+                        #(#states)*
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Grammar {
+    fn compile_parser_impl(&self, tracer: bool) -> TokenStream2 {
+        let initial_state = Ident::new(&self.states[0].name, Span::call_site());
+        let parsing_loop = self.compile_parsing_loop(tracer);
+
+        let (chunk_resume_hook, chunk_suspend_hook) = if tracer {
+            (compile_chunk_resume_hook(), compile_chunk_suspend_hook())
+        } else {
+            (quote! {}, quote! {})
+        };
+        let helpers = compile_parser_helpers();
+        let released_byte_count = self.compile_released_byte_count();
+        let trace_field = if tracer {
+            quote! { __trace: None, }
+        } else {
+            quote! {}
+        };
+
+        let (token_fields, tokens_iterator) = if self.token_kinds().is_empty() {
+            (quote! {}, quote! {})
+        } else {
+            (
+                quote! {
+                    __tokens: None,
+                    __is_suspended: false,
+                },
+                compile_tokens_iterator(),
+            )
+        };
+
+        quote! {
+            impl<A: Actions> Parser<A> {
+                pub fn new(actions: A) -> Self {
+                    Parser {
+                        actions,
+                        __pins: Pins::default(),
+                        __state: State::#initial_state,
+                        __state_enter: true,
+                        __is_last_input: false,
+                        __pos: 0,
+                        __chunk_offset: 0,
+                        #token_fields
+                        #trace_field
+                    }
+                }
+
+                pub fn state(&self) -> State {
+                    self.__state
+                }
+
+                pub fn pins(&self) -> &Pins {
+                    &self.__pins
+                }
+
+                pub fn into_actions(self) -> A {
+                    self.actions
+                }
+
+                /// Parses the chunk of input and returns the number of bytes at the
+                /// end of the chunk that were not consumed or are pinned. These bytes
+                /// should be prepended to the next chunk.
+                pub fn run_parsing_loop(
+                    &mut self,
+                    input: &[u8],
+                    is_last: bool
+                ) -> Result<usize, ParsingError> {
+                    self.__start_chunk(is_last);
+
+                    let blocked_byte_count = self.__run(input)?;
+
+                    Ok(self.__end_chunk(input, blocked_byte_count))
+                }
+
+                fn __start_chunk(&mut self, is_last: bool) {
+                    self.__is_last_input = is_last;
+
+                    #chunk_resume_hook
+                }
+
+                // NOTE: input from the earliest pin which input is passed to the
+                // actions is blocked too, so the pinned slices span the chunks.
+                // Parsing resumes after the bytes that are already parsed.
+                fn __end_chunk(&mut self, input: &[u8], blocked_byte_count: usize) -> usize {
+                    let consumed_byte_count = input.len() - blocked_byte_count;
+                    let released_byte_count = #released_byte_count;
+
+                    self.__chunk_offset += released_byte_count;
+                    self.__pos = consumed_byte_count - released_byte_count;
+
+                    let blocked_byte_count = input.len() - released_byte_count;
+
+                    #chunk_suspend_hook
+
+                    blocked_byte_count
+                }
+
+                #parsing_loop
+                #helpers
+            }
+
+            #tokens_iterator
+        }
+    }
+}
+
+fn compile_parser_struct(has_tokens: bool, tracer: bool) -> TokenStream2 {
+    // NOTE: tokens are queued instead of being passed to the actions
+    // while they are pulled by the `Tokens` iterator.
+    let token_fields = if has_tokens {
+        quote! {
+            __tokens: Option<std::collections::VecDeque<Token>>,
+            __is_suspended: bool,
+        }
+    } else {
+        quote! {}
+    };
+
+    let trace_field = if tracer {
+        quote! { __trace: Option<Vec<TraceStep>>, }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        pub struct Parser<A: Actions> {
+            actions: A,
+            __pins: Pins,
+            __state: State,
+            __state_enter: bool,
+            __is_last_input: bool,
+            __pos: usize,
+            __chunk_offset: usize,
+            #token_fields
+            #trace_field
+        }
+
+        impl<A: Actions> Deref for Parser<A> {
+            type Target = A;
+
+            fn deref(&self) -> &A {
+                &self.actions
+            }
+        }
+
+        impl<A: Actions> DerefMut for Parser<A> {
+            fn deref_mut(&mut self) -> &mut A {
+                &mut self.actions
+            }
+        }
+    }
+}
+
+fn compile_parser_helpers() -> TokenStream2 {
+    let sequence_match = quote! {
+        fn __match_sequence(
+            &self,
+            input: &[u8],
+            sequence: &[u8],
+            ignore_case: bool
+        ) -> SequenceMatch {
+            let available = &input[self.__pos..];
+            let len = available.len().min(sequence.len());
+
+            let matches = if ignore_case {
+                available[..len].eq_ignore_ascii_case(&sequence[..len])
+            } else {
+                available[..len] == sequence[..len]
+            };
+
+            if !matches {
+                SequenceMatch::Mismatch
+            } else if len == sequence.len() {
+                SequenceMatch::Full
+            } else if self.__is_last_input {
+                SequenceMatch::Mismatch
+            } else {
+                SequenceMatch::Partial
+            }
+        }
+
+        fn __match_sequence_with(
+            &self,
+            input: &[u8],
+            len: usize,
+            element_matches: impl Fn(usize, u8) -> bool
+        ) -> SequenceMatch {
+            let available = &input[self.__pos..];
+
+            let matches = available
+                .iter()
+                .take(len)
+                .enumerate()
+                .all(|(i, &b)| element_matches(i, b));
+
+            if !matches {
+                SequenceMatch::Mismatch
+            } else if available.len() >= len {
+                SequenceMatch::Full
+            } else if self.__is_last_input {
+                SequenceMatch::Mismatch
+            } else {
+                SequenceMatch::Partial
+            }
+        }
+    };
+
+    // NOTE: input from the pins that are passed to the actions is kept in the
+    // chunk, so the pin is `None` only if it's not set.
+    let pin_slice = quote! {
+        fn __pin_slice<'i>(&self, input: &'i [u8], pin: Option<usize>) -> Option<&'i [u8]> {
+            pin.map(|p| &input[p - self.__chunk_offset..self.__pos])
+        }
+    };
+
+    let skip = quote! {
+        fn __skip_until(&self, input: &[u8], delimiters: &[u8]) -> usize {
+            __find_delimiter(&input[self.__pos..], delimiters)
+        }
+
+        fn __skip_by_table(&self, input: &[u8], skippable: &[bool; 256]) -> usize {
+            input[self.__pos..]
+                .iter()
+                .take_while(|&&b| skippable[b as usize])
+                .count()
+        }
+    };
+
+    let error = quote! {
+        fn __error(&self, error: Error, state: &'static str, byte: Option<u8>) -> ParsingError {
+            ParsingError {
+                state,
+                offset: self.__chunk_offset + self.__pos,
+                byte,
+                error,
+            }
+        }
+    };
+
+    quote! {
+        #sequence_match
+        #error
+        #pin_slice
+        #skip
+    }
+}
+
+impl Compile for Grammar {
+    fn compile(&self) -> TokenStream2 {
+        let name = Ident::new(&self.name, Span::call_site());
+        let error_type = self.compile_error_type();
+        let state_enum = self.compile_state_enum();
+        // NOTE: parsers are generated without tracer hooks unless the
+        // `tracer` feature is enabled, so they don't pay for tracing.
+        let tracer = cfg!(feature = "tracer");
+        let tracer_trait = if tracer {
+            compile_tracer_trait()
+        } else {
+            quote! {}
+        };
+        let actions_trait = compile_actions_trait(self, tracer);
+        let pins = self.compile_pins();
+        let token_enum = self.compile_token_enum();
+        let parser_struct = compile_parser_struct(!self.token_kinds().is_empty(), tracer);
+        let parser_impl = self.compile_parser_impl(tracer);
+        let tracing = if tracer {
+            compile_tracing(self)
+        } else {
+            quote! {}
+        };
+        let utf8_decoding = self.compile_utf8_decoding();
+        let delimiter_search = compile_delimiter_search();
+
+        quote! {
+            #[allow(
+                non_snake_case,
+                non_camel_case_types,
+                dead_code,
+                unused_imports,
+                unused_parens,
+                unreachable_patterns,
+                clippy::all
+            )]
+            pub mod #name {
+                use super::*;
+                use std::ops::{Deref, DerefMut};
+
+                #error_type
+                #state_enum
+                #tracer_trait
+                #actions_trait
+                #pins
+                #token_enum
+
+                enum SequenceMatch {
+                    Full,
+                    Partial,
+                    Mismatch,
+                }
+
+                #utf8_decoding
+                #parser_struct
+                #parser_impl
+                #tracing
+                #delimiter_search
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test_helpers::to_code_str;
+
+    curry_parse_macros!($Grammar);
+
+    #[test]
+    fn compile_state_enum() {
+        assert_eq!(
+            to_code_str(
+                parse_ok! {
+                    TestGrammar = {
+                        foo_state:
+                            _ => --> bar_state.
+
+                        bar_state:
+                            _ => --> foo_state.
+                    }
+                }
+                .compile_state_enum()
+            ),
+            code_str! {
+                #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+                pub enum State {
+                    foo_state,
+                    bar_state
+                }
+
+                impl State {
+                    pub fn name(self) -> &'static str {
+                        match self {
+                            State::foo_state => "foo_state",
+                            State::bar_state => "bar_state"
+                        }
+                    }
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn compile_pins() {
+        assert_eq!(
+            to_code_str(
+                parse_ok! {
+                    TestGrammar = {
+                        foo_state:
+                            'a' => @pin(foo), --> bar_state.
+                            _ => if cond { @pin(bar). } else { @pin(foo). }
+
+                        bar_state:
+                            _ => @pin(baz).
+                    }
+                }
+                .compile_pins()
+            ),
+            code_str! {
+                #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+                pub struct Pins {
+                    pub bar: Option<usize>,
+                    pub baz: Option<usize>,
+                    pub foo: Option<usize>
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn compile_token_enum() {
+        assert_eq!(
+            to_code_str(
+                parse_ok! {
+                    TestGrammar = {
+                        foo_state:
+                            'a' => @pin(foo), --> bar_state.
+                            _ => @emit(Foo).
+
+                        bar_state:
+                            'b' => @emit(Bar, foo), @emit(Foo).
+                            _ => .
+                    }
+                }
+                .compile_token_enum()
+            ),
+            code_str! {
+                /// Token emitted by the parser. Offsets are counted from the start of the input.
+                #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+                pub enum Token {
+                    Bar { start: usize, end: usize },
+                    Foo { start: usize, end: usize }
+                }
+
+                impl Token {
+                    pub fn name(self) -> &'static str {
+                        match self {
+                            Token::Bar { .. } => "Bar",
+                            Token::Foo { .. } => "Foo"
+                        }
+                    }
+
+                    pub fn start(self) -> usize {
+                        match self {
+                            Token::Bar { start, .. } | Token::Foo { start, .. } => start
+                        }
+                    }
+
+                    pub fn end(self) -> usize {
+                        match self {
+                            Token::Bar { end, .. } | Token::Foo { end, .. } => end
+                        }
+                    }
+                }
+            }
+        );
+
+        assert_eq!(
+            to_code_str(parse_ok! { TestGrammar = { foo_state: _ => foo. } }.compile_token_enum()),
+            ""
+        );
+    }
+
+    #[test]
+    fn compile_error_type() {
+        let compile_error_type = |grammar: Grammar| to_code_str(grammar.compile_error_type());
+
+        let expected = |error_type| {
+            format!(
+                "pub type Error = {} ; {}",
+                error_type,
+                code_str! {
+                    #[derive(Debug)]
+                    pub struct ParsingError {
+                        pub state: &'static str,
+                        pub offset: usize,
+                        pub byte: Option<u8>,
+                        pub error: Error,
+                    }
+
+                    impl std::fmt::Display for ParsingError {
+                        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                            write!(f, "parsing error in `{}` at offset {} ", self.state, self.offset)?;
+
+                            match self.byte {
+                                Some(b) => write!(f, "(byte 0x{:02x})", b)?,
+                                None => write!(f, "(end of input)")?,
+                            }
+
+                            write!(f, ": {:?}", self.error)
+                        }
+                    }
+
+                    impl std::error::Error for ParsingError {}
+                }
+            )
+        };
+
+        assert_eq!(
+            compile_error_type(parse_ok! {
+                TestGrammar = {
+                    foo_state:
+                        _ => foo?.
+                }
+            }),
+            expected(code_str! { ::std::convert::Infallible })
+        );
+
+        assert_eq!(
+            compile_error_type(parse_ok! {
+                TestGrammar = {
+                    type Error = crate::FooError;
+
+                    foo_state:
+                        _ => foo?.
+                }
+            }),
+            expected(code_str! { crate::FooError })
+        );
+    }
+
+    #[test]
+    fn compile_parsing_loop() {
+        assert_eq!(
+            to_code_str(
+                parse_ok! {
+                    TestGrammar = {
+                        foo_state:
+                            _ => --> foo_state.
+                    }
+                }
+                .compile_parsing_loop(false)
+            ),
+            code_str! {
+                fn __run(&mut self, input: &[u8]) -> Result<usize, ParsingError> {
+                    loop {
+                        let ch = input.get(self.__pos).cloned();
+
+                        match self.__state {
+                            State::foo_state => {
+                                match ch {
+                                    Some(_) => {
+                                        self.__state = State::foo_state;
+                                        self.__state_enter = true;
+                                        self.__pos += 1;
+                                    }
+                                    Some(_) => {
+                                        self.__pos += 1;
+                                    }
+                                    None => {
+                                        return Ok(0);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn compile_parsing_loop_with_tokens() {
+        assert_eq!(
+            to_code_str(
+                parse_ok! {
+                    TestGrammar = {
+                        foo_state:
+                            _ => @emit(Foo).
+                    }
+                }
+                .compile_parsing_loop(false)
+            ),
+            code_str! {
+                fn __run(&mut self, input: &[u8]) -> Result<usize, ParsingError> {
+                    loop {
+                        if matches!(self.__tokens, Some(ref tokens) if !tokens.is_empty()) {
+                            self.__is_suspended = true;
+
+                            return Ok(0);
+                        }
+
+                        let ch = input.get(self.__pos).cloned();
+
+                        match self.__state {
+                            State::foo_state => {
+                                match ch {
+                                    Some(_) => {
+                                        self.__emit_token(Token::Foo {
+                                            start: (self.__chunk_offset + self.__pos),
+                                            end: (self.__chunk_offset + self.__pos) + 1usize,
+                                        });
+                                        self.__pos += 1;
+                                    }
+                                    Some(_) => {
+                                        self.__pos += 1;
+                                    }
+                                    None => {
+                                        return Ok(0);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        );
+    }
+}
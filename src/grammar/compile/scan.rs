@@ -0,0 +1,215 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+// NOTE: memchr-style search that checks 8 bytes at a time for the
+// presence of the delimiters (see "Determine if a word has a byte
+// equal to n" in Bit Twiddling Hacks). Used on the platforms without
+// SIMD support and for the tails of the vectorized searches.
+fn compile_swar_search() -> TokenStream2 {
+    quote! {
+        fn __find_delimiter_swar(haystack: &[u8], delimiters: &[u8]) -> usize {
+            const LO: u64 = 0x0101_0101_0101_0101;
+            const HI: u64 = 0x8080_8080_8080_8080;
+
+            let has_delimiter = |word: u64| {
+                delimiters.iter().any(|&d| {
+                    let x = word ^ (LO * d as u64);
+
+                    x.wrapping_sub(LO) & !x & HI != 0
+                })
+            };
+
+            let mut pos = 0;
+
+            while pos + 8 <= haystack.len() {
+                let mut word = [0; 8];
+
+                word.copy_from_slice(&haystack[pos..pos + 8]);
+
+                if has_delimiter(u64::from_le_bytes(word)) {
+                    break;
+                }
+
+                pos += 8;
+            }
+
+            while pos < haystack.len() && !delimiters.contains(&haystack[pos]) {
+                pos += 1;
+            }
+
+            pos
+        }
+    }
+}
+
+fn compile_x86_64_search() -> TokenStream2 {
+    let sse2 = quote! {
+        #[cfg(target_arch = "x86_64")]
+        #[target_feature(enable = "sse2")]
+        unsafe fn __find_delimiter_sse2(haystack: &[u8], delimiters: &[u8]) -> usize {
+            use std::arch::x86_64::*;
+
+            let mut pos = 0;
+
+            unsafe {
+                while pos + 16 <= haystack.len() {
+                    let chunk = _mm_loadu_si128(haystack.as_ptr().add(pos) as *const __m128i);
+                    let mut eq = _mm_setzero_si128();
+
+                    for &d in delimiters {
+                        eq = _mm_or_si128(eq, _mm_cmpeq_epi8(chunk, _mm_set1_epi8(d as i8)));
+                    }
+
+                    let mask = _mm_movemask_epi8(eq) as u32;
+
+                    if mask != 0 {
+                        return pos + mask.trailing_zeros() as usize;
+                    }
+
+                    pos += 16;
+                }
+            }
+
+            pos + __find_delimiter_swar(&haystack[pos..], delimiters)
+        }
+    };
+
+    let avx2 = quote! {
+        #[cfg(target_arch = "x86_64")]
+        #[target_feature(enable = "avx2")]
+        unsafe fn __find_delimiter_avx2(haystack: &[u8], delimiters: &[u8]) -> usize {
+            use std::arch::x86_64::*;
+
+            let mut pos = 0;
+
+            unsafe {
+                while pos + 32 <= haystack.len() {
+                    let chunk = _mm256_loadu_si256(haystack.as_ptr().add(pos) as *const __m256i);
+                    let mut eq = _mm256_setzero_si256();
+
+                    for &d in delimiters {
+                        eq = _mm256_or_si256(eq, _mm256_cmpeq_epi8(chunk, _mm256_set1_epi8(d as i8)));
+                    }
+
+                    let mask = _mm256_movemask_epi8(eq) as u32;
+
+                    if mask != 0 {
+                        return pos + mask.trailing_zeros() as usize;
+                    }
+
+                    pos += 32;
+                }
+
+                pos + __find_delimiter_sse2(&haystack[pos..], delimiters)
+            }
+        }
+    };
+
+    quote! {
+        #sse2
+        #avx2
+    }
+}
+
+// NOTE: NEON has no movemask instruction, so the position of the delimiter
+// in the matched 16 byte block is found with the scalar search.
+fn compile_aarch64_search() -> TokenStream2 {
+    quote! {
+        #[cfg(target_arch = "aarch64")]
+        #[target_feature(enable = "neon")]
+        unsafe fn __find_delimiter_neon(haystack: &[u8], delimiters: &[u8]) -> usize {
+            use std::arch::aarch64::*;
+
+            let mut pos = 0;
+
+            unsafe {
+                while pos + 16 <= haystack.len() {
+                    let chunk = vld1q_u8(haystack.as_ptr().add(pos));
+                    let mut eq = vdupq_n_u8(0);
+
+                    for &d in delimiters {
+                        eq = vorrq_u8(eq, vceqq_u8(chunk, vdupq_n_u8(d)));
+                    }
+
+                    if vmaxvq_u8(eq) != 0 {
+                        break;
+                    }
+
+                    pos += 16;
+                }
+            }
+
+            pos + __find_delimiter_swar(&haystack[pos..], delimiters)
+        }
+    }
+}
+
+fn compile_dispatch() -> TokenStream2 {
+    quote! {
+        /// Returns the position of the first delimiter in the haystack (or the haystack's
+        /// length if there are no delimiters), using the widest SIMD instruction set
+        /// that is available on the CPU.
+        #[allow(unreachable_code)]
+        fn __find_delimiter(haystack: &[u8], delimiters: &[u8]) -> usize {
+            #[cfg(target_arch = "x86_64")]
+            {
+                if is_x86_feature_detected!("avx2") {
+                    return unsafe { __find_delimiter_avx2(haystack, delimiters) };
+                }
+
+                // NOTE: SSE2 is a part of the x86_64 baseline.
+                return unsafe { __find_delimiter_sse2(haystack, delimiters) };
+            }
+
+            #[cfg(target_arch = "aarch64")]
+            {
+                if std::arch::is_aarch64_feature_detected!("neon") {
+                    return unsafe { __find_delimiter_neon(haystack, delimiters) };
+                }
+            }
+
+            __find_delimiter_swar(haystack, delimiters)
+        }
+    }
+}
+
+// NOTE: the search functions are split into several quotes to stay below quote's recursion limit.
+pub fn compile_delimiter_search() -> TokenStream2 {
+    let dispatch = compile_dispatch();
+    let swar = compile_swar_search();
+    let x86_64 = compile_x86_64_search();
+    let aarch64 = compile_aarch64_search();
+
+    quote! {
+        #dispatch
+        #swar
+        #x86_64
+        #aarch64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test_helpers::to_code_str;
+
+    #[test]
+    fn compile_delimiter_search() {
+        let code = to_code_str(super::compile_delimiter_search());
+
+        for search in &[
+            "unsafe fn __find_delimiter_avx2",
+            "unsafe fn __find_delimiter_sse2",
+            "unsafe fn __find_delimiter_neon",
+            "fn __find_delimiter_swar",
+        ] {
+            assert!(code.contains(search), "`{}` is not generated", search);
+        }
+
+        assert!(code.contains(&to_code_str(quote! {
+            if is_x86_feature_detected!("avx2") {
+                return unsafe { __find_delimiter_avx2(haystack, delimiters) };
+            }
+        })));
+    }
+}
@@ -0,0 +1,177 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+// NOTE: the parser is suspended only at the top of the parsing loop, where the
+// whole parsing state (including the state enter flag) is stored in the parser.
+// Sequences are either matched within the chunk or block the input, so there
+// is no partial sequence progress to save on suspension.
+pub fn compile_suspension_check() -> TokenStream2 {
+    let tokens = gen_parser_intrinsics!(tokens);
+    let is_suspended = gen_parser_intrinsics!(is_suspended);
+
+    quote! {
+        if matches!(#tokens, Some(ref tokens) if !tokens.is_empty()) {
+            #is_suspended = true;
+
+            return Ok(0);
+        }
+    }
+}
+
+fn compile_tokens_struct() -> TokenStream2 {
+    quote! {
+        /// Iterator over the tokens emitted by the parser. The parser is driven only
+        /// until it emits the next token, so the input is parsed lazily.
+        ///
+        /// Tokens are not passed to `Actions::on_token` while the iterator exists.
+        /// If the iterator is dropped early, the rest of its input is discarded
+        /// and the parser resumes after the parsed bytes.
+        pub struct Tokens<'p, A: Actions, C: Iterator>
+        where
+            C::Item: AsRef<[u8]>,
+        {
+            parser: &'p mut Parser<A>,
+            chunks: std::iter::Peekable<C>,
+            /// Current chunk prepended with the blocked bytes of the previous one.
+            input: Vec<u8>,
+            is_in_chunk: bool,
+            is_done: bool,
+            error: Option<ParsingError>,
+        }
+
+        impl<'p, A: Actions, C: Iterator> Drop for Tokens<'p, A, C>
+        where
+            C::Item: AsRef<[u8]>,
+        {
+            fn drop(&mut self) {
+                if let Some(tokens) = self.parser.__tokens.take() {
+                    for token in tokens {
+                        self.parser.actions.on_token(token);
+                    }
+                }
+
+                self.parser.__chunk_offset += self.parser.__pos;
+                self.parser.__pos = 0;
+                self.parser.__is_suspended = false;
+            }
+        }
+    }
+}
+
+// NOTE: the code is split into several quotes to stay below quote's recursion limit.
+fn compile_tokens_iterator_impl() -> TokenStream2 {
+    let tokens = quote! { self.parser.__tokens };
+    let is_suspended = quote! { self.parser.__is_suspended };
+
+    // NOTE: if there are no chunks, the empty input is parsed as the
+    // last one, so the parser still reaches the end of the input.
+    let start_chunk = quote! {
+        if !self.is_in_chunk {
+            if let Some(chunk) = self.chunks.next() {
+                self.input.extend_from_slice(chunk.as_ref());
+            }
+
+            let is_last = self.chunks.peek().is_none();
+
+            self.parser.__start_chunk(is_last);
+            self.is_in_chunk = true;
+        }
+    };
+
+    let run = quote! {
+        match self.parser.__run(&self.input) {
+            Ok(_) if #is_suspended => #is_suspended = false,
+            Ok(blocked_byte_count) => {
+                let blocked_byte_count = self.parser.__end_chunk(&self.input, blocked_byte_count);
+                let consumed_byte_count = self.input.len() - blocked_byte_count;
+
+                self.input.drain(..consumed_byte_count);
+                self.is_in_chunk = false;
+                self.is_done = self.parser.__is_last_input;
+            }
+            Err(error) => self.error = Some(error),
+        }
+    };
+
+    quote! {
+        impl<'p, A: Actions, C: Iterator> Iterator for Tokens<'p, A, C>
+        where
+            C::Item: AsRef<[u8]>,
+        {
+            type Item = Result<Token, ParsingError>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                loop {
+                    if let Some(token) = #tokens.as_mut().and_then(|t| t.pop_front()) {
+                        return Some(Ok(token));
+                    }
+
+                    if let Some(error) = self.error.take() {
+                        self.is_done = true;
+
+                        return Some(Err(error));
+                    }
+
+                    if self.is_done {
+                        return None;
+                    }
+
+                    #start_chunk
+                    #run
+                }
+            }
+        }
+    }
+}
+
+fn compile_parser_methods() -> TokenStream2 {
+    let tokens = gen_parser_intrinsics!(tokens);
+    let actions = gen_parser_intrinsics!(actions);
+
+    quote! {
+        impl<A: Actions> Parser<A> {
+            /// Returns iterator over the tokens of the input that is parsed as the last chunk.
+            pub fn tokens<'i>(&mut self, input: &'i [u8]) -> Tokens<'_, A, std::iter::Once<&'i [u8]>> {
+                self.tokens_from_chunks(std::iter::once(input))
+            }
+
+            /// Returns iterator over the tokens of the chunked input. Chunks are requested
+            /// when the parser needs more input (and one more chunk ahead to find out if
+            /// the current chunk is the last one).
+            pub fn tokens_from_chunks<C: IntoIterator>(&mut self, chunks: C) -> Tokens<'_, A, C::IntoIter>
+            where
+                C::Item: AsRef<[u8]>,
+            {
+                #tokens = Some(Default::default());
+
+                Tokens {
+                    parser: self,
+                    chunks: chunks.into_iter().peekable(),
+                    input: Vec::new(),
+                    is_in_chunk: false,
+                    is_done: false,
+                    error: None,
+                }
+            }
+
+            fn __emit_token(&mut self, token: Token) {
+                match #tokens {
+                    Some(ref mut tokens) => tokens.push_back(token),
+                    None => #actions.on_token(token),
+                }
+            }
+        }
+    }
+}
+
+pub fn compile_tokens_iterator() -> TokenStream2 {
+    let tokens_struct = compile_tokens_struct();
+    let iterator_impl = compile_tokens_iterator_impl();
+    let parser_methods = compile_parser_methods();
+
+    quote! {
+        #tokens_struct
+        #iterator_impl
+        #parser_methods
+    }
+}
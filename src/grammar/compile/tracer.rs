@@ -0,0 +1,46 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+pub fn compile_tracer_trait() -> TokenStream2 {
+    quote! {
+        /// Hooks that are called by the parser. Pattern and action call arguments
+        /// contain their grammar source text. Offsets are counted from the start
+        /// of the input.
+        pub trait Tracer {
+            fn on_state_enter(&mut self, _state: State, _offset: usize) {}
+            fn on_arm_match(&mut self, _state: State, _pattern: &'static str, _offset: usize) {}
+            fn on_action_call(&mut self, _call: &'static str, _offset: usize) {}
+
+            /// Called when the parser reaches the end of the chunk that is not the
+            /// last one. Blocked bytes are expected at the start of the next chunk.
+            fn on_chunk_suspend(
+                &mut self,
+                _state: State,
+                _offset: usize,
+                _blocked_byte_count: usize
+            ) {}
+
+            /// Called when the parser starts parsing the chunk.
+            fn on_chunk_resume(&mut self, _state: State, _offset: usize) {}
+        }
+    }
+}
+
+pub fn compile_chunk_resume_hook() -> TokenStream2 {
+    let actions = gen_parser_intrinsics!(actions);
+    let state = gen_parser_intrinsics!(state);
+
+    quote! { #actions.on_chunk_resume(#state, self.__chunk_offset); }
+}
+
+pub fn compile_chunk_suspend_hook() -> TokenStream2 {
+    let actions = gen_parser_intrinsics!(actions);
+    let state = gen_parser_intrinsics!(state);
+    let is_last_input = gen_parser_intrinsics!(is_last_input);
+
+    quote! {
+        if !#is_last_input {
+            #actions.on_chunk_suspend(#state, self.__chunk_offset, blocked_byte_count);
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use crate::graphviz::{dot_edges, dot_header, dot_nodes};
+use crate::Grammar;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+fn compile_dot_tables(grammar: &Grammar) -> TokenStream2 {
+    let nodes = dot_nodes(grammar).into_iter().map(|node| {
+        let state = match node.state {
+            Some(idx) => quote! { Some(#idx) },
+            None => quote! { None },
+        };
+
+        let id = node.id;
+        let attrs = node.attrs;
+
+        quote! { (#state, #id, &[#(#attrs),*]) }
+    });
+
+    let edges = dot_edges(grammar).into_iter().map(|edge| {
+        let state = edge.id.state;
+        let arm = edge.id.arm;
+        let branch = edge.id.branch;
+        let endpoints = edge.endpoints;
+        let label = edge.label;
+        let attrs = edge.attrs;
+
+        quote! { (#state, #arm, #branch, #endpoints, #label, &[#(#attrs),*]) }
+    });
+
+    let header = dot_header(grammar);
+
+    quote! {
+        const __DOT_HEADER: &str = #header;
+
+        const __DOT_NODES: &[(Option<usize>, &str, &[&str])] = &[#(#nodes),*];
+
+        const __DOT_EDGES: &[(usize, usize, usize, &str, &str, &[&str])] = &[#(#edges),*];
+    }
+}
+
+// NOTE: the rendering code is split into several quotes to stay below quote's recursion limit.
+fn compile_trace_to_dot() -> TokenStream2 {
+    let dot_line = quote! {
+        fn __dot_escape(s: &str) -> String {
+            s.replace('\\', "\\\\").replace('"', "\\\"")
+        }
+
+        fn __dot_line(item: &str, attrs: &[String]) -> String {
+            if attrs.is_empty() {
+                format!("    {};\n", item)
+            } else {
+                format!("    {} [{}];\n", item, attrs.join(", "))
+            }
+        }
+    };
+
+    let render_nodes = quote! {
+        for &(state, id, attrs) in __DOT_NODES {
+            let mut attrs = attrs.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+
+            if state.map_or(false, |state| trace.iter().any(|s| s.state() as usize == state)) {
+                attrs.push("style=filled".into());
+                attrs.push("fillcolor=lightyellow".into());
+            }
+
+            dot.push_str(&Self::__dot_line(id, &attrs));
+        }
+    };
+
+    let render_edges = quote! {
+        for &(state, arm, branch, endpoints, label, attrs) in __DOT_EDGES {
+            let hits = trace
+                .iter()
+                .filter(|s| s.edge() == Some((state, arm, branch)))
+                .count();
+
+            let mut all_attrs = if hits > 0 {
+                vec![format!("label=\"{}\\nhits: {}\"", label, hits), "penwidth=2".into()]
+            } else {
+                vec![format!("label=\"{}\"", label)]
+            };
+
+            all_attrs.extend(attrs.iter().map(|a| a.to_string()));
+            dot.push_str(&Self::__dot_line(endpoints, &all_attrs));
+        }
+    };
+
+    let render_sequence = quote! {
+        let mut sequence = String::new();
+
+        for (i, step) in trace.iter().enumerate() {
+            let label = match *step {
+                TraceStep::ActionCall { call, .. } => format!("call {}", Self::__dot_escape(call)),
+                _ => __DOT_EDGES
+                    .iter()
+                    .find(|e| Some((e.0, e.1, e.2)) == step.edge())
+                    .map_or(String::new(), |e| e.4.replace("\\n", " / ")),
+            };
+
+            sequence.push_str(&format!(
+                "{}. {} @{}: {}\\l",
+                i + 1,
+                step.state().name(),
+                step.offset(),
+                label
+            ));
+        }
+
+        let sequence_attrs = vec!["shape=note".into(), format!("label=\"{}\"", sequence)];
+
+        dot.push_str(&Self::__dot_line("\"__sequence\"", &sequence_attrs));
+    };
+
+    quote! {
+        #dot_line
+
+        /// Renders the grammar's DOT graph with the traced path: visited states are
+        /// filled, taken edges are bold and labeled with hit counts, and the note
+        /// node lists the exact sequence of taken arms and action calls.
+        pub fn trace_to_dot(&self) -> String {
+            let trace = self.trace();
+            let mut dot = String::from(__DOT_HEADER);
+
+            #render_nodes
+            #render_edges
+            #render_sequence
+
+            dot.push_str("}\n");
+            dot
+        }
+    }
+}
+
+// NOTE: tracing is available only with the `tracer` feature and is off
+// until `start_tracing` is called, since the trace grows with the input.
+pub fn compile_tracing(grammar: &Grammar) -> TokenStream2 {
+    let dot_tables = compile_dot_tables(grammar);
+    let trace_to_dot = compile_trace_to_dot();
+
+    quote! {
+        /// Step of the parser: arm (and condition branch of the arm) taken in
+        /// the state or action called by the arm, in the order of the calls.
+        #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+        pub enum TraceStep {
+            Arm {
+                state: State,
+                arm: usize,
+                branch: usize,
+                offset: usize,
+            },
+            ActionCall {
+                state: State,
+                call: &'static str,
+                offset: usize,
+            },
+        }
+
+        impl TraceStep {
+            pub fn state(&self) -> State {
+                match *self {
+                    TraceStep::Arm { state, .. } | TraceStep::ActionCall { state, .. } => state,
+                }
+            }
+
+            pub fn offset(&self) -> usize {
+                match *self {
+                    TraceStep::Arm { offset, .. } | TraceStep::ActionCall { offset, .. } => offset,
+                }
+            }
+
+            fn edge(&self) -> Option<(usize, usize, usize)> {
+                match *self {
+                    TraceStep::Arm { state, arm, branch, .. } => Some((state as usize, arm, branch)),
+                    TraceStep::ActionCall { .. } => None,
+                }
+            }
+        }
+
+        #dot_tables
+
+        impl<A: Actions> Parser<A> {
+            pub fn start_tracing(&mut self) {
+                self.__trace = Some(Vec::new());
+            }
+
+            pub fn trace(&self) -> &[TraceStep] {
+                self.__trace.as_ref().map_or(&[], |t| &t[..])
+            }
+
+            fn __record(&mut self, step: TraceStep) {
+                if let Some(ref mut trace) = self.__trace {
+                    trace.push(step);
+                }
+            }
+
+            fn __record_step(&mut self, arm: usize, branch: usize) {
+                self.__record(TraceStep::Arm {
+                    state: self.__state,
+                    arm,
+                    branch,
+                    offset: self.__chunk_offset + self.__pos,
+                });
+            }
+
+            fn __record_action_call(&mut self, call: &'static str) {
+                self.__record(TraceStep::ActionCall {
+                    state: self.__state,
+                    call,
+                    offset: self.__chunk_offset + self.__pos,
+                });
+            }
+
+            #trace_to_dot
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test_helpers::to_code_str;
+
+    curry_parse_macros!($Grammar);
+
+    #[test]
+    fn compile_dot_tables() {
+        assert_eq!(
+            to_code_str(super::compile_dot_tables(&parse_ok! {
+                TestGrammar = {
+                    foo_state:
+                        'a' => foo, --> dyn next_state.
+                        _ => if cond { move --> foo_state. } else { bar. }
+                }
+            })),
+            code_str! {
+                const __DOT_HEADER: &str =
+                    "digraph \"TestGrammar\" {\n    rankdir=LR;\n    node [shape=box];\n";
+
+                const __DOT_NODES: &[(Option<usize>, &str, &[&str])] = &[
+                    (Some(0usize), "\"foo_state\"", &["peripheries=2"]),
+                    (None, "\"dyn next_state\"", &["shape=diamond"])
+                ];
+
+                const __DOT_EDGES: &[(usize, usize, usize, &str, &str, &[&str])] = &[
+                    (
+                        0usize,
+                        0usize,
+                        0usize,
+                        "\"foo_state\" -> \"dyn next_state\"",
+                        "'a'\\nfoo",
+                        &["style=dotted"]
+                    ),
+                    (
+                        0usize,
+                        1usize,
+                        0usize,
+                        "\"foo_state\" -> \"foo_state\"",
+                        "_ => if cond",
+                        &["style=dashed", "color=blue", "fontcolor=blue"]
+                    ),
+                    (
+                        0usize,
+                        1usize,
+                        1usize,
+                        "\"foo_state\" -> \"foo_state\"",
+                        "_ => else\\nbar",
+                        &["color=blue", "fontcolor=blue"]
+                    )
+                ];
+            }
+        );
+    }
+}
@@ -0,0 +1,373 @@
+use super::*;
+use crate::{Arm, ArmRhs, ConditionBranch, Directives, StateTransition};
+use std::fmt::{self, Display, Formatter};
+
+/// Equivalent states that were merged by `Grammar::minimize`.
+#[derive(Debug, PartialEq)]
+pub struct StateMerge {
+    /// State that is kept in the grammar.
+    pub state: String,
+    /// States that were replaced by the kept state.
+    pub merged: Vec<String>,
+}
+
+impl Display for StateMerge {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} <- {}", self.state, self.merged.join(", "))
+    }
+}
+
+// NOTE: states are compared structurally, except the transition targets
+// which are compared by the block of the partition they belong to.
+struct Partition<'g> {
+    names: Vec<&'g str>,
+    blocks: Vec<usize>,
+}
+
+impl<'g> Partition<'g> {
+    fn block(&self, name: &str) -> Option<usize> {
+        self.names
+            .iter()
+            .position(|&n| n == name)
+            .map(|i| self.blocks[i])
+    }
+
+    fn same_transition(&self, t1: &StateTransition, t2: &StateTransition) -> bool {
+        let same_target = t1.target == t2.target
+            || self.block(&t1.target).is_some() && self.block(&t1.target) == self.block(&t2.target);
+
+        t1.epsilon_move == t2.epsilon_move && same_target
+    }
+
+    fn same_directives(&self, d1: &Directives, d2: &Directives) -> bool {
+        let same_transition = match (&d1.state_transition, &d2.state_transition) {
+            (Some(t1), Some(t2)) => self.same_transition(t1, t2),
+            (None, None) => true,
+            _ => false,
+        };
+
+        d1.action_calls == d2.action_calls && same_transition
+    }
+
+    fn same_branches(&self, b1: &ConditionBranch, b2: &ConditionBranch) -> bool {
+        b1.condition == b2.condition && self.same_directives(&b1.directives, &b2.directives)
+    }
+
+    fn same_arms(&self, a1: &Arm, a2: &Arm) -> bool {
+        let same_rhs = match (&a1.rhs, &a2.rhs) {
+            (ArmRhs::Directives(d1), ArmRhs::Directives(d2)) => self.same_directives(d1, d2),
+            (
+                ArmRhs::Condition {
+                    if_branch: if1,
+                    else_if_branches: else_ifs1,
+                    else_branch: else1,
+                },
+                ArmRhs::Condition {
+                    if_branch: if2,
+                    else_if_branches: else_ifs2,
+                    else_branch: else2,
+                },
+            ) => {
+                self.same_branches(if1, if2)
+                    && else_ifs1.len() == else_ifs2.len()
+                    && else_ifs1
+                        .iter()
+                        .zip(else_ifs2)
+                        .all(|(b1, b2)| self.same_branches(b1, b2))
+                    && self.same_directives(else1, else2)
+            }
+            _ => false,
+        };
+
+        a1.pattern == a2.pattern && same_rhs
+    }
+
+    fn equivalent(&self, s1: &State, s2: &State) -> bool {
+        s1.arms.len() == s2.arms.len()
+            && s1
+                .arms
+                .iter()
+                .zip(&s2.arms)
+                .all(|(a1, a2)| self.same_arms(a1, a2))
+    }
+}
+
+fn rename_targets(directives: &mut Directives, renames: &[(String, String)]) {
+    if let Some(ref mut transition) = directives.state_transition {
+        if let Some((_, to)) = renames.iter().find(|(from, _)| *from == transition.target) {
+            transition.target = to.clone();
+        }
+    }
+}
+
+impl Grammar {
+    fn has_dynamic_transitions(&self) -> bool {
+        self.states
+            .iter()
+            .flat_map(|s| s.arms.iter())
+            .flat_map(|a| a.rhs.directives())
+            .filter_map(|d| d.state_transition.as_ref())
+            .any(|t| t.dynamic)
+    }
+
+    // NOTE: Moore's partition refinement: all states start in one block
+    // and blocks are split until equivalent states refer to the same blocks.
+    fn equivalence_blocks(&self) -> Vec<usize> {
+        let mut partition = Partition {
+            names: self.states.iter().map(|s| s.name.as_str()).collect(),
+            blocks: vec![0; self.states.len()],
+        };
+
+        let mut block_count = 1;
+
+        loop {
+            let mut blocks: Vec<usize> = Vec::with_capacity(self.states.len());
+            let mut new_block_count = 0;
+
+            for (i, state) in self.states.iter().enumerate() {
+                let block = (0..i)
+                    .find(|&j| {
+                        partition.blocks[i] == partition.blocks[j]
+                            && partition.equivalent(state, &self.states[j])
+                    })
+                    .map(|j| blocks[j]);
+
+                blocks.push(block.unwrap_or_else(|| {
+                    new_block_count += 1;
+                    new_block_count - 1
+                }));
+            }
+
+            partition.blocks = blocks;
+
+            // NOTE: blocks are only split, so the partition is stable
+            // if the refinement didn't produce new blocks.
+            if new_block_count == block_count {
+                return partition.blocks;
+            }
+
+            block_count = new_block_count;
+        }
+    }
+
+    /// Merges equivalent states: states that have the same arms with the same actions
+    /// and transitions to equivalent states. The first of the equivalent states is kept
+    /// (so the initial state is never replaced) and transitions to the rest of them are
+    /// redirected to it. Merged states are removed from the generated `State` enum.
+    ///
+    /// Grammars with dynamic transitions are not minimized, since their targets are
+    /// `State` values returned by the actions, so any of the states can be a target.
+    pub fn minimize(&mut self) -> Vec<StateMerge> {
+        if self.has_dynamic_transitions() {
+            return vec![];
+        }
+
+        let blocks = self.equivalence_blocks();
+        let mut merges: Vec<StateMerge> = vec![];
+        let mut renames = vec![];
+
+        for (i, &block) in blocks.iter().enumerate() {
+            let kept = blocks.iter().position(|&b| b == block).unwrap_or(i);
+
+            if kept == i {
+                continue;
+            }
+
+            let kept_name = self.states[kept].name.clone();
+            let merged_name = self.states[i].name.clone();
+
+            match merges.iter_mut().find(|m| m.state == kept_name) {
+                Some(merge) => merge.merged.push(merged_name.clone()),
+                None => merges.push(StateMerge {
+                    state: kept_name.clone(),
+                    merged: vec![merged_name.clone()],
+                }),
+            }
+
+            renames.push((merged_name, kept_name));
+        }
+
+        if renames.is_empty() {
+            return merges;
+        }
+
+        self.states
+            .retain(|s| !renames.iter().any(|(from, _)| *from == s.name));
+
+        for arm in self.states.iter_mut().flat_map(|s| s.arms.iter_mut()) {
+            match arm.rhs {
+                ArmRhs::Directives(ref mut d) => rename_targets(d, &renames),
+                ArmRhs::Condition {
+                    ref mut if_branch,
+                    ref mut else_if_branches,
+                    ref mut else_branch,
+                } => {
+                    rename_targets(&mut if_branch.directives, &renames);
+
+                    for branch in else_if_branches {
+                        rename_targets(&mut branch.directives, &renames);
+                    }
+
+                    rename_targets(else_branch, &renames);
+                }
+            }
+        }
+
+        merges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    curry_parse_macros!($Grammar);
+
+    #[test]
+    fn minimize() {
+        let mut grammar = parse_ok! {
+            TestGrammar = {
+                data_state:
+                    '<' => --> tag_open_state.
+                    '"' => --> quoted_state.
+                    '\'' => --> single_quoted_state.
+
+                quoted_state:
+                    '"' => --> data_state.
+                    _ => if is_foo { foo, --> tag_open_state. } else { bar. }
+
+                tag_open_state:
+                    '>' => --> data_state.
+                    _ => --> quoted_state.
+
+                single_quoted_state:
+                    '"' => --> data_state.
+                    _ => if is_foo { foo, --> tag_open_copy_state. } else { bar. }
+
+                tag_open_copy_state:
+                    '>' => --> data_state.
+                    _ => --> single_quoted_state.
+            }
+        };
+
+        let merges = grammar.minimize();
+
+        assert_eq!(
+            merges.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec![
+                "quoted_state <- single_quoted_state",
+                "tag_open_state <- tag_open_copy_state",
+            ]
+        );
+
+        assert_eq!(
+            grammar,
+            parse_ok! {
+                TestGrammar = {
+                    data_state:
+                        '<' => --> tag_open_state.
+                        '"' => --> quoted_state.
+                        '\'' => --> quoted_state.
+
+                    quoted_state:
+                        '"' => --> data_state.
+                        _ => if is_foo { foo, --> tag_open_state. } else { bar. }
+
+                    tag_open_state:
+                        '>' => --> data_state.
+                        _ => --> quoted_state.
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn minimize_cycles() {
+        let mut grammar = parse_ok! {
+            TestGrammar = {
+                foo_state:
+                    'a' => --> bar_state.
+                    'b' => --> baz_state.
+
+                bar_state:
+                    'a' => foo, --> baz_state.
+
+                baz_state:
+                    'a' => foo, --> bar_state.
+            }
+        };
+
+        assert_eq!(
+            grammar.minimize(),
+            vec![StateMerge {
+                state: "bar_state".into(),
+                merged: vec!["baz_state".into()]
+            }]
+        );
+
+        assert_eq!(
+            grammar,
+            parse_ok! {
+                TestGrammar = {
+                    foo_state:
+                        'a' => --> bar_state.
+                        'b' => --> bar_state.
+
+                    bar_state:
+                        'a' => foo, --> bar_state.
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn no_merges() {
+        let grammar = || {
+            parse_ok! {
+                TestGrammar = {
+                    foo_state:
+                        'a' => --> bar_state.
+                        _ => foo.
+
+                    bar_state:
+                        'a' => --> baz_state.
+                        _ => foo.
+
+                    baz_state:
+                        'a' => --> foo_state.
+                        _ => bar.
+                }
+            }
+        };
+
+        let mut minimized = grammar();
+
+        assert!(minimized.minimize().is_empty());
+        assert_eq!(minimized, grammar());
+    }
+
+    #[test]
+    fn no_merges_with_dynamic_transitions() {
+        let grammar = || {
+            parse_ok! {
+                TestGrammar = {
+                    foo_state:
+                        'a' => --> bar_state.
+                        'b' => --> baz_state.
+                        _ => --> dyn next_state.
+
+                    bar_state:
+                        'a' => foo, --> foo_state.
+
+                    baz_state:
+                        'a' => foo, --> foo_state.
+                }
+            }
+        };
+
+        let mut minimized = grammar();
+
+        assert!(minimized.minimize().is_empty());
+        assert_eq!(minimized, grammar());
+    }
+}
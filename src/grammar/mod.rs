@@ -1,9 +1,39 @@
+mod compile;
+mod minimize;
 mod parse;
 
-use crate::State;
+pub use self::minimize::StateMerge;
+
+use crate::{ActionArg, ActionCall, NamedClass, State};
+use std::collections::BTreeSet;
+use syn::Type;
 
 #[derive(PartialEq, Debug)]
 pub struct Grammar {
     pub name: String,
+    pub error_type: Option<Type>,
+    /// Classes declared in the grammar, resolved into the sets of bytes.
+    pub classes: Vec<NamedClass>,
     pub states: Vec<State>,
 }
+
+impl Grammar {
+    /// Returns the names of the pins which input is passed to the actions
+    /// (`@pin(name)` arguments). Input from these pins is kept by the parsers.
+    pub(crate) fn sliced_pins(&self) -> BTreeSet<&str> {
+        self.states
+            .iter()
+            .flat_map(|s| s.arms.iter())
+            .flat_map(|a| a.rhs.directives())
+            .flat_map(|d| d.action_calls.iter())
+            .flat_map(|c| match c {
+                ActionCall::UserDefined { args, .. } => args.iter().collect(),
+                _ => vec![],
+            })
+            .filter_map(|a| match a {
+                ActionArg::Pin(name) => Some(name.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+}
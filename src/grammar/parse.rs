@@ -1,11 +1,37 @@
 use super::*;
+use crate::{resolve_class_declarations, ClassDeclaration};
 use syn::parse::{Parse, ParseStream};
-use syn::{braced, Ident, Result as ParseResult, Token};
+use syn::{braced, Error as ParseError, Ident, Result as ParseResult, Token};
+
+const ERR_UNKNOWN_TYPE_DECLARATION: &str =
+    "unknown type declaration. Only error type (`type Error = ...;`) can be declared";
+
+const ERR_DUPLICATE_ERROR_TYPE: &str = "error type is already declared for the grammar";
+
+fn parse_error_type(input: ParseStream) -> ParseResult<Type> {
+    input.parse::<Token! { type }>()?;
+
+    let name = input.parse::<Ident>()?;
+
+    if name != "Error" {
+        return Err(ParseError::new_spanned(name, ERR_UNKNOWN_TYPE_DECLARATION));
+    }
+
+    input.parse::<Token! { = }>()?;
+
+    let error_type = input.parse::<Type>()?;
+
+    input.parse::<Token! { ; }>()?;
+
+    Ok(error_type)
+}
 
 impl Parse for Grammar {
     fn parse(input: ParseStream) -> ParseResult<Self> {
         let braces_content;
         let mut states = vec![];
+        let mut error_type = None;
+        let mut class_declarations = vec![];
         let name = input.parse::<Ident>()?.to_string();
 
         input.parse::<Token! { = }>()?;
@@ -13,14 +39,37 @@ impl Parse for Grammar {
         braced!(braces_content in input);
 
         loop {
-            states.push(braces_content.parse::<State>()?);
+            if braces_content.peek(Token! { type }) {
+                if error_type.is_some() {
+                    return Err(braces_content.error(ERR_DUPLICATE_ERROR_TYPE));
+                }
+
+                error_type = Some(parse_error_type(&braces_content)?);
+            } else if ClassDeclaration::peek(&braces_content) {
+                class_declarations.push(braces_content.parse::<ClassDeclaration>()?);
+            } else {
+                break;
+            }
+        }
+
+        // NOTE: classes are resolved before the states are parsed,
+        // so the patterns of the states can refer to them.
+        let classes = resolve_class_declarations(&class_declarations)?;
+
+        loop {
+            states.push(State::parse_with_classes(&braces_content, &classes)?);
 
             if braces_content.is_empty() {
                 break;
             }
         }
 
-        Ok(Grammar { name, states })
+        Ok(Grammar {
+            name,
+            error_type,
+            classes,
+            states,
+        })
     }
 }
 
@@ -51,12 +100,17 @@ mod tests {
             },
             Grammar {
                 name: "TestGrammar".into(),
+                error_type: None,
+                classes: vec![],
                 states: vec![
                     State {
                         name: "foo_state".into(),
                         arms: vec![
                             Arm {
-                                pattern: Pattern::Byte(b'a'),
+                                pattern: Pattern::Byte {
+                                    byte: b'a',
+                                    ignore_case: false,
+                                },
                                 rhs: ArmRhs::Directives(Directives {
                                     action_calls: vec![act!("bar")],
                                     state_transition: Some(StateTransition {
@@ -126,6 +180,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_error_type() {
+        let grammar = parse_ok! {
+            TestGrammar = {
+                type Error = crate::errors::FooError;
+
+                foo_state:
+                    _ => bar?.
+            }
+        };
+
+        assert_eq!(
+            grammar.error_type,
+            Some(parse!(<Type>, { crate::errors::FooError }).unwrap())
+        );
+    }
+
+    #[test]
+    fn unknown_type_declaration_error() {
+        assert_eq!(
+            parse_err! {
+                TestGrammar = {
+                    type Foo = Bar;
+
+                    foo_state:
+                        _ => bar.
+                }
+            },
+            ERR_UNKNOWN_TYPE_DECLARATION
+        );
+    }
+
+    #[test]
+    fn duplicate_error_type_error() {
+        assert_eq!(
+            parse_err! {
+                TestGrammar = {
+                    type Error = Foo;
+                    type Error = Bar;
+
+                    foo_state:
+                        _ => bar.
+                }
+            },
+            ERR_DUPLICATE_ERROR_TYPE
+        );
+    }
+
     #[test]
     fn empty_grammar_error() {
         assert_eq!(
@@ -0,0 +1,324 @@
+//! GraphViz DOT export of the grammar state machines.
+
+use crate::{ArmRhs, Directives, Grammar, StateTransition};
+
+/// Identifies the edge by the state, the arm and the condition branch of the arm
+/// (`if` branch is `0`, `else if` branches follow it and `else` branch is the last one).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EdgeId {
+    pub state: usize,
+    pub arm: usize,
+    pub branch: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target<'g> {
+    State(&'g str),
+    Dyn(&'g str),
+}
+
+#[derive(Debug)]
+struct Edge<'g> {
+    id: EdgeId,
+    from: &'g str,
+    to: Target<'g>,
+    label: String,
+    epsilon_move: bool,
+    condition_branch: bool,
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn node_id(target: Target) -> String {
+    match target {
+        Target::State(name) => format!("\"{}\"", escape(name)),
+        Target::Dyn(getter) => format!("\"dyn {}\"", escape(getter)),
+    }
+}
+
+fn edge<'g>(
+    id: EdgeId,
+    from: &'g str,
+    label: String,
+    directives: &'g Directives,
+    condition_branch: bool,
+) -> Edge<'g> {
+    let mut label = label;
+
+    if !directives.action_calls.is_empty() {
+        let calls = directives
+            .action_calls
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        label.push('\n');
+        label.push_str(&calls.join(", "));
+    }
+
+    let (to, epsilon_move) = match directives.state_transition {
+        Some(StateTransition {
+            ref target,
+            dynamic,
+            epsilon_move,
+        }) => {
+            let to = if dynamic {
+                Target::Dyn(target)
+            } else {
+                Target::State(target)
+            };
+
+            (to, epsilon_move)
+        }
+        None => (Target::State(from), false),
+    };
+
+    Edge {
+        id,
+        from,
+        to,
+        label,
+        epsilon_move,
+        condition_branch,
+    }
+}
+
+fn edges(grammar: &Grammar) -> Vec<Edge<'_>> {
+    let mut edges = vec![];
+
+    for (state_idx, state) in grammar.states.iter().enumerate() {
+        for (arm_idx, arm) in state.arms.iter().enumerate() {
+            let id = |branch| EdgeId {
+                state: state_idx,
+                arm: arm_idx,
+                branch,
+            };
+
+            let pattern = arm.pattern.to_string();
+
+            match arm.rhs {
+                ArmRhs::Directives(ref d) => {
+                    edges.push(edge(id(0), &state.name, pattern, d, false))
+                }
+                ArmRhs::Condition {
+                    ref if_branch,
+                    ref else_if_branches,
+                    ref else_branch,
+                } => {
+                    let branches = Some(("if", if_branch))
+                        .into_iter()
+                        .chain(else_if_branches.iter().map(|b| ("else if", b)));
+
+                    for (branch_idx, (keyword, branch)) in branches.enumerate() {
+                        let label = format!("{} => {} {}", pattern, keyword, branch.condition);
+
+                        edges.push(edge(
+                            id(branch_idx),
+                            &state.name,
+                            label,
+                            &branch.directives,
+                            true,
+                        ));
+                    }
+
+                    let label = format!("{} => else", pattern);
+                    let else_idx = else_if_branches.len() + 1;
+
+                    edges.push(edge(id(else_idx), &state.name, label, else_branch, true));
+                }
+            }
+        }
+    }
+
+    edges
+}
+
+// NOTE: nodes and edges are exposed to the parser compiler, so generated parsers
+// can render tracing graphs without depending on this crate at runtime.
+pub(crate) struct DotNode {
+    /// Index of the state (`None` for the dynamic transition nodes).
+    pub state: Option<usize>,
+    pub id: String,
+    pub attrs: Vec<String>,
+}
+
+pub(crate) struct DotEdge {
+    pub id: EdgeId,
+    /// Node ids joined with `->`.
+    pub endpoints: String,
+    /// Escaped label text.
+    pub label: String,
+    pub attrs: Vec<String>,
+}
+
+pub(crate) fn dot_header(grammar: &Grammar) -> String {
+    format!(
+        "digraph \"{}\" {{\n    rankdir=LR;\n    node [shape=box];\n",
+        escape(&grammar.name)
+    )
+}
+
+pub(crate) fn dot_line(item: &str, attrs: &[String]) -> String {
+    if attrs.is_empty() {
+        format!("    {};\n", item)
+    } else {
+        format!("    {} [{}];\n", item, attrs.join(", "))
+    }
+}
+
+pub(crate) fn dot_nodes(grammar: &Grammar) -> Vec<DotNode> {
+    let mut nodes = grammar
+        .states
+        .iter()
+        .enumerate()
+        .map(|(i, state)| DotNode {
+            state: Some(i),
+            id: node_id(Target::State(&state.name)),
+            attrs: if i == 0 {
+                vec!["peripheries=2".into()]
+            } else {
+                vec![]
+            },
+        })
+        .collect::<Vec<_>>();
+
+    let mut dyn_targets = vec![];
+
+    for edge in edges(grammar) {
+        if let Target::Dyn(_) = edge.to {
+            if !dyn_targets.contains(&edge.to) {
+                dyn_targets.push(edge.to);
+            }
+        }
+    }
+
+    nodes.extend(dyn_targets.into_iter().map(|target| DotNode {
+        state: None,
+        id: node_id(target),
+        attrs: vec!["shape=diamond".into()],
+    }));
+
+    nodes
+}
+
+pub(crate) fn dot_edges(grammar: &Grammar) -> Vec<DotEdge> {
+    edges(grammar)
+        .into_iter()
+        .map(|edge| {
+            let mut attrs = vec![];
+
+            if edge.epsilon_move {
+                attrs.push("style=dashed".into());
+            } else if let Target::Dyn(_) = edge.to {
+                attrs.push("style=dotted".into());
+            }
+
+            if edge.condition_branch {
+                attrs.push("color=blue".into());
+                attrs.push("fontcolor=blue".into());
+            }
+
+            DotEdge {
+                id: edge.id,
+                endpoints: format!(
+                    "{} -> {}",
+                    node_id(Target::State(edge.from)),
+                    node_id(edge.to)
+                ),
+                label: escape(&edge.label),
+                attrs,
+            }
+        })
+        .collect()
+}
+
+/// Exports grammar as a DOT graph: states are nodes (the initial state has a double border)
+/// and arms are edges labeled with their patterns and action calls. Epsilon move (`move`)
+/// edges are dashed, dynamic transitions are dotted edges to the diamond node of the state
+/// getter and condition branches are blue.
+pub fn to_dot(grammar: &Grammar) -> String {
+    let mut dot = dot_header(grammar);
+
+    for node in dot_nodes(grammar) {
+        dot.push_str(&dot_line(&node.id, &node.attrs));
+    }
+
+    for edge in dot_edges(grammar) {
+        let attrs = Some(format!("label=\"{}\"", edge.label))
+            .into_iter()
+            .chain(edge.attrs)
+            .collect::<Vec<_>>();
+
+        dot.push_str(&dot_line(&edge.endpoints, &attrs));
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    curry_parse_macros!($Grammar);
+
+    #[test]
+    fn export() {
+        let grammar = parse_ok! {
+            TestGrammar = {
+                data_state:
+                    --> => enter.
+                    '<' => @pin(start), --> tag_open_state.
+                    "&amp;"|i => amp("&").
+                    eof => done.
+                    _ => .
+
+                tag_open_state:
+                    alpha => if is_end {
+                        end, --> dyn next_state.
+                    } else if is_foo {
+                        move --> data_state.
+                    } else {
+                        tag_name?.
+                    }
+                    _ => move --> data_state.
+            }
+        };
+
+        assert_eq!(
+            to_dot(&grammar),
+            [
+                "digraph \"TestGrammar\" {",
+                "    rankdir=LR;",
+                "    node [shape=box];",
+                "    \"data_state\" [peripheries=2];",
+                "    \"tag_open_state\";",
+                "    \"dyn next_state\" [shape=diamond];",
+                "    \"data_state\" -> \"data_state\" [label=\"-->\\nenter\"];",
+                "    \"data_state\" -> \"tag_open_state\" [label=\"'<'\\n@pin(start)\"];",
+                "    \"data_state\" -> \"data_state\" [label=\"\\\"&amp;\\\"|i\\namp(\\\"&\\\")\"];",
+                "    \"data_state\" -> \"data_state\" [label=\"eof\\ndone\"];",
+                "    \"data_state\" -> \"data_state\" [label=\"_\"];",
+                concat![
+                    "    \"tag_open_state\" -> \"dyn next_state\" ",
+                    "[label=\"alpha => if is_end\\nend\", style=dotted, color=blue, fontcolor=blue];"
+                ],
+                concat![
+                    "    \"tag_open_state\" -> \"data_state\" ",
+                    "[label=\"alpha => else if is_foo\", style=dashed, color=blue, fontcolor=blue];"
+                ],
+                concat![
+                    "    \"tag_open_state\" -> \"tag_open_state\" ",
+                    "[label=\"alpha => else\\ntag_name?\", color=blue, fontcolor=blue];"
+                ],
+                "    \"tag_open_state\" -> \"data_state\" [label=\"_\", style=dashed];",
+                "}\n",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -33,7 +33,7 @@ macro_rules! parse3_if_present {
 
 #[cfg(test)]
 #[macro_use]
-mod test_helpers {
+pub(crate) mod test_helpers {
     macro_rules! parse {
         (<$AstNode:path>, { $($t:tt)* }) => {
             syn::parse_str::<$AstNode>(stringify!($($t)*))
@@ -61,12 +61,38 @@ mod test_helpers {
         };
     }
 
+    use proc_macro2::{Delimiter, TokenStream, TokenTree};
+
+    // NOTE: spacing of punctuation in the `to_string` output depends on the
+    // way tokens were produced (e.g. `&'static` vs `& 'static`), so we print
+    // all tokens separated by a single space to make code comparable.
+    pub fn to_code_str(tokens: TokenStream) -> String {
+        tokens
+            .into_iter()
+            .map(|t| match t {
+                TokenTree::Group(g) => {
+                    let (open, close) = match g.delimiter() {
+                        Delimiter::Parenthesis => ("(", ")"),
+                        Delimiter::Brace => ("{", "}"),
+                        Delimiter::Bracket => ("[", "]"),
+                        Delimiter::None => ("", ""),
+                    };
+
+                    format!("{} {} {}", open, to_code_str(g.stream()), close)
+                }
+                t => t.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     macro_rules! code_str {
         ($($t:tt)*) => {
             // NOTE: parse-compile to discard formating
-            parse!(<proc_macro2::TokenStream>, { $($t)* })
-                .expect("Rust code parsing failed")
-                .to_string()
+            crate::helpers::test_helpers::to_code_str(
+                parse!(<proc_macro2::TokenStream>, { $($t)* })
+                    .expect("Rust code parsing failed")
+            )
         };
     }
 
@@ -82,7 +108,7 @@ mod test_helpers {
 
     macro_rules! lit {
         ($t:tt) => {
-            parse!(<syn::Lit>, { $t }).unwrap()
+            crate::ActionArg::Lit(parse!(<syn::Lit>, { $t }).unwrap())
         };
     }
 }
@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+use syn::{Expr, Lit};
+
+/// Value of the action call argument.
+#[derive(Debug, PartialEq)]
+pub enum ArgValue<'i> {
+    Lit(&'i Lit),
+    /// Expressions are not evaluated by the interpreter.
+    Expr(&'i Expr),
+    Byte(Option<u8>),
+    Offset(usize),
+    /// Pinned input or `None` if the pin is not set.
+    Pin(Option<&'i [u8]>),
+}
+
+pub type Action<'a, E> = Box<dyn FnMut(&[ArgValue]) -> Result<(), E> + 'a>;
+pub type PatternCondition<'a> = Box<dyn FnMut(u8) -> bool + 'a>;
+pub type Condition<'a> = Box<dyn FnMut() -> bool + 'a>;
+pub type StateGetter<'a> = Box<dyn FnMut() -> String + 'a>;
+pub type TokenSink<'a> = Box<dyn FnMut(Token) + 'a>;
+
+/// Token emitted by `@emit` (e.g. `@emit(Kind, pin)`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub kind: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Dynamic counterpart of the generated `Actions` trait.
+///
+/// Errors returned by actions that are called without an error
+/// check (`?`) are ignored, the same way as the generated code
+/// ignores return values of such actions.
+pub struct ActionTable<'a, E> {
+    pub(super) actions: HashMap<String, Action<'a, E>>,
+    pub(super) pattern_conditions: HashMap<String, PatternCondition<'a>>,
+    pub(super) conditions: HashMap<String, Condition<'a>>,
+    pub(super) state_getters: HashMap<String, StateGetter<'a>>,
+    pub(super) token_sink: Option<TokenSink<'a>>,
+}
+
+impl<'a, E> Default for ActionTable<'a, E> {
+    fn default() -> Self {
+        ActionTable {
+            actions: HashMap::default(),
+            pattern_conditions: HashMap::default(),
+            conditions: HashMap::default(),
+            state_getters: HashMap::default(),
+            token_sink: None,
+        }
+    }
+}
+
+impl<'a, E> ActionTable<'a, E> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_action(
+        &mut self,
+        name: &str,
+        action: impl FnMut(&[ArgValue]) -> Result<(), E> + 'a,
+    ) -> &mut Self {
+        self.actions.insert(name.into(), Box::new(action));
+        self
+    }
+
+    /// Adds condition that is used in patterns (e.g. `if foo => ...`).
+    pub fn add_pattern_condition(
+        &mut self,
+        name: &str,
+        condition: impl FnMut(u8) -> bool + 'a,
+    ) -> &mut Self {
+        self.pattern_conditions
+            .insert(name.into(), Box::new(condition));
+        self
+    }
+
+    /// Adds condition that is used in arm's right hand side (e.g. `_ => if foo { ... }`).
+    pub fn add_condition(&mut self, name: &str, condition: impl FnMut() -> bool + 'a) -> &mut Self {
+        self.conditions.insert(name.into(), Box::new(condition));
+        self
+    }
+
+    /// Adds getter of the target state for dynamic state transitions (`--> dyn foo`).
+    pub fn add_state_getter(
+        &mut self,
+        name: &str,
+        getter: impl FnMut() -> String + 'a,
+    ) -> &mut Self {
+        self.state_getters.insert(name.into(), Box::new(getter));
+        self
+    }
+
+    /// Sets receiver of the tokens emitted by `@emit`.
+    pub fn set_token_sink(&mut self, sink: impl FnMut(Token) + 'a) -> &mut Self {
+        self.token_sink = Some(Box::new(sink));
+        self
+    }
+}
@@ -0,0 +1,866 @@
+mod action_table;
+
+pub use self::action_table::*;
+
+use crate::compile::Consumption;
+use crate::{
+    ActionArg, ActionCall, Arm, ArmRhs, Directives, Grammar, InputStatePattern, Pattern,
+    SequenceElement, State, Utf8Match,
+};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Display, Formatter};
+use std::iter::once;
+
+#[derive(Debug, PartialEq)]
+pub enum InterpreterError {
+    UnknownState(String),
+    UndefinedAction(String),
+    UndefinedPatternCondition(String),
+    UndefinedCondition(String),
+    UndefinedStateGetter(String),
+    UnknownPin(String),
+    UndefinedTokenSink,
+}
+
+impl Display for InterpreterError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        use InterpreterError::*;
+
+        match self {
+            UnknownState(name) => write!(f, "unknown state `{}`", name),
+            UndefinedAction(name) => write!(f, "action `{}` is not defined", name),
+            UndefinedPatternCondition(name) => {
+                write!(f, "pattern condition `{}` is not defined", name)
+            }
+            UndefinedCondition(name) => write!(f, "condition `{}` is not defined", name),
+            UndefinedStateGetter(name) => write!(f, "state getter `{}` is not defined", name),
+            UnknownPin(name) => write!(f, "pin `{}` is never set", name),
+            UndefinedTokenSink => write!(f, "token sink is not defined"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParsingError<E> {
+    pub state: String,
+    pub offset: usize,
+    pub byte: Option<u8>,
+    pub error: E,
+}
+
+/// Error of the interpreter's parsing loop.
+#[derive(Debug, PartialEq)]
+pub enum RunError<E> {
+    /// Error returned by the action that is called with the error check (`?`).
+    Parsing(ParsingError<E>),
+    /// Error caused by the action table (e.g. a state getter that
+    /// returns the name of the state that doesn't exist).
+    Interpreter(ParsingError<InterpreterError>),
+}
+
+enum PatternMatch {
+    Matched,
+    Mismatched,
+    Blocked,
+}
+
+enum Flow {
+    Proceed,
+    Restart,
+    Return(usize),
+}
+
+// NOTE: presence of all the action table entries required
+// by the grammar is checked on the interpreter construction.
+fn validated<'m, T>(entries: &'m mut HashMap<String, T>, name: &str) -> &'m mut T {
+    entries
+        .get_mut(name)
+        .expect("action table entries should be validated")
+}
+
+fn validate<E>(grammar: &Grammar, actions: &ActionTable<E>) -> Result<(), InterpreterError> {
+    use InterpreterError::*;
+
+    let has_state = |name: &str| grammar.states.iter().any(|s| s.name == name);
+
+    let check = |defined: bool, err: fn(String) -> InterpreterError, name: &str| {
+        if defined {
+            Ok(())
+        } else {
+            Err(err(name.into()))
+        }
+    };
+
+    let arms = || grammar.states.iter().flat_map(|s| s.arms.iter());
+
+    let is_pin_set = |name: &str| {
+        arms()
+            .flat_map(|a| a.rhs.directives())
+            .flat_map(|d| d.action_calls.iter())
+            .any(|c| matches!(c, ActionCall::Pin(n) if n == name))
+    };
+
+    for arm in arms() {
+        if let Pattern::Condition(ref c) = arm.pattern {
+            check(
+                actions.pattern_conditions.contains_key(c),
+                UndefinedPatternCondition,
+                c,
+            )?;
+        }
+
+        if let ArmRhs::Condition {
+            if_branch,
+            else_if_branches,
+            ..
+        } = &arm.rhs
+        {
+            for branch in once(if_branch).chain(else_if_branches) {
+                let c = &branch.condition;
+
+                check(actions.conditions.contains_key(c), UndefinedCondition, c)?;
+            }
+        }
+
+        for directives in arm.rhs.directives() {
+            for call in &directives.action_calls {
+                match call {
+                    ActionCall::UserDefined { name, args, .. } => {
+                        check(actions.actions.contains_key(name), UndefinedAction, name)?;
+
+                        for arg in args {
+                            if let ActionArg::Pin(pin) = arg {
+                                check(is_pin_set(pin), UnknownPin, pin)?;
+                            }
+                        }
+                    }
+                    ActionCall::Emit { pin, .. } => {
+                        if actions.token_sink.is_none() {
+                            return Err(UndefinedTokenSink);
+                        }
+
+                        if let Some(pin) = pin {
+                            check(is_pin_set(pin), UnknownPin, pin)?;
+                        }
+                    }
+                    ActionCall::Pin(_) => (),
+                }
+            }
+
+            if let Some(ref t) = directives.state_transition {
+                if t.dynamic {
+                    check(
+                        actions.state_getters.contains_key(&t.target),
+                        UndefinedStateGetter,
+                        &t.target,
+                    )?;
+                } else {
+                    check(has_state(&t.target), UnknownState, &t.target)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Executes `Grammar` without code generation. The interpreter has the same
+/// streaming interface and semantics as the generated parser.
+pub struct Interpreter<'g, 'a, E> {
+    grammar: &'g Grammar,
+    actions: ActionTable<'a, E>,
+    state: usize,
+    state_enter: bool,
+    is_last_input: bool,
+    pos: usize,
+    /// Number of bytes matched by the last matched UTF-8 pattern.
+    code_point_len: usize,
+    chunk_offset: usize,
+    pins: BTreeMap<String, usize>,
+}
+
+impl<'g, 'a, E> Interpreter<'g, 'a, E> {
+    pub fn new(
+        grammar: &'g Grammar,
+        actions: ActionTable<'a, E>,
+    ) -> Result<Self, InterpreterError> {
+        validate(grammar, &actions)?;
+
+        Ok(Interpreter {
+            grammar,
+            actions,
+            state: 0,
+            state_enter: true,
+            is_last_input: false,
+            pos: 0,
+            code_point_len: 0,
+            chunk_offset: 0,
+            pins: BTreeMap::default(),
+        })
+    }
+
+    pub fn state(&self) -> &str {
+        &self.grammar.states[self.state].name
+    }
+
+    pub fn pins(&self) -> &BTreeMap<String, usize> {
+        &self.pins
+    }
+
+    /// Parses the chunk of input and returns the number of bytes at the
+    /// end of the chunk that were not consumed or are pinned. These bytes
+    /// should be prepended to the next chunk.
+    pub fn run_parsing_loop(&mut self, input: &[u8], is_last: bool) -> Result<usize, RunError<E>> {
+        self.is_last_input = is_last;
+
+        let blocked_byte_count = self.run(input)?;
+        let consumed_byte_count = input.len() - blocked_byte_count;
+
+        // NOTE: input from the earliest pin which input is passed to the
+        // actions is blocked too, so the pinned slices span the chunks.
+        let released_byte_count = self
+            .grammar
+            .sliced_pins()
+            .into_iter()
+            .filter_map(|name| self.pins.get(name))
+            .fold(consumed_byte_count, |count, &pin| {
+                count.min(pin - self.chunk_offset)
+            });
+
+        self.chunk_offset += released_byte_count;
+        self.pos = consumed_byte_count - released_byte_count;
+
+        Ok(input.len() - released_byte_count)
+    }
+
+    fn run(&mut self, input: &[u8]) -> Result<usize, RunError<E>> {
+        let grammar = self.grammar;
+
+        loop {
+            let ch = input.get(self.pos).cloned();
+            let state = &grammar.states[self.state];
+
+            if self.state_enter {
+                let state_enter_arm = state.arms.iter().find(|a| a.pattern == Pattern::StateEnter);
+
+                if let Some(arm) = state_enter_arm {
+                    self.state_enter = false;
+
+                    match self.exec_arm(arm, state, input, ch)? {
+                        Flow::Proceed => (),
+                        Flow::Restart => continue,
+                        Flow::Return(count) => return Ok(count),
+                    }
+                }
+            }
+
+            let mut flow = None;
+
+            for arm in state
+                .arms
+                .iter()
+                .filter(|a| a.pattern != Pattern::StateEnter)
+            {
+                match self.match_pattern(&arm.pattern, input, ch) {
+                    PatternMatch::Matched => {
+                        flow = Some(self.exec_arm(arm, state, input, ch)?);
+                        break;
+                    }
+                    PatternMatch::Mismatched => (),
+                    PatternMatch::Blocked => return Ok(input.len() - self.pos),
+                }
+            }
+
+            let flow = match flow {
+                Some(flow) => flow,
+                None => {
+                    let consumption = if ch.is_some() {
+                        Consumption::Byte
+                    } else {
+                        Consumption::InputState
+                    };
+
+                    self.exec_directives(&Directives::default(), state, consumption, input, ch)?
+                }
+            };
+
+            if let Flow::Return(count) = flow {
+                return Ok(count);
+            }
+        }
+    }
+
+    fn match_pattern(&mut self, pattern: &Pattern, input: &[u8], ch: Option<u8>) -> PatternMatch {
+        let matched = match (pattern, ch) {
+            (Pattern::StateEnter, _) => false,
+            (&Pattern::Byte { byte, ignore_case }, Some(ch)) => {
+                SequenceElement::Byte(byte).matches(ch, ignore_case)
+            }
+            (Pattern::Range(r), Some(ch)) => r.matches(ch),
+            (Pattern::Mask(m), Some(ch)) => m.matches(ch),
+            (Pattern::Class(c), Some(ch)) => c.matches(ch),
+            (Pattern::InputState(InputStatePattern::Eoc), None) => !self.is_last_input,
+            (Pattern::InputState(InputStatePattern::Eof), None) => true,
+            (Pattern::Condition(c), Some(ch)) => {
+                validated(&mut self.actions.pattern_conditions, c)(ch)
+            }
+            (Pattern::Any, Some(_)) => true,
+            (Pattern::Sequence(s), Some(_)) => {
+                let available = &input[self.pos..];
+                let len = available.len().min(s.len());
+                let matches = s.matches(&available[..len]);
+
+                if matches && len < s.len() && !self.is_last_input {
+                    return PatternMatch::Blocked;
+                }
+
+                matches && len == s.len()
+            }
+            (Pattern::Unicode(_), Some(_)) | (Pattern::InvalidUtf8, Some(_)) => {
+                let matcher = pattern
+                    .utf8_matcher()
+                    .expect("UTF-8 pattern should have matcher");
+
+                match matcher.matches(&input[self.pos..], self.is_last_input) {
+                    Utf8Match::Matched(len) => {
+                        self.code_point_len = len;
+                        true
+                    }
+                    Utf8Match::Mismatched => false,
+                    Utf8Match::Blocked => return PatternMatch::Blocked,
+                }
+            }
+            _ => false,
+        };
+
+        if matched {
+            PatternMatch::Matched
+        } else {
+            PatternMatch::Mismatched
+        }
+    }
+
+    fn exec_arm(
+        &mut self,
+        arm: &Arm,
+        state: &State,
+        input: &[u8],
+        ch: Option<u8>,
+    ) -> Result<Flow, RunError<E>> {
+        let consumption = arm.consumption();
+
+        let directives = match arm.rhs {
+            ArmRhs::Directives(ref directives) => directives,
+            ArmRhs::Condition {
+                ref if_branch,
+                ref else_if_branches,
+                ref else_branch,
+            } => once(if_branch)
+                .chain(else_if_branches)
+                .find(|b| validated(&mut self.actions.conditions, &b.condition)())
+                .map_or(else_branch, |b| &b.directives),
+        };
+
+        self.exec_directives(directives, state, consumption, input, ch)
+    }
+
+    fn arg_value<'i>(&self, arg: &'i ActionArg, input: &'i [u8], ch: Option<u8>) -> ArgValue<'i> {
+        match arg {
+            ActionArg::Lit(lit) => ArgValue::Lit(lit),
+            ActionArg::Expr { expr, .. } => ArgValue::Expr(expr),
+            ActionArg::Byte => ArgValue::Byte(ch),
+            ActionArg::Offset => ArgValue::Offset(self.chunk_offset + self.pos),
+            ActionArg::Pin(name) => ArgValue::Pin(
+                self.pins
+                    .get(name)
+                    .map(|&p| &input[p - self.chunk_offset..self.pos]),
+            ),
+        }
+    }
+
+    fn consumed_len(&self, directives: &Directives, consumption: Consumption) -> usize {
+        let epsilon_move = directives
+            .state_transition
+            .as_ref()
+            .is_some_and(|t| t.epsilon_move);
+
+        match consumption {
+            _ if epsilon_move => 0,
+            Consumption::Byte => 1,
+            Consumption::Bytes(count) => count,
+            Consumption::CodePoint => self.code_point_len,
+            Consumption::Nothing | Consumption::InputState => 0,
+        }
+    }
+
+    fn exec_directives(
+        &mut self,
+        directives: &Directives,
+        state: &State,
+        consumption: Consumption,
+        input: &[u8],
+        ch: Option<u8>,
+    ) -> Result<Flow, RunError<E>> {
+        for call in &directives.action_calls {
+            match call {
+                ActionCall::UserDefined {
+                    name,
+                    args,
+                    with_error_check,
+                } => {
+                    let args = args
+                        .iter()
+                        .map(|a| self.arg_value(a, input, ch))
+                        .collect::<Vec<_>>();
+
+                    let result = validated(&mut self.actions.actions, name)(&args);
+
+                    if let (Err(error), true) = (result, with_error_check) {
+                        return Err(RunError::Parsing(ParsingError {
+                            state: state.name.clone(),
+                            offset: self.chunk_offset + self.pos,
+                            byte: ch,
+                            error,
+                        }));
+                    }
+                }
+                ActionCall::Pin(name) => {
+                    self.pins.insert(name.clone(), self.chunk_offset + self.pos);
+                }
+                ActionCall::Emit { kind, pin } => {
+                    let offset = self.chunk_offset + self.pos;
+                    let start = pin.as_ref().and_then(|p| self.pins.get(p));
+
+                    let token = Token {
+                        kind: kind.clone(),
+                        start: start.cloned().unwrap_or(offset),
+                        end: offset + self.consumed_len(directives, consumption),
+                    };
+
+                    let sink = self.actions.token_sink.as_mut();
+
+                    sink.expect("token sink should be validated")(token);
+                }
+            }
+        }
+
+        let mut epsilon_move = false;
+
+        if let Some(ref t) = directives.state_transition {
+            let target = if t.dynamic {
+                validated(&mut self.actions.state_getters, &t.target)()
+            } else {
+                t.target.clone()
+            };
+
+            self.state = match self.grammar.states.iter().position(|s| s.name == target) {
+                Some(idx) => idx,
+                None => {
+                    return Err(RunError::Interpreter(ParsingError {
+                        state: state.name.clone(),
+                        offset: self.chunk_offset + self.pos,
+                        byte: ch,
+                        error: InterpreterError::UnknownState(target),
+                    }));
+                }
+            };
+
+            self.state_enter = true;
+            epsilon_move = t.epsilon_move;
+        }
+
+        Ok(match consumption {
+            Consumption::Nothing if directives.state_transition.is_some() => Flow::Restart,
+            Consumption::Nothing => Flow::Proceed,
+            _ if epsilon_move => Flow::Proceed,
+            Consumption::Byte => {
+                self.pos += 1;
+                Flow::Proceed
+            }
+            Consumption::Bytes(count) => {
+                self.pos += count;
+                Flow::Proceed
+            }
+            Consumption::CodePoint => {
+                self.pos += self.code_point_len;
+                Flow::Proceed
+            }
+            Consumption::InputState => Flow::Return(0),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    curry_parse_macros!($Grammar);
+
+    fn run<E>(interpreter: &mut Interpreter<E>, chunks: &[&[u8]]) -> Result<(), RunError<E>> {
+        let mut input = vec![];
+
+        for (i, chunk) in chunks.iter().enumerate() {
+            input.extend_from_slice(chunk);
+
+            let blocked_byte_count = interpreter.run_parsing_loop(&input, i == chunks.len() - 1)?;
+
+            input.drain(..input.len() - blocked_byte_count);
+        }
+
+        Ok(())
+    }
+
+    macro_rules! recorder {
+        ($log:ident, $table:ident, [$($name:expr),*]) => {
+            $(
+                $table.add_action($name, |args| {
+                    let args = args
+                        .iter()
+                        .map(|a| match a {
+                            ArgValue::Lit(l) => {
+                                crate::helpers::test_helpers::to_code_str(quote::quote! { #l })
+                            }
+                            ArgValue::Expr(e) => {
+                                crate::helpers::test_helpers::to_code_str(quote::quote! { #e })
+                            }
+                            a => format!("{:?}", a),
+                        })
+                        .collect::<Vec<_>>();
+
+                    $log.borrow_mut().push(format!("{}({})", $name, args.join(", ")));
+                    Ok(())
+                });
+            )*
+        };
+    }
+
+    #[test]
+    fn interpret() {
+        let grammar = parse_ok! {
+            TestGrammar = {
+                data_state:
+                    --> => enter.
+                    '<' => @pin(tag_start), --> tag_open_state.
+                    "&amp;"|i => amp.
+                    eoc => chunk_end.
+                    eof => eof.
+                    _ => text.
+
+                tag_open_state:
+                    alpha => tag_name("foo", 1), --> data_state.
+                    _ => move --> data_state.
+            }
+        };
+
+        let log = RefCell::new(vec![]);
+        let mut actions = ActionTable::<()>::new();
+
+        recorder!(
+            log,
+            actions,
+            ["enter", "amp", "chunk_end", "eof", "text", "tag_name"]
+        );
+
+        let mut interpreter = Interpreter::new(&grammar, actions).unwrap();
+
+        run(&mut interpreter, &[b"a", b"&A", b"mP;<", b"b<1"]).unwrap();
+
+        assert_eq!(
+            *log.borrow(),
+            vec![
+                "enter()",
+                "text()",
+                "chunk_end()",
+                "amp()",
+                "tag_name(\"foo\", 1)",
+                "enter()",
+                "enter()",
+                "text()",
+                "eof()"
+            ]
+        );
+
+        assert_eq!(interpreter.state(), "data_state");
+        assert_eq!(interpreter.pins()["tag_start"], 8);
+    }
+
+    #[test]
+    fn conditions_and_dynamic_transitions() {
+        let grammar = parse_ok! {
+            TestGrammar = {
+                foo_state:
+                    if is_x => --> dyn next_state.
+                    _ => if is_first {
+                        first.
+                    } else {
+                        other.
+                    }
+
+                bar_state:
+                    _ => bar.
+            }
+        };
+
+        let log = RefCell::new(vec![]);
+        let first = RefCell::new(true);
+        let mut actions = ActionTable::<()>::new();
+
+        recorder!(log, actions, ["bar", "other"]);
+
+        actions
+            .add_action("first", |_| {
+                *first.borrow_mut() = false;
+                log.borrow_mut().push("first()".into());
+                Ok(())
+            })
+            .add_condition("is_first", || *first.borrow())
+            .add_pattern_condition("is_x", |b| b == b'x')
+            .add_state_getter("next_state", || "bar_state".into());
+
+        let mut interpreter = Interpreter::new(&grammar, actions).unwrap();
+
+        run(&mut interpreter, &[b"aax", b"a"]).unwrap();
+
+        assert_eq!(*log.borrow(), vec!["first()", "other()", "bar()"]);
+        assert_eq!(interpreter.state(), "bar_state");
+    }
+
+    #[test]
+    fn action_error() {
+        let grammar = parse_ok! {
+            TestGrammar = {
+                foo_state:
+                    'x' => --> bar_state.
+                    _ => .
+
+                bar_state:
+                    'y' => bar?.
+                    _ => baz.
+            }
+        };
+
+        let mut actions = ActionTable::new();
+
+        actions
+            .add_action("bar", |_| Err("bar failed"))
+            .add_action("baz", |_| Err("baz failed"));
+
+        let mut interpreter = Interpreter::new(&grammar, actions).unwrap();
+
+        assert_eq!(
+            run(&mut interpreter, &[b"ax", b"zzy"]),
+            Err(RunError::Parsing(ParsingError {
+                state: "bar_state".into(),
+                offset: 4,
+                byte: Some(b'y'),
+                error: "bar failed"
+            }))
+        );
+    }
+
+    #[test]
+    fn unknown_dynamic_state_error() {
+        let grammar = parse_ok! {
+            TestGrammar = {
+                foo_state:
+                    'x' => --> dyn next_state.
+                    _ => .
+            }
+        };
+
+        let mut actions = ActionTable::<()>::new();
+
+        actions.add_state_getter("next_state", || "bar_state".into());
+
+        let mut interpreter = Interpreter::new(&grammar, actions).unwrap();
+
+        assert_eq!(
+            run(&mut interpreter, &[b"a", b"x"]),
+            Err(RunError::Interpreter(ParsingError {
+                state: "foo_state".into(),
+                offset: 1,
+                byte: Some(b'x'),
+                error: InterpreterError::UnknownState("bar_state".into())
+            }))
+        );
+    }
+
+    #[test]
+    fn sequence_at_the_end_of_input() {
+        let grammar = parse_ok! {
+            TestGrammar = {
+                foo_state:
+                    "foo" => foo.
+                    _ => other.
+            }
+        };
+
+        let log = RefCell::new(vec![]);
+        let mut actions = ActionTable::<()>::new();
+
+        recorder!(log, actions, ["foo", "other"]);
+
+        let mut interpreter = Interpreter::new(&grammar, actions).unwrap();
+
+        run(&mut interpreter, &[b"fo", b"ofo"]).unwrap();
+
+        assert_eq!(*log.borrow(), vec!["foo()", "other()", "other()"]);
+    }
+
+    #[test]
+    fn lookahead_sequence() {
+        let grammar = parse_ok! {
+            TestGrammar = {
+                data_state:
+                    peek "-->" => --> comment_end_state.
+                    _ => data.
+
+                comment_end_state:
+                    "-->" => end, --> data_state.
+            }
+        };
+
+        let log = RefCell::new(vec![]);
+        let mut actions = ActionTable::<()>::new();
+
+        recorder!(log, actions, ["data", "end"]);
+
+        let mut interpreter = Interpreter::new(&grammar, actions).unwrap();
+
+        run(&mut interpreter, &[b"a-", b"-", b">-"]).unwrap();
+
+        assert_eq!(*log.borrow(), vec!["data()", "end()", "data()"]);
+    }
+
+    #[test]
+    fn built_in_args() {
+        let grammar = parse_ok! {
+            TestGrammar = {
+                data_state:
+                    '<' => @pin(tag_start), --> tag_state.
+                    _ => text(@byte, @offset).
+
+                tag_state:
+                    '>' => tag(@pin(tag_start), @offset), --> data_state.
+                    _ => .
+            }
+        };
+
+        let log = RefCell::new(vec![]);
+        let mut actions = ActionTable::<()>::new();
+
+        recorder!(log, actions, ["text", "tag"]);
+
+        let mut interpreter = Interpreter::new(&grammar, actions).unwrap();
+
+        run(&mut interpreter, &[b"a<b", b">", b"c<d>"]).unwrap();
+
+        assert_eq!(
+            *log.borrow(),
+            vec![
+                "text(Byte(Some(97)), Offset(0))",
+                "tag(Pin(Some([60, 98])), Offset(3))",
+                "text(Byte(Some(99)), Offset(4))",
+                "tag(Pin(Some([60, 100])), Offset(7))"
+            ]
+        );
+    }
+
+    #[test]
+    fn emit_tokens() {
+        let grammar = parse_ok! {
+            TestGrammar = {
+                data_state:
+                    "<!--" => @pin(start), --> comment_state.
+                    _ => @emit(Char).
+
+                comment_state:
+                    "-->" => @emit(Comment, start), --> data_state.
+                    '>' => @emit(Gt), move --> data_state.
+                    _ => .
+            }
+        };
+
+        let tokens = RefCell::new(vec![]);
+        let mut actions = ActionTable::<()>::new();
+
+        actions.set_token_sink(|t| tokens.borrow_mut().push(t));
+
+        let mut interpreter = Interpreter::new(&grammar, actions).unwrap();
+
+        run(&mut interpreter, &[b"a<!-", b"-x-->b<!-->"]).unwrap();
+
+        let token = |kind: &str, start, end| Token {
+            kind: kind.into(),
+            start,
+            end,
+        };
+
+        assert_eq!(
+            *tokens.borrow(),
+            vec![
+                token("Char", 0, 1),
+                token("Comment", 1, 9),
+                token("Char", 9, 10),
+                token("Gt", 14, 14),
+                token("Char", 14, 15)
+            ]
+        );
+    }
+
+    #[test]
+    fn undefined_items_error() {
+        let new_interpreter = |grammar: &Grammar| {
+            Interpreter::new(grammar, ActionTable::<()>::new())
+                .err()
+                .unwrap()
+        };
+
+        assert_eq!(
+            new_interpreter(&parse_ok! { TestGrammar = { foo_state: _ => --> bar_state. } }),
+            InterpreterError::UnknownState("bar_state".into())
+        );
+
+        assert_eq!(
+            new_interpreter(&parse_ok! { TestGrammar = { foo_state: _ => foo. } }),
+            InterpreterError::UndefinedAction("foo".into())
+        );
+
+        assert_eq!(
+            new_interpreter(&parse_ok! { TestGrammar = { foo_state: if foo => . } }),
+            InterpreterError::UndefinedPatternCondition("foo".into())
+        );
+
+        assert_eq!(
+            new_interpreter(&parse_ok! {
+                TestGrammar = { foo_state: _ => if foo { . } else { . } }
+            }),
+            InterpreterError::UndefinedCondition("foo".into())
+        );
+
+        assert_eq!(
+            new_interpreter(&parse_ok! { TestGrammar = { foo_state: _ => --> dyn foo. } }),
+            InterpreterError::UndefinedStateGetter("foo".into())
+        );
+
+        let mut actions = ActionTable::<()>::new();
+
+        actions.add_action("foo", |_| Ok(()));
+
+        assert_eq!(
+            Interpreter::new(
+                &parse_ok! { TestGrammar = { foo_state: _ => foo(@pin(bar)). } },
+                actions
+            )
+            .err()
+            .unwrap(),
+            InterpreterError::UnknownPin("bar".into())
+        );
+
+        assert_eq!(
+            new_interpreter(&parse_ok! { TestGrammar = { foo_state: _ => @emit(Foo). } }),
+            InterpreterError::UndefinedTokenSink
+        );
+    }
+}
@@ -11,6 +11,10 @@ mod directives;
 mod grammar;
 mod state;
 
+pub mod graphviz;
+pub mod interpreter;
+pub mod testing;
+
 pub use self::arm::*;
 pub use self::compile::Compile;
 pub use self::directives::*;
@@ -19,13 +23,10 @@ pub use self::state::*;
 
 // TODO
 // v0.1.0
-// 4. Initial state
-// 5. Generate SM streaming
 // 6. module system
 // 7. cool_thing POC
 
 // v0.2.0
-// 1. Range pattern
 // 2. Pattern negation
 // 3. Pattern Or(|)
 // 4. Errors functional tests
@@ -37,13 +38,6 @@ pub use self::state::*;
 //    f. Reconsume in sequence (?)
 // 5. JSON POC
 
-// v0.3.0
-// 1. Skip optimisation
-
-// v0.4.0
-// 1. GrarphViz
-// 2. TracingGraphViz
-
 // v1.0.0
 // 1. Other optimisations
 
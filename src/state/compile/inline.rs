@@ -0,0 +1,163 @@
+use super::*;
+use crate::ArmRhs;
+
+impl State {
+    // NOTE: only the first state enter arm is executed, since it resets the state enter flag.
+    fn switches_on_enter(&self) -> bool {
+        let first_state_enter_arm = self.arms.iter().find(|a| a.pattern == Pattern::StateEnter);
+
+        match first_state_enter_arm.map(|a| &a.rhs) {
+            Some(ArmRhs::Directives(d)) => d.state_transition.is_some(),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the state only switches to another state: either its first
+    /// state enter arm has a state transition, or it has only the state enter arms
+    /// without transitions and the `_` arm with a state transition.
+    pub(crate) fn is_trivial(&self) -> bool {
+        let (state_enter_arms, arms): (Vec<_>, Vec<_>) = self
+            .arms
+            .iter()
+            .partition(|a| a.pattern == Pattern::StateEnter);
+
+        if self.switches_on_enter() {
+            return true;
+        }
+
+        let has_enter_transitions = state_enter_arms
+            .iter()
+            .flat_map(|a| a.rhs.directives())
+            .any(|d| d.state_transition.is_some());
+
+        match arms[..] {
+            [Arm {
+                pattern: Pattern::Any,
+                rhs: ArmRhs::Directives(ref d),
+            }] => !has_enter_transitions && d.state_transition.is_some(),
+            _ => false,
+        }
+    }
+
+    // NOTE: the inlined code is executed right after the transition to the state and
+    // does the same as the next iteration of the parsing loop would do. If there is no
+    // input available, the `_` arm is not executed and the parser stays in the state,
+    // so the rest of the state is handled by the parsing loop once more input arrives.
+    pub(crate) fn compile_inlined(&self, idx: usize, tracer: bool) -> TokenStream2 {
+        let ch = gen_parser_intrinsics!(current_byte);
+        let input = gen_parser_intrinsics!(input);
+        let pos = gen_parser_intrinsics!(pos);
+
+        // NOTE: inlined code doesn't inline the states it switches to, so
+        // the cycles of the trivial states don't inline each other forever.
+        let prelude = self.compile_prelude(idx, tracer, &[]);
+
+        let any_arm = match self.arms.iter().position(|a| a.pattern == Pattern::Any) {
+            Some(arm) if !self.switches_on_enter() => {
+                let edge = EdgeId {
+                    state: idx,
+                    arm,
+                    branch: 0,
+                };
+
+                let body = self.arms[arm].compile_body(&self.name, edge, tracer, &[]);
+
+                quote! {
+                    if #ch.is_some() {
+                        #body
+                    }
+                }
+            }
+            _ => quote! {},
+        };
+
+        // NOTE: the current byte is unused if the state only has the state enter arms.
+        quote! {{
+            #[allow(unused_variables)]
+            let #ch = #input.get(#pos).cloned();
+
+            #prelude
+            #any_arm
+        }}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test_helpers::to_code_str;
+
+    curry_parse_macros!($State);
+
+    #[test]
+    fn is_trivial() {
+        assert!(parse_ok! {
+            foo_state:
+                _ => move --> bar_state.
+        }
+        .is_trivial());
+
+        assert!(parse_ok! {
+            foo_state:
+                --> => foo, @pin(bar).
+                _ => baz, --> dyn next_state.
+        }
+        .is_trivial());
+
+        assert!(parse_ok! {
+            foo_state:
+                --> => foo, --> bar_state.
+                'a' => bar.
+        }
+        .is_trivial());
+
+        assert!(!parse_ok! {
+            foo_state:
+                --> => if cond { --> bar_state. } else { foo. }
+                _ => move --> bar_state.
+        }
+        .is_trivial());
+
+        assert!(!parse_ok! {
+            foo_state:
+                eof => foo.
+                _ => move --> bar_state.
+        }
+        .is_trivial());
+
+        assert!(!parse_ok! {
+            foo_state:
+                _ => foo.
+        }
+        .is_trivial());
+    }
+
+    #[test]
+    fn compile_inlined() {
+        assert_eq!(
+            to_code_str(
+                parse_ok! {
+                    foo_state:
+                        --> => @pin(foo).
+                        _ => bar, move --> baz_state.
+                }
+                .compile_inlined(1, false)
+            ),
+            code_str! {{
+                #[allow(unused_variables)]
+                let ch = input.get(self.__pos).cloned();
+
+                if self.__state_enter {
+                    self.__state_enter = false;
+                    self.__pins.foo = Some((self.__chunk_offset + self.__pos));
+                }
+
+                if ch.is_some() {
+                    self.bar();
+                    self.__state = State::baz_state;
+                    self.__state_enter = true;
+                }
+            }}
+        );
+    }
+}
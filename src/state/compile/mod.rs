@@ -0,0 +1,286 @@
+mod inline;
+mod skip;
+mod table;
+mod trie;
+
+use super::*;
+use crate::compile::{ArmCtx, Consumption};
+use crate::graphviz::EdgeId;
+use crate::{Arm, Directives, Pattern};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::Ident;
+
+// NOTE: the variable is assigned in the guards of the match arms, so it's
+// reassigned only if there is more than one arm that assigns it.
+fn compile_guard_var_decl(var: TokenStream2, ty: TokenStream2, guard_count: usize) -> TokenStream2 {
+    match guard_count {
+        0 => quote! {},
+        1 => quote! { let #var: #ty; },
+        _ => quote! { let mut #var: #ty; },
+    }
+}
+
+impl State {
+    // NOTE: bytes and input states that are not handled by the
+    // state's arms are silently consumed.
+    fn compile_fallback(&self, consumption: Consumption) -> TokenStream2 {
+        Directives::default().compile(&ArmCtx {
+            state_name: &self.name,
+            consumption,
+            edge: None,
+            tracer: false,
+            states: &[],
+        })
+    }
+
+    fn compile_fallback_arms(&self) -> TokenStream2 {
+        let byte_fallback = self.compile_fallback(Consumption::Byte);
+        let input_state_fallback = self.compile_fallback(Consumption::InputState);
+
+        quote! {
+            Some(_) => { #byte_fallback }
+            None => { #input_state_fallback }
+        }
+    }
+
+    // NOTE: states without state enter arms don't reset the state enter
+    // flag, so the hook resets it to be called once per state enter.
+    fn compile_tracer_hook(&self, has_state_enter_arms: bool) -> TokenStream2 {
+        let state_enter_flag = gen_parser_intrinsics!(state_enter_flag);
+        let actions = gen_parser_intrinsics!(actions);
+        let offset = gen_parser_intrinsics!(offset);
+        let name = Ident::new(&self.name, Span::call_site());
+
+        let flag_reset = if has_state_enter_arms {
+            quote! {}
+        } else {
+            quote! { #state_enter_flag = false; }
+        };
+
+        quote! {
+            if #state_enter_flag {
+                #flag_reset
+                #actions.on_state_enter(State::#name, #offset);
+            }
+        }
+    }
+
+    fn compile_arm(&self, idx: usize, arm: usize, tracer: bool, states: &[State]) -> TokenStream2 {
+        let edge = EdgeId {
+            state: idx,
+            arm,
+            branch: 0,
+        };
+
+        self.arms[arm].compile(&self.name, edge, tracer, states)
+    }
+
+    // NOTE: code that runs before the current byte is dispatched
+    // to the arms: the tracer hook and the state enter arms.
+    fn compile_prelude(&self, idx: usize, tracer: bool, states: &[State]) -> TokenStream2 {
+        let state_enter_arms = (0..self.arms.len())
+            .filter(|&i| self.arms[i].pattern == Pattern::StateEnter)
+            .map(|i| self.compile_arm(idx, i, tracer, states))
+            .collect::<Vec<_>>();
+
+        let tracer_hook = if tracer {
+            self.compile_tracer_hook(!state_enter_arms.is_empty())
+        } else {
+            quote! {}
+        };
+
+        quote! {
+            #tracer_hook
+            #(#state_enter_arms)*
+        }
+    }
+
+    pub(crate) fn compile(&self, idx: usize, tracer: bool, states: &[State]) -> TokenStream2 {
+        let name = Ident::new(&self.name, Span::call_site());
+        let ch = gen_parser_intrinsics!(current_byte);
+
+        let arms = self
+            .arms
+            .iter()
+            .enumerate()
+            .filter(|(_, a)| a.pattern != Pattern::StateEnter)
+            .collect::<Vec<_>>();
+
+        let prelude = self.compile_prelude(idx, tracer, states);
+        let skip = self.compile_skip(tracer);
+
+        let dispatch = if self.has_table_dispatch() {
+            self.compile_table_dispatch(idx, tracer, states)
+        } else {
+            let groups = State::group_sequence_arms(&arms);
+            let fallback_arms = self.compile_fallback_arms();
+
+            let sequence_arm_decl = compile_guard_var_decl(
+                gen_parser_intrinsics!(sequence_arm),
+                quote! { Option<usize> },
+                groups.iter().filter(|g| g.len() > 1).count(),
+            );
+
+            let code_point_len_decl = compile_guard_var_decl(
+                gen_parser_intrinsics!(code_point_len),
+                quote! { usize },
+                arms.iter()
+                    .filter(|(_, a)| a.pattern.utf8_matcher().is_some())
+                    .count(),
+            );
+
+            let arms = groups.iter().map(|g| match g[..] {
+                [(arm, _)] => self.compile_arm(idx, arm, tracer, states),
+                _ => self.compile_sequence_group(g, idx, tracer, states),
+            });
+
+            quote! {
+                #sequence_arm_decl
+                #code_point_len_decl
+
+                match #ch {
+                    #(#arms)*
+                    #fallback_arms
+                }
+            }
+        };
+
+        quote! {
+            State::#name => {
+                #prelude
+                #skip
+                #dispatch
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test_helpers::to_code_str;
+
+    curry_parse_macros!($State);
+
+    #[test]
+    fn compile() {
+        assert_eq!(
+            to_code_str(
+                parse_ok! {
+                    foo_state:
+                        --> => bar.
+                        'a' => baz, --> qux_state.
+                        eof => quz.
+                }
+                .compile(0, false, &[])
+            ),
+            code_str! {
+                State::foo_state => {
+                    if self.__state_enter {
+                        self.__state_enter = false;
+                        self.bar();
+                    }
+
+                    let skipped = self.__skip_until(input, &[97u8]);
+
+                    if skipped > 0 {
+                        self.__pos += skipped;
+                        continue;
+                    }
+
+                    match ch {
+                        Some(97u8) => {
+                            self.baz();
+                            self.__state = State::qux_state;
+                            self.__state_enter = true;
+                            self.__pos += 1;
+                        }
+                        None => {
+                            self.quz();
+                            return Ok(0);
+                        }
+                        Some(_) => {
+                            self.__pos += 1;
+                        }
+                        None => {
+                            return Ok(0);
+                        }
+                    }
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn compile_with_tracer_hooks() {
+        assert_eq!(
+            to_code_str(
+                parse_ok! {
+                    foo_state:
+                        "ab"|i => foo(1), @pin(bar).
+                }
+                .compile(0, true, &[])
+            ),
+            code_str! {
+                State::foo_state => {
+                    if self.__state_enter {
+                        self.__state_enter = false;
+                        self.actions.on_state_enter(State::foo_state, (self.__chunk_offset + self.__pos));
+                    }
+
+                    let skipped = self.__skip_until(input, &[65u8, 97u8]);
+
+                    if skipped > 0 {
+                        self.__pos += skipped;
+                        continue;
+                    }
+
+                    match ch {
+                        Some(_) if match self.__match_sequence(input, b"ab", true) {
+                            SequenceMatch::Full => true,
+                            SequenceMatch::Partial => return Ok(input.len() - self.__pos),
+                            SequenceMatch::Mismatch => false,
+                        } => {
+                            self.actions.on_arm_match(
+                                State::foo_state,
+                                "\"ab\"|i",
+                                (self.__chunk_offset + self.__pos)
+                            );
+
+                            self.__record_step(0usize, 0usize);
+                            self.actions.on_action_call("foo(1)", (self.__chunk_offset + self.__pos));
+                            self.__record_action_call("foo(1)");
+                            self.foo(1);
+                            self.actions.on_action_call("@pin(bar)", (self.__chunk_offset + self.__pos));
+                            self.__record_action_call("@pin(bar)");
+                            self.__pins.bar = Some((self.__chunk_offset + self.__pos));
+                            self.__pos += 2usize;
+                        }
+                        Some(_) => {
+                            self.__pos += 1;
+                        }
+                        None => {
+                            return Ok(0);
+                        }
+                    }
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn compile_guard_var_decl() {
+        let decl = |guard_count| {
+            to_code_str(super::compile_guard_var_decl(
+                quote! { sequence_arm },
+                quote! { Option<usize> },
+                guard_count,
+            ))
+        };
+
+        assert_eq!(decl(0), "");
+        assert_eq!(decl(1), code_str! { let sequence_arm: Option<usize>; });
+        assert_eq!(decl(2), code_str! { let mut sequence_arm: Option<usize>; });
+    }
+}
@@ -0,0 +1,204 @@
+use super::*;
+use crate::{ArmRhs, Pattern, SequenceElement};
+use syn::{IntSuffix, LitInt};
+
+// NOTE: up to this number of delimiters bytes are compared with
+// the delimiters directly, for more delimiters lookup table is used.
+const MAX_SEARCH_DELIMITERS: usize = 3;
+
+impl Arm {
+    // NOTE: conservative approximation, sequences and conditions
+    // are considered matching if they can potentially match.
+    fn may_match(&self, b: u8) -> bool {
+        match self.pattern {
+            Pattern::StateEnter | Pattern::InputState(_) => false,
+            Pattern::Byte { byte, ignore_case } => {
+                SequenceElement::Byte(byte).matches(b, ignore_case)
+            }
+            Pattern::Range(r) => r.matches(b),
+            Pattern::Mask(m) => m.matches(b),
+            Pattern::Class(ref c) => c.matches(b),
+            Pattern::Condition(_) | Pattern::Any => true,
+            Pattern::Unicode(_) | Pattern::InvalidUtf8 => {
+                self.pattern.utf8_matcher().is_some_and(|m| m.may_match(b))
+            }
+            Pattern::Sequence(ref s) => s
+                .elements
+                .first()
+                .is_none_or(|first| first.matches(b, s.ignore_case)),
+        }
+    }
+
+    fn is_skip_arm(&self, tracer: bool) -> bool {
+        let is_single_byte = matches!(
+            self.pattern,
+            Pattern::Byte { .. }
+                | Pattern::Range(_)
+                | Pattern::Mask(_)
+                | Pattern::Class(_)
+                | Pattern::Any
+        );
+
+        let has_no_effect = match self.rhs {
+            ArmRhs::Directives(ref d) => d.action_calls.is_empty() && d.state_transition.is_none(),
+            ArmRhs::Condition { .. } => false,
+        };
+
+        // NOTE: tracer needs to be notified about each matched arm.
+        is_single_byte && has_no_effect && !tracer
+    }
+}
+
+impl State {
+    /// Returns bytes that are consumed by the state without any effect: either
+    /// by arms with empty directives or by the fallback arm.
+    pub(crate) fn skippable_bytes(&self, tracer: bool) -> [bool; 256] {
+        let mut skippable = [false; 256];
+
+        for (b, skippable) in skippable.iter_mut().enumerate() {
+            *skippable = match self.arms.iter().find(|a| a.may_match(b as u8)) {
+                Some(arm) => arm.is_skip_arm(tracer),
+                None => true,
+            };
+        }
+
+        skippable
+    }
+
+    pub(super) fn compile_skip(&self, tracer: bool) -> TokenStream2 {
+        let skippable = self.skippable_bytes(tracer);
+
+        let delimiters = (0..=255u8)
+            .filter(|&b| !skippable[b as usize])
+            .map(|b| LitInt::new(b.into(), IntSuffix::U8, Span::call_site()))
+            .collect::<Vec<_>>();
+
+        if delimiters.len() == skippable.len() {
+            return quote! {};
+        }
+
+        let input = gen_parser_intrinsics!(input);
+        let pos = gen_parser_intrinsics!(pos);
+
+        let skipped = if delimiters.len() <= MAX_SEARCH_DELIMITERS {
+            let skip_until = gen_parser_intrinsics!(skip_until);
+
+            quote! { #skip_until(#input, &[#(#delimiters),*]) }
+        } else {
+            let skip_by_table = gen_parser_intrinsics!(skip_by_table);
+            let skippable = skippable.iter();
+
+            quote! {{
+                const SKIPPABLE: [bool; 256] = [#(#skippable),*];
+
+                #skip_by_table(#input, &SKIPPABLE)
+            }}
+        };
+
+        // NOTE: restart the loop after the skip, so the current byte is updated.
+        quote! {
+            let skipped = #skipped;
+
+            if skipped > 0 {
+                #pos += skipped;
+                continue;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test_helpers::to_code_str;
+
+    curry_parse_macros!($State);
+
+    fn delimiters(state: &State, tracer: bool) -> Vec<u8> {
+        let skippable = state.skippable_bytes(tracer);
+
+        (0..=255u8).filter(|&b| !skippable[b as usize]).collect()
+    }
+
+    #[test]
+    fn skippable_bytes() {
+        let state = parse_ok! {
+            foo_state:
+                --> => foo.
+                'a' => .
+                "bar"|i => bar.
+                eof => baz.
+                digit => .
+                '1' => qux.
+                '"' => --> bar_state.
+                _ => .
+        };
+
+        assert_eq!(delimiters(&state, false), b"\"Bb");
+        assert_eq!(delimiters(&state, true), (0..=255u8).collect::<Vec<_>>());
+
+        let state = parse_ok! {
+            foo_state:
+                '<' => foo.
+        };
+
+        assert_eq!(delimiters(&state, false), b"<");
+        assert_eq!(delimiters(&state, true), b"<");
+
+        let state = parse_ok! {
+            foo_state:
+                '<' => foo.
+                if cond => bar.
+        };
+
+        assert_eq!(delimiters(&state, false), (0..=255u8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn compile_skip_until_delimiter() {
+        assert_eq!(
+            to_code_str(
+                parse_ok! {
+                    foo_state:
+                        '"' => --> bar_state.
+                        '&' => foo.
+                        _ => .
+                }
+                .compile_skip(false)
+            ),
+            code_str! {
+                let skipped = self.__skip_until(input, &[34u8, 38u8]);
+
+                if skipped > 0 {
+                    self.__pos += skipped;
+                    continue;
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn compile_skip_by_table() {
+        let code = to_code_str(
+            parse_ok! {
+                foo_state:
+                    alpha => foo.
+                    _ => .
+            }
+            .compile_skip(false),
+        );
+
+        assert!(code.contains("const SKIPPABLE : [ bool ; 256 ] = [ true , true ,"));
+        assert!(code.contains("self . __skip_by_table ( input , & SKIPPABLE )"));
+    }
+
+    #[test]
+    fn no_skip() {
+        let state = parse_ok! {
+            foo_state:
+                _ => foo.
+        };
+
+        assert!(state.compile_skip(false).is_empty());
+    }
+}
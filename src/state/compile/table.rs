@@ -0,0 +1,236 @@
+use super::*;
+use crate::SequenceElement;
+use syn::{IntSuffix, LitInt};
+
+// NOTE: states with at least this number of byte consuming arms or byte
+// ranges matched by them are compiled into a lookup in the 256 entry table
+// that maps bytes to the arms instead of the ordered match arms.
+const TABLE_DISPATCH_MIN_ARMS: usize = 6;
+const TABLE_DISPATCH_MIN_RANGES: usize = 8;
+
+// NOTE: arm indices are stored in the table shifted by one,
+// `0` is reserved for the bytes handled by the fallback arm.
+const MAX_TABLE_ARMS: usize = u8::MAX as usize;
+
+impl Arm {
+    fn matches_byte(&self, b: u8) -> bool {
+        match self.pattern {
+            Pattern::Byte { byte, ignore_case } => {
+                SequenceElement::Byte(byte).matches(b, ignore_case)
+            }
+            Pattern::Range(r) => r.matches(b),
+            Pattern::Mask(m) => m.matches(b),
+            Pattern::Class(ref c) => c.matches(b),
+            Pattern::Any => true,
+            _ => false,
+        }
+    }
+
+    // NOTE: number of contiguous byte ranges that need to be compared
+    // with the byte if the arm is compiled into a match arm.
+    fn range_count(&self) -> usize {
+        (0..=255u8)
+            .filter(|&b| self.matches_byte(b) && (b == 0 || !self.matches_byte(b - 1)))
+            .count()
+    }
+}
+
+impl State {
+    // NOTE: table dispatch is used only if all the arms that consume bytes
+    // match single bytes, so the table can't change the order in which
+    // arms are tried: arms that match bytes can't interfere with the input
+    // state arms and each byte is dispatched to the first arm that matches it.
+    pub(super) fn has_table_dispatch(&self) -> bool {
+        let mut byte_arm_count = 0;
+        let mut range_count = 0;
+
+        for arm in &self.arms {
+            match arm.pattern {
+                Pattern::StateEnter | Pattern::InputState(_) => (),
+                Pattern::Byte { .. } | Pattern::Range(_) | Pattern::Mask(_) | Pattern::Class(_) => {
+                    byte_arm_count += 1;
+                    range_count += arm.range_count();
+                }
+                Pattern::Any => (),
+                Pattern::Condition(_)
+                | Pattern::Sequence(_)
+                | Pattern::Unicode(_)
+                | Pattern::InvalidUtf8 => return false,
+            }
+        }
+
+        self.arms.len() <= MAX_TABLE_ARMS
+            && (byte_arm_count >= TABLE_DISPATCH_MIN_ARMS
+                || range_count >= TABLE_DISPATCH_MIN_RANGES)
+    }
+
+    /// Returns the table that maps each byte to the index of the first arm that
+    /// matches it plus one, or to `0` if the byte is handled by the fallback arm.
+    pub(crate) fn arm_table(&self) -> [u8; 256] {
+        let mut table = [0; 256];
+
+        for (b, entry) in table.iter_mut().enumerate() {
+            if let Some(idx) = self.arms.iter().position(|a| a.matches_byte(b as u8)) {
+                *entry = idx as u8 + 1;
+            }
+        }
+
+        table
+    }
+
+    pub(super) fn compile_table_dispatch(
+        &self,
+        idx: usize,
+        tracer: bool,
+        states: &[State],
+    ) -> TokenStream2 {
+        let ch = gen_parser_intrinsics!(current_byte);
+        let table = self.arm_table();
+
+        let compile_edge = |arm| EdgeId {
+            state: idx,
+            arm,
+            branch: 0,
+        };
+
+        let byte_arms = self
+            .arms
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| table.contains(&(i as u8 + 1)))
+            .map(|(i, a)| {
+                let entry = LitInt::new(i as u64 + 1, IntSuffix::U8, Span::call_site());
+                let body = a.compile_body(&self.name, compile_edge(i), tracer, states);
+
+                quote! { #entry => { #body } }
+            });
+
+        let input_state_arms = self
+            .arms
+            .iter()
+            .enumerate()
+            .filter(|(_, a)| matches!(a.pattern, Pattern::InputState(_)))
+            .map(|(i, a)| a.compile(&self.name, compile_edge(i), tracer, states));
+
+        let byte_fallback = self.compile_fallback(Consumption::Byte);
+        let input_state_fallback = self.compile_fallback(Consumption::InputState);
+        let table = table.iter();
+
+        quote! {
+            const ARM_TABLE: [u8; 256] = [#(#table),*];
+
+            match #ch {
+                Some(b) => match ARM_TABLE[b as usize] {
+                    #(#byte_arms)*
+                    _ => { #byte_fallback }
+                },
+                #(#input_state_arms)*
+                None => { #input_state_fallback }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test_helpers::to_code_str;
+
+    curry_parse_macros!($State);
+
+    #[test]
+    fn has_table_dispatch() {
+        let state = parse_ok! {
+            foo_state:
+                xdigit => foo.
+                space => bar.
+                alnum => baz.
+                _ => .
+        };
+
+        assert!(state.has_table_dispatch());
+
+        let state = parse_ok! {
+            foo_state:
+                'a' => foo.
+                'b' => foo.
+                digit => bar.
+                eof => baz.
+        };
+
+        assert!(!state.has_table_dispatch());
+
+        let state = parse_ok! {
+            foo_state:
+                xdigit => foo.
+                space => bar.
+                "foo" => baz.
+        };
+
+        assert!(!state.has_table_dispatch());
+    }
+
+    #[test]
+    fn arm_table() {
+        let table = parse_ok! {
+            foo_state:
+                --> => foo.
+                eof => foo.
+                'a' => foo.
+                alpha => bar.
+                digit => baz.
+                '1' => qux.
+        }
+        .arm_table();
+
+        assert_eq!(table[b'a' as usize], 3);
+        assert_eq!(table[b'b' as usize], 4);
+        assert_eq!(table[b'Z' as usize], 4);
+        assert_eq!(table[b'1' as usize], 5);
+        assert_eq!(table[b'-' as usize], 0);
+    }
+
+    #[test]
+    fn compile_table_dispatch() {
+        let code = to_code_str(
+            parse_ok! {
+                foo_state:
+                    eoc => foo.
+                    xdigit => bar.
+                    space => baz.
+                    alnum => .
+            }
+            .compile_table_dispatch(0, false, &[]),
+        );
+
+        assert!(code.starts_with("const ARM_TABLE : [ u8 ; 256 ] = [ 0u8 , 0u8 ,"));
+
+        assert!(code.ends_with(&to_code_str(quote! {
+            match ch {
+                Some(b) => match ARM_TABLE[b as usize] {
+                    2u8 => {
+                        self.bar();
+                        self.__pos += 1;
+                    }
+                    3u8 => {
+                        self.baz();
+                        self.__pos += 1;
+                    }
+                    4u8 => {
+                        self.__pos += 1;
+                    }
+                    _ => {
+                        self.__pos += 1;
+                    }
+                },
+                None if !self.__is_last_input => {
+                    self.foo();
+                    return Ok(0);
+                }
+                None => {
+                    return Ok(0);
+                }
+            }
+        })));
+    }
+}
@@ -0,0 +1,232 @@
+use super::*;
+use crate::SequencePattern;
+use syn::{IntSuffix, LitInt};
+
+// NOTE: sequence arm candidate of the trie node, arms
+// are ordered in the order they appear in the state.
+type Candidate<'s> = (usize, &'s SequencePattern);
+
+fn sequence(arm: &Arm) -> Option<&SequencePattern> {
+    match arm.pattern {
+        Pattern::Sequence(ref s) => Some(s),
+        _ => None,
+    }
+}
+
+fn byte_matches(sequence: &SequencePattern, depth: usize, b: u8) -> bool {
+    sequence.elements[depth].matches(b, sequence.ignore_case)
+}
+
+fn compile_outcome(arm: Option<usize>) -> TokenStream2 {
+    match arm {
+        Some(arm) => quote! { Some(#arm) },
+        None => quote! { None },
+    }
+}
+
+// NOTE: produces the same outcome as the ordered matching of the candidates with
+// `__match_sequence`: the first candidate that doesn't mismatch wins and the input is
+// blocked if it's a partial match. Candidates that follow the one that is completely
+// matched at the node can't win, so they are dropped and the complete candidate
+// becomes the outcome of the node if the rest of the candidates mismatch.
+fn compile_trie_node(
+    candidates: &[Candidate],
+    depth: usize,
+    fallback: Option<usize>,
+) -> TokenStream2 {
+    let input = gen_parser_intrinsics!(input);
+    let pos = gen_parser_intrinsics!(pos);
+    let is_last_input = gen_parser_intrinsics!(is_last_input);
+    let block_input = gen_parser_intrinsics!(block_input);
+
+    let (pending, fallback) = match candidates.iter().position(|c| c.1.len() == depth) {
+        Some(i) => (&candidates[..i], Some(candidates[i].0)),
+        None => (candidates, fallback),
+    };
+
+    let fallback_outcome = compile_outcome(fallback);
+
+    if pending.is_empty() {
+        return fallback_outcome;
+    }
+
+    let mut branches: Vec<(Vec<Candidate>, Vec<u8>)> = vec![];
+
+    for b in 0..=255u8 {
+        let next = pending
+            .iter()
+            .filter(|c| byte_matches(c.1, depth, b))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if next.is_empty() {
+            continue;
+        }
+
+        match branches.iter_mut().find(|(c, _)| *c == next) {
+            Some((_, bytes)) => bytes.push(b),
+            None => branches.push((next, vec![b])),
+        }
+    }
+
+    let branches = branches.iter().map(|(next, bytes)| {
+        let bytes = bytes
+            .iter()
+            .map(|&b| LitInt::new(b.into(), IntSuffix::U8, Span::call_site()));
+
+        let node = compile_trie_node(next, depth + 1, fallback);
+
+        quote! { #(Some(#bytes))|* => #node, }
+    });
+
+    quote! {
+        match #input.get(#pos + #depth).cloned() {
+            #(#branches)*
+            Some(_) => #fallback_outcome,
+            None if !#is_last_input => #block_input,
+            None => #fallback_outcome,
+        }
+    }
+}
+
+impl State {
+    /// Splits the state's arms into the groups that are compiled as a single match
+    /// arm: runs of the consecutive sequence arms and the rest of the arms one by one.
+    pub(super) fn group_sequence_arms<'s>(arms: &[(usize, &'s Arm)]) -> Vec<Vec<(usize, &'s Arm)>> {
+        let mut groups: Vec<Vec<(usize, &Arm)>> = vec![];
+
+        for &arm in arms {
+            let joins_group = sequence(arm.1).is_some()
+                && groups
+                    .last()
+                    .and_then(|g| g.last())
+                    .is_some_and(|a| sequence(a.1).is_some());
+
+            match groups.last_mut() {
+                Some(group) if joins_group => group.push(arm),
+                _ => groups.push(vec![arm]),
+            }
+        }
+
+        groups
+    }
+
+    // NOTE: sequences of the group are matched in one pass by the trie. The guard stores the
+    // matched arm, so the arms that follow the group are tried if none of the sequences match.
+    pub(super) fn compile_sequence_group(
+        &self,
+        group: &[(usize, &Arm)],
+        idx: usize,
+        tracer: bool,
+        states: &[State],
+    ) -> TokenStream2 {
+        let sequence_arm = gen_parser_intrinsics!(sequence_arm);
+
+        let candidates = group
+            .iter()
+            .filter_map(|&(i, a)| sequence(a).map(|s| (i, s)))
+            .collect::<Vec<_>>();
+
+        let trie = compile_trie_node(&candidates, 0, None);
+
+        let arms = group.iter().map(|&(i, a)| {
+            let edge = EdgeId {
+                state: idx,
+                arm: i,
+                branch: 0,
+            };
+
+            let body = a.compile_body(&self.name, edge, tracer, states);
+
+            quote! { Some(#i) => { #body } }
+        });
+
+        quote! {
+            Some(_) if {
+                #sequence_arm = #trie;
+                #sequence_arm.is_some()
+            } => match #sequence_arm {
+                #(#arms)*
+                _ => unreachable!(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test_helpers::to_code_str;
+
+    curry_parse_macros!($State);
+
+    #[test]
+    fn group_sequence_arms() {
+        let state = parse_ok! {
+            foo_state:
+                "foo" => foo.
+                '-' => bar.
+                "--" => baz.
+                "DOCTYPE"|i => qux.
+                "[CDATA[" => quz.
+                _ => .
+        };
+
+        let arms = state.arms.iter().enumerate().collect::<Vec<_>>();
+
+        let groups = State::group_sequence_arms(&arms)
+            .into_iter()
+            .map(|g| g.into_iter().map(|(i, _)| i).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        assert_eq!(groups, vec![vec![0], vec![1], vec![2, 3, 4], vec![5]]);
+    }
+
+    #[test]
+    fn compile_sequence_group() {
+        let state = parse_ok! {
+            foo_state:
+                "ab" => foo.
+                "a"|i => bar.
+                "b" => baz.
+        };
+
+        let arms = state.arms.iter().enumerate().collect::<Vec<_>>();
+
+        assert_eq!(
+            to_code_str(state.compile_sequence_group(&arms, 0, false, &[])),
+            code_str! {
+                Some(_) if {
+                    sequence_arm = match input.get(self.__pos + 0usize).cloned() {
+                        Some(65u8) => Some(1usize),
+                        Some(97u8) => match input.get(self.__pos + 1usize).cloned() {
+                            Some(98u8) => Some(0usize),
+                            Some(_) => Some(1usize),
+                            None if !self.__is_last_input => return Ok(input.len() - self.__pos),
+                            None => Some(1usize),
+                        },
+                        Some(98u8) => Some(2usize),
+                        Some(_) => None,
+                        None if !self.__is_last_input => return Ok(input.len() - self.__pos),
+                        None => None,
+                    };
+                    sequence_arm.is_some()
+                } => match sequence_arm {
+                    Some(0usize) => {
+                        self.foo();
+                        self.__pos += 2usize;
+                    }
+                    Some(1usize) => {
+                        self.bar();
+                        self.__pos += 1usize;
+                    }
+                    Some(2usize) => {
+                        self.baz();
+                        self.__pos += 1usize;
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        );
+    }
+}
@@ -1,3 +1,4 @@
+mod compile;
 mod parse;
 
 use crate::Arm;
@@ -1,12 +1,13 @@
 use super::*;
+use crate::NamedClass;
 use syn::parse::{Parse, ParseStream};
 use syn::{Ident, Result as ParseResult, Token};
 
-fn parse_arms(input: ParseStream) -> ParseResult<Vec<Arm>> {
+fn parse_arms(input: ParseStream, classes: &[NamedClass]) -> ParseResult<Vec<Arm>> {
     let mut arms = vec![];
 
     loop {
-        arms.push(input.parse::<Arm>()?);
+        arms.push(Arm::parse_with_classes(input, classes)?);
 
         let is_next_state_name = input.peek(Ident) && input.peek2(Token! { : });
 
@@ -18,19 +19,29 @@ fn parse_arms(input: ParseStream) -> ParseResult<Vec<Arm>> {
     Ok(arms)
 }
 
-impl Parse for State {
-    fn parse(input: ParseStream) -> ParseResult<Self> {
+impl State {
+    /// Parses the state which arms can refer to the classes declared in the grammar.
+    pub(crate) fn parse_with_classes(
+        input: ParseStream,
+        classes: &[NamedClass],
+    ) -> ParseResult<Self> {
         let name = input.parse::<Ident>()?.to_string();
 
         input.parse::<Token! { : }>()?;
 
         Ok(State {
             name,
-            arms: parse_arms(input)?,
+            arms: parse_arms(input, classes)?,
         })
     }
 }
 
+impl Parse for State {
+    fn parse(input: ParseStream) -> ParseResult<Self> {
+        Self::parse_with_classes(input, &[])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -50,7 +61,10 @@ mod tests {
                 name: "foo_state".into(),
                 arms: vec![
                     Arm {
-                        pattern: Pattern::Byte(b'a'),
+                        pattern: Pattern::Byte {
+                            byte: b'a',
+                            ignore_case: false,
+                        },
                         rhs: ArmRhs::Directives(Directives {
                             action_calls: vec![act!("bar")],
                             state_transition: Some(StateTransition {
@@ -83,8 +97,9 @@ mod tests {
                 foo_state:
             ],
             concat![
-                "unexpected end of input, expected one of: character literal, integer literal, ",
-                "string literal, square brackets, identifier, `_`, `if`"
+                "unexpected end of input, expected one of: character literal, byte literal, ",
+                "integer literal, string literal, byte string literal, square brackets, ",
+                "identifier, `_`, `if`"
             ]
         );
     }
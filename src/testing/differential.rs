@@ -0,0 +1,240 @@
+use super::input::{split_randomly, InputGenerator, Rng};
+use super::trace::{fmt_bytes, fmt_chunks, fmt_event, Trace, TraceEvent};
+use crate::Grammar;
+use std::fmt::{self, Display, Formatter};
+
+/// Returns the index of the first event that differs in the traces
+/// along with the events (`None` if the trace has ended).
+pub(super) fn first_divergence(
+    left: &[TraceEvent],
+    right: &[TraceEvent],
+) -> Option<(usize, Option<TraceEvent>, Option<TraceEvent>)> {
+    let len = left.len().max(right.len());
+
+    (0..len)
+        .map(|i| (i, left.get(i).cloned(), right.get(i).cloned()))
+        .find(|(_, l, r)| l != r)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Divergence {
+    pub input: Vec<u8>,
+    pub chunks: Vec<Vec<u8>>,
+    /// Index of the first trace event that differs.
+    pub index: usize,
+    pub compiled: Option<TraceEvent>,
+    pub interpreted: Option<TraceEvent>,
+    pub minimized_input: Vec<u8>,
+    pub minimized_chunks: Vec<Vec<u8>>,
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        writeln!(f, "traces diverge at event #{}:", self.index)?;
+        writeln!(
+            f,
+            "  compiled:    {}",
+            fmt_event(&self.compiled, &self.input)
+        )?;
+        writeln!(
+            f,
+            "  interpreted: {}",
+            fmt_event(&self.interpreted, &self.input)
+        )?;
+        writeln!(f, "input: {}", fmt_bytes(&self.input))?;
+        writeln!(f, "chunks: {}", fmt_chunks(&self.chunks))?;
+        writeln!(f, "minimized input: {}", fmt_bytes(&self.minimized_input))?;
+        write!(
+            f,
+            "minimized chunks: {}",
+            fmt_chunks(&self.minimized_chunks)
+        )
+    }
+}
+
+impl std::error::Error for Divergence {}
+
+// NOTE: minimized input loses the original chunk boundaries, so the
+// input is considered diverging if any of its simple chunkings diverges:
+// whole input, every split in two and byte-by-byte feeding.
+fn candidate_chunkings(input: &[u8]) -> Vec<Vec<&[u8]>> {
+    let mut chunkings = vec![vec![input]];
+
+    for i in 1..input.len() {
+        let (head, tail) = input.split_at(i);
+
+        chunkings.push(vec![head, tail]);
+    }
+
+    if input.len() > 2 {
+        chunkings.push(input.chunks(1).collect());
+    }
+
+    chunkings
+}
+
+/// Runs random and structured inputs split into random chunks through both
+/// the compiled parser and the interpreter, comparing the recorded traces.
+///
+/// Runners get the input chunks and should feed them to a fresh parser,
+/// recording the trace (see `interpreter_trace` and `TraceEvent`).
+#[derive(Debug, Clone)]
+pub struct DifferentialTest {
+    pub iterations: usize,
+    pub seed: u64,
+    pub max_input_len: usize,
+    generator: InputGenerator,
+}
+
+impl DifferentialTest {
+    pub fn new(grammar: &Grammar) -> Self {
+        DifferentialTest {
+            iterations: 1000,
+            seed: 0x5eed,
+            max_input_len: 64,
+            generator: InputGenerator::new(grammar),
+        }
+    }
+
+    pub fn run(
+        &self,
+        mut compiled: impl FnMut(&[&[u8]]) -> Trace,
+        mut interpreted: impl FnMut(&[&[u8]]) -> Trace,
+    ) -> Result<(), Box<Divergence>> {
+        let mut rng = Rng::new(self.seed);
+
+        for i in 0..self.iterations {
+            let input = if i % 2 == 0 {
+                self.generator.structured(&mut rng, self.max_input_len)
+            } else {
+                self.generator.random(&mut rng, self.max_input_len)
+            };
+
+            let chunks = split_randomly(&mut rng, &input);
+
+            if let Some((index, c, i)) = first_divergence(&compiled(&chunks), &interpreted(&chunks))
+            {
+                let mut diverges = |input: &[u8]| {
+                    candidate_chunkings(input)
+                        .into_iter()
+                        .find(|chunks| compiled(chunks) != interpreted(chunks))
+                        .map(|chunks| chunks.iter().map(|c| c.to_vec()).collect::<Vec<_>>())
+                };
+
+                let (minimized_input, minimized_chunks) = match minimize(&input, &mut diverges) {
+                    Some(minimized) => minimized,
+                    None => (input.clone(), chunks.iter().map(|c| c.to_vec()).collect()),
+                };
+
+                return Err(Box::new(Divergence {
+                    chunks: chunks.iter().map(|c| c.to_vec()).collect(),
+                    input,
+                    index,
+                    compiled: c,
+                    interpreted: i,
+                    minimized_input,
+                    minimized_chunks,
+                }));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Delta debugging minimization: repeatedly removes parts of the input
+/// while it still diverges. Returns `None` if the input itself doesn't
+/// diverge with any of the candidate chunkings.
+fn minimize(
+    input: &[u8],
+    diverges: &mut impl FnMut(&[u8]) -> Option<Vec<Vec<u8>>>,
+) -> Option<(Vec<u8>, Vec<Vec<u8>>)> {
+    let mut chunks = diverges(input)?;
+    let mut input = input.to_vec();
+    let mut granularity = 2;
+
+    while input.len() >= 2 {
+        let part_len = input.len().div_ceil(granularity);
+        let mut reduced = false;
+
+        for start in (0..input.len()).step_by(part_len) {
+            let end = (start + part_len).min(input.len());
+            let complement = [&input[..start], &input[end..]].concat();
+
+            if let Some(c) = diverges(&complement) {
+                input = complement;
+                chunks = c;
+                granularity = (granularity - 1).max(2);
+                reduced = true;
+                break;
+            }
+        }
+
+        if !reduced {
+            if granularity >= input.len() {
+                break;
+            }
+
+            granularity = (granularity * 2).min(input.len());
+        }
+    }
+
+    Some((input, chunks))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::interpreter_trace;
+
+    curry_parse_macros!($Grammar);
+
+    #[test]
+    fn no_divergence() {
+        let grammar = parse_ok! {
+            TestGrammar = {
+                foo_state:
+                    "foo" => foo, @pin(foo_start).
+                    "bar"|i => bar.
+                    digit => digit.
+                    _ => other.
+            }
+        };
+
+        let test = DifferentialTest::new(&grammar);
+        let run = |chunks: &[&[u8]]| interpreter_trace::<()>(&grammar, |_| (), chunks);
+
+        assert_eq!(test.run(run, run), Ok(()));
+    }
+
+    #[test]
+    fn divergence() {
+        let grammar = parse_ok! {
+            TestGrammar = {
+                foo_state:
+                    "foo" => foo.
+                    _ => other.
+            }
+        };
+
+        let buggy_grammar = parse_ok! {
+            TestGrammar = {
+                foo_state:
+                    "fo" => foo.
+                    _ => other.
+            }
+        };
+
+        let err = DifferentialTest::new(&grammar)
+            .run(
+                |chunks| interpreter_trace::<()>(&buggy_grammar, |_| (), chunks),
+                |chunks| interpreter_trace::<()>(&grammar, |_| (), chunks),
+            )
+            .unwrap_err();
+
+        assert_eq!(err.minimized_input, b"fo");
+        assert_eq!(err.minimized_chunks, vec![b"fo".to_vec()]);
+        assert_ne!(err.compiled, err.interpreted);
+        assert!(err.to_string().contains("minimized input: b\"fo\""));
+    }
+}
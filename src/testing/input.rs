@@ -0,0 +1,240 @@
+use crate::{
+    ClassPattern, Grammar, MaskPattern, Pattern, RangePattern, SequenceElement, SequencePattern,
+};
+
+/// Xorshift random number generator. Tests need reproducible
+/// inputs rather than high quality randomness.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // NOTE: xorshift gets stuck on zero state.
+        Rng(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.0 = x;
+
+        x.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Returns random number in the `0..n` range.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n.max(1) as u64) as usize
+    }
+
+    pub fn byte(&mut self) -> u8 {
+        self.next_u64() as u8
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Fragment {
+    Bytes(Vec<u8>),
+    Sequence(SequencePattern),
+    Range(RangePattern),
+    Mask(MaskPattern),
+    Class(ClassPattern),
+    /// UTF-8 encoded code point, possibly truncated or malformed.
+    CodePoint,
+    AnyByte,
+}
+
+/// Generates inputs from the pieces of the grammar's patterns, so inputs
+/// are likely to exercise the state machine's arms.
+#[derive(Debug, Clone)]
+pub struct InputGenerator {
+    fragments: Vec<Fragment>,
+    alphabet: Vec<u8>,
+}
+
+impl InputGenerator {
+    pub fn new(grammar: &Grammar) -> Self {
+        let mut fragments = vec![Fragment::AnyByte];
+        let mut alphabet = vec![];
+
+        for arm in grammar.states.iter().flat_map(|s| s.arms.iter()) {
+            match arm.pattern {
+                Pattern::Byte { byte, ignore_case } => {
+                    let bytes = (0..=255)
+                        .filter(|&b| SequenceElement::Byte(byte).matches(b, ignore_case))
+                        .collect::<Vec<_>>();
+
+                    fragments.extend(bytes.iter().map(|&b| Fragment::Bytes(vec![b])));
+                    alphabet.extend(bytes);
+                }
+                Pattern::Range(r) => fragments.push(Fragment::Range(r)),
+                Pattern::Mask(m) => fragments.push(Fragment::Mask(m)),
+                Pattern::Class(ref c) => fragments.push(Fragment::Class(c.clone())),
+                Pattern::Unicode(_) | Pattern::InvalidUtf8 => fragments.push(Fragment::CodePoint),
+                Pattern::Sequence(ref s) => {
+                    fragments.push(Fragment::Sequence(s.clone()));
+
+                    alphabet.extend(s.elements.iter().filter_map(|e| match e {
+                        SequenceElement::Byte(b) => Some(b),
+                        _ => None,
+                    }));
+                }
+                _ => (),
+            }
+        }
+
+        alphabet.sort();
+        alphabet.dedup();
+
+        InputGenerator {
+            fragments,
+            alphabet,
+        }
+    }
+
+    fn gen_fragment(&self, rng: &mut Rng, input: &mut Vec<u8>) {
+        match self.fragments[rng.below(self.fragments.len())] {
+            Fragment::Bytes(ref bytes) => input.extend(bytes),
+            Fragment::Sequence(ref sequence) => {
+                // NOTE: sequence prefixes test failover to the next arms.
+                let len = if rng.below(4) == 0 {
+                    rng.below(sequence.len())
+                } else {
+                    sequence.len()
+                };
+
+                for element in &sequence.elements[..len] {
+                    let matching = (0..=255)
+                        .filter(|&b| element.matches(b, sequence.ignore_case))
+                        .collect::<Vec<_>>();
+
+                    input.push(matching[rng.below(matching.len())]);
+                }
+            }
+            Fragment::Range(range) => {
+                let matching = (0..=255).filter(|&b| range.matches(b)).collect::<Vec<_>>();
+
+                input.push(matching[rng.below(matching.len())]);
+            }
+            Fragment::Mask(mask) => input.push(rng.byte() & !mask.mask | mask.value),
+            Fragment::Class(ref class) => {
+                let matching = (0..=255).filter(|&b| class.matches(b)).collect::<Vec<_>>();
+
+                input.push(matching[rng.below(matching.len())]);
+            }
+            Fragment::CodePoint => {
+                // NOTE: code points of each encoded length are equally likely.
+                let (start, end) = [
+                    (0, 0x7f),
+                    (0x80, 0x7ff),
+                    (0x800, 0xffff),
+                    (0x10000, 0x10ffff),
+                ][rng.below(4)];
+
+                let c = std::char::from_u32(start + rng.below((end - start + 1) as usize) as u32)
+                    .unwrap_or(std::char::REPLACEMENT_CHARACTER);
+
+                let mut buf = [0; 4];
+                let bytes = c.encode_utf8(&mut buf).as_bytes();
+
+                match rng.below(8) {
+                    0 => input.extend(&bytes[..rng.below(bytes.len())]),
+                    1 => input.push(0x80 | rng.byte()),
+                    _ => input.extend(bytes),
+                }
+            }
+            Fragment::AnyByte => input.push(rng.byte()),
+        }
+    }
+
+    /// Generates input from the fragments of the grammar's patterns.
+    pub fn structured(&self, rng: &mut Rng, max_len: usize) -> Vec<u8> {
+        let mut input = vec![];
+        let len = rng.below(max_len + 1);
+
+        while input.len() < len {
+            self.gen_fragment(rng, &mut input);
+        }
+
+        input.truncate(len);
+        input
+    }
+
+    /// Generates input from random bytes, preferring bytes used in the grammar.
+    pub fn random(&self, rng: &mut Rng, max_len: usize) -> Vec<u8> {
+        (0..rng.below(max_len + 1))
+            .map(|_| {
+                if self.alphabet.is_empty() || rng.below(4) == 0 {
+                    rng.byte()
+                } else {
+                    self.alphabet[rng.below(self.alphabet.len())]
+                }
+            })
+            .collect()
+    }
+}
+
+/// Splits input into chunks at random boundaries.
+pub fn split_randomly<'i>(rng: &mut Rng, input: &'i [u8]) -> Vec<&'i [u8]> {
+    let mut chunks = vec![];
+    let mut rest = input;
+
+    while !rest.is_empty() {
+        let (chunk, tail) = rest.split_at(1 + rng.below(rest.len()));
+
+        chunks.push(chunk);
+        rest = tail;
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    curry_parse_macros!($Grammar);
+
+    #[test]
+    fn generate_structured_input() {
+        let generator = InputGenerator::new(&parse_ok! {
+            TestGrammar = {
+                foo_state:
+                    "foo"|i => foo.
+                    '<' => bar.
+                    digit => baz.
+            }
+        });
+
+        let mut rng = Rng::new(42);
+
+        for _ in 0..100 {
+            let input = generator.structured(&mut rng, 20);
+
+            assert!(input.len() <= 20);
+        }
+
+        let input = (0..100)
+            .flat_map(|_| generator.structured(&mut rng, 20))
+            .collect::<Vec<_>>();
+
+        assert!(input.windows(3).any(|w| w.eq_ignore_ascii_case(b"foo")));
+        assert!(input.contains(&b'<'));
+        assert!(input.iter().any(u8::is_ascii_digit));
+    }
+
+    #[test]
+    fn split_input_randomly() {
+        let mut rng = Rng::new(42);
+        let input = b"foobarbaz";
+
+        for _ in 0..100 {
+            let chunks = split_randomly(&mut rng, input);
+
+            assert!(chunks.iter().all(|c| !c.is_empty()));
+            assert_eq!(chunks.concat(), input);
+        }
+    }
+}
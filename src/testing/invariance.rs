@@ -0,0 +1,282 @@
+use super::differential::first_divergence;
+use super::input::{split_randomly, Rng};
+use super::trace::{
+    feed_chunks, fmt_bytes, fmt_chunks, fmt_event, StreamingParser, Trace, TraceEvent,
+};
+use crate::{Grammar, Pattern, SequencePattern};
+use std::collections::BTreeSet;
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitPoints {
+    /// Input is split in two at every position.
+    All,
+    /// Input is split in two at the given number of random positions.
+    /// Positions inside sequence matches and around pins are always checked.
+    Sample(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChunkBoundaryViolation {
+    pub input: Vec<u8>,
+    pub chunks: Vec<Vec<u8>>,
+    /// Index of the first trace event that differs.
+    pub index: usize,
+    /// Event of the trace produced for the whole input.
+    pub expected: Option<TraceEvent>,
+    /// Event of the trace produced for the chunks.
+    pub actual: Option<TraceEvent>,
+}
+
+impl Display for ChunkBoundaryViolation {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "trace for the chunked input diverges from the whole input trace at event #{}:",
+            self.index
+        )?;
+
+        writeln!(f, "  whole:   {}", fmt_event(&self.expected, &self.input))?;
+        writeln!(f, "  chunked: {}", fmt_event(&self.actual, &self.input))?;
+        writeln!(f, "input: {}", fmt_bytes(&self.input))?;
+        write!(f, "chunks: {}", fmt_chunks(&self.chunks))
+    }
+}
+
+impl std::error::Error for ChunkBoundaryViolation {}
+
+/// Checks that the parser produces the same trace (including pins and pinned input
+/// passed to the actions) whether the input arrives whole, split byte-by-byte or
+/// split in two at the split points.
+#[derive(Debug, Clone)]
+pub struct ChunkInvarianceTest {
+    pub split_points: SplitPoints,
+    pub seed: u64,
+    sequences: Vec<SequencePattern>,
+    has_utf8_patterns: bool,
+}
+
+impl ChunkInvarianceTest {
+    pub fn new(grammar: &Grammar) -> Self {
+        let arms = || grammar.states.iter().flat_map(|s| s.arms.iter());
+
+        let sequences = arms()
+            .filter_map(|a| match a.pattern {
+                Pattern::Sequence(ref s) => Some(s.clone()),
+                _ => None,
+            })
+            .collect();
+
+        ChunkInvarianceTest {
+            split_points: SplitPoints::All,
+            seed: 0x5eed,
+            sequences,
+            has_utf8_patterns: arms().any(|a| a.pattern.utf8_matcher().is_some()),
+        }
+    }
+
+    // NOTE: boundaries inside sequences and multi-byte code points make the
+    // parser block the input and boundaries around pins shift the chunk
+    // offset, so they are checked regardless of the sampling.
+    fn priority_split_points(&self, input: &[u8], trace: &[TraceEvent]) -> BTreeSet<usize> {
+        let mut points = BTreeSet::new();
+
+        for sequence in self.sequences.iter().filter(|s| !s.is_empty()) {
+            for (start, window) in input.windows(sequence.len()).enumerate() {
+                if sequence.matches(window) {
+                    points.extend(start + 1..start + sequence.len());
+                }
+            }
+        }
+
+        if self.has_utf8_patterns {
+            for (start, _) in input.iter().enumerate().filter(|(_, &b)| b >= 0xc0) {
+                points.extend(start + 1..start + 4);
+            }
+        }
+
+        for event in trace {
+            if let TraceEvent::Pins(pins) = event {
+                for &(_, offset) in pins {
+                    points.extend(offset.saturating_sub(1)..=offset + 1);
+                }
+            }
+        }
+
+        points.retain(|&p| p > 0 && p < input.len());
+        points
+    }
+
+    fn split_points(&self, input: &[u8], trace: &[TraceEvent]) -> BTreeSet<usize> {
+        match self.split_points {
+            SplitPoints::All => (1..input.len()).collect(),
+            SplitPoints::Sample(count) => {
+                let mut rng = Rng::new(self.seed);
+                let mut points = self.priority_split_points(input, trace);
+
+                if input.len() > 1 {
+                    points.extend((0..count).map(|_| 1 + rng.below(input.len() - 1)));
+                }
+
+                points
+            }
+        }
+    }
+
+    /// Runs the check with `run` that should feed the chunks to a fresh parser
+    /// and return the recorded trace.
+    pub fn check(
+        &self,
+        input: &[u8],
+        mut run: impl FnMut(&[&[u8]]) -> Trace,
+    ) -> Result<(), Box<ChunkBoundaryViolation>> {
+        let expected = run(&[input]);
+        let mut chunkings = vec![input.chunks(1).collect::<Vec<_>>()];
+
+        for point in self.split_points(input, &expected) {
+            let (head, tail) = input.split_at(point);
+
+            chunkings.push(vec![head, tail]);
+        }
+
+        if let SplitPoints::Sample(count) = self.split_points {
+            let mut rng = Rng::new(self.seed);
+
+            chunkings.extend((0..count).map(|_| split_randomly(&mut rng, input)));
+        }
+
+        for chunks in chunkings {
+            let actual = run(&chunks);
+
+            if let Some((index, expected, actual)) = first_divergence(&expected, &actual) {
+                return Err(Box::new(ChunkBoundaryViolation {
+                    input: input.to_vec(),
+                    chunks: chunks.iter().map(|c| c.to_vec()).collect(),
+                    index,
+                    expected,
+                    actual,
+                }));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Runs the check on parsers created with `new_parser`. `into_trace` gets
+    /// the parser with the result of parsing and should return the trace
+    /// recorded by the parser's actions, e.g. followed by `TraceEvent::pins`.
+    pub fn check_parser<P: StreamingParser>(
+        &self,
+        input: &[u8],
+        mut new_parser: impl FnMut() -> P,
+        mut into_trace: impl FnMut(P, Result<(), P::Error>) -> Trace,
+    ) -> Result<(), Box<ChunkBoundaryViolation>> {
+        self.check(input, |chunks| {
+            let mut parser = new_parser();
+            let result = feed_chunks(&mut parser, chunks);
+
+            into_trace(parser, result)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interpreter::{ActionTable, Interpreter};
+    use crate::testing::interpreter_trace;
+
+    curry_parse_macros!($Grammar);
+
+    #[test]
+    fn invariant_trace() {
+        let grammar = parse_ok! {
+            TestGrammar = {
+                foo_state:
+                    "foo"|i => foo, @pin(foo_start).
+                    '<' => @pin(tag_start), --> tag_state.
+                    _ => other.
+
+                tag_state:
+                    "/>" => tag, --> foo_state.
+                    _ => .
+            }
+        };
+
+        let input = b"xFoO<a/>foo<b/";
+
+        for &split_points in &[SplitPoints::All, SplitPoints::Sample(3)] {
+            let test = ChunkInvarianceTest {
+                split_points,
+                ..ChunkInvarianceTest::new(&grammar)
+            };
+
+            assert_eq!(
+                test.check(input, |chunks| interpreter_trace::<()>(
+                    &grammar,
+                    |_| (),
+                    chunks
+                )),
+                Ok(())
+            );
+
+            assert_eq!(
+                test.check_parser(
+                    input,
+                    || {
+                        let mut actions = ActionTable::<()>::new();
+
+                        for name in &["foo", "tag", "other"] {
+                            actions.add_action(name, |_| Ok(()));
+                        }
+
+                        Interpreter::new(&grammar, actions).unwrap()
+                    },
+                    |parser, result| {
+                        assert!(result.is_ok());
+
+                        vec![TraceEvent::pins(
+                            parser.pins().iter().map(|(n, &o)| (n.as_str(), Some(o))),
+                        )]
+                    }
+                ),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn chunk_boundary_violation() {
+        let grammar = parse_ok! {
+            TestGrammar = {
+                foo_state:
+                    "foo" => foo.
+                    _ => other.
+            }
+        };
+
+        // NOTE: simulates parser that doesn't block input on partial sequences.
+        let run = |chunks: &[&[u8]]| {
+            chunks
+                .iter()
+                .flat_map(|c| interpreter_trace::<()>(&grammar, |_| (), &[c]))
+                .filter(|e| *e != TraceEvent::pins(vec![]))
+                .collect()
+        };
+
+        let err = ChunkInvarianceTest {
+            split_points: SplitPoints::Sample(0),
+            ..ChunkInvarianceTest::new(&grammar)
+        }
+        .check(b"afoo", run)
+        .unwrap_err();
+
+        assert_eq!(
+            err.chunks,
+            vec![b"a".to_vec(), b"f".to_vec(), b"o".to_vec(), b"o".to_vec()]
+        );
+        assert_eq!(err.index, 1);
+        assert_eq!(err.expected, Some(TraceEvent::action_call("foo", &[])));
+        assert_eq!(err.actual, Some(TraceEvent::action_call("other", &[])));
+    }
+}
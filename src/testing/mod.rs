@@ -0,0 +1,11 @@
+//! Utilities for testing generated parsers against the interpreter.
+
+mod differential;
+mod input;
+mod invariance;
+mod trace;
+
+pub use self::differential::*;
+pub use self::input::*;
+pub use self::invariance::*;
+pub use self::trace::*;
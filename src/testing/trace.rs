@@ -0,0 +1,325 @@
+use crate::interpreter::{ActionTable, ArgValue, Interpreter, RunError};
+use crate::{ActionCall, Grammar};
+use quote::ToTokens;
+use std::cell::RefCell;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::rc::Rc;
+use syn::Lit;
+
+/// Streaming interface that is shared by the generated parsers and the interpreter.
+///
+/// Generated parsers can implement it by forwarding the call to their own
+/// `run_parsing_loop` method.
+pub trait StreamingParser {
+    type Error;
+
+    fn run_parsing_loop(&mut self, input: &[u8], is_last: bool) -> Result<usize, Self::Error>;
+}
+
+impl<'g, 'a, E> StreamingParser for Interpreter<'g, 'a, E> {
+    type Error = RunError<E>;
+
+    fn run_parsing_loop(&mut self, input: &[u8], is_last: bool) -> Result<usize, Self::Error> {
+        Interpreter::run_parsing_loop(self, input, is_last)
+    }
+}
+
+/// Feeds chunks to the parser, prepending blocked bytes of the previous chunk to the next one.
+pub fn feed_chunks<P: StreamingParser>(parser: &mut P, chunks: &[&[u8]]) -> Result<(), P::Error> {
+    let mut input = vec![];
+
+    for (i, chunk) in chunks.iter().enumerate() {
+        input.extend_from_slice(chunk);
+
+        let blocked_byte_count = parser.run_parsing_loop(&input, i == chunks.len() - 1)?;
+
+        input.drain(..input.len() - blocked_byte_count);
+    }
+
+    if chunks.is_empty() {
+        parser.run_parsing_loop(&[], true)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceEvent {
+    ActionCall {
+        name: String,
+        args: Vec<String>,
+    },
+    Error {
+        state: String,
+        offset: usize,
+        byte: Option<u8>,
+    },
+    Token {
+        kind: String,
+        start: usize,
+        end: usize,
+    },
+    /// Pins that are set at the end of parsing, sorted by name.
+    Pins(Vec<(String, usize)>),
+}
+
+impl TraceEvent {
+    /// Creates action call event. Arguments are formatted with `Debug`, so generated
+    /// parsers' actions can record the event using their typed arguments.
+    pub fn action_call(name: &str, args: &[&dyn Debug]) -> Self {
+        TraceEvent::ActionCall {
+            name: name.into(),
+            args: args.iter().map(|a| format!("{:?}", a)).collect(),
+        }
+    }
+
+    pub fn error(state: &str, offset: usize, byte: Option<u8>) -> Self {
+        TraceEvent::Error {
+            state: state.into(),
+            offset,
+            byte,
+        }
+    }
+
+    /// Creates token event. Generated parsers' `on_token` can record the event
+    /// using the token's `name`, `start` and `end` methods.
+    pub fn token(kind: &str, start: usize, end: usize) -> Self {
+        TraceEvent::Token {
+            kind: kind.into(),
+            start,
+            end,
+        }
+    }
+
+    /// Creates pins event. Unset pins are skipped, so the event can be created
+    /// from both the generated `Pins` struct and the interpreter's pins map.
+    pub fn pins<'p>(pins: impl IntoIterator<Item = (&'p str, Option<usize>)>) -> Self {
+        let mut pins = pins
+            .into_iter()
+            .filter_map(|(name, offset)| offset.map(|o| (name.to_string(), o)))
+            .collect::<Vec<_>>();
+
+        pins.sort();
+
+        TraceEvent::Pins(pins)
+    }
+}
+
+impl Display for TraceEvent {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TraceEvent::ActionCall { name, args } => write!(f, "{}({})", name, args.join(", ")),
+            TraceEvent::Error {
+                state,
+                offset,
+                byte,
+            } => write!(f, "error in `{}` at {} ({:?})", state, offset, byte),
+            TraceEvent::Token { kind, start, end } => write!(f, "{}[{}..{}]", kind, start, end),
+            TraceEvent::Pins(pins) => {
+                let pins = pins
+                    .iter()
+                    .map(|(name, offset)| format!("{}: {}", name, offset))
+                    .collect::<Vec<_>>();
+
+                write!(f, "pins {{ {} }}", pins.join(", "))
+            }
+        }
+    }
+}
+
+pub type Trace = Vec<TraceEvent>;
+
+const PINNED_SLICE_MAX_LEN: usize = 16;
+
+pub(super) fn fmt_bytes(bytes: &[u8]) -> String {
+    format!("b\"{}\"", bytes.escape_ascii())
+}
+
+pub(super) fn fmt_chunks(chunks: &[Vec<u8>]) -> String {
+    let chunks = chunks.iter().map(|c| fmt_bytes(c)).collect::<Vec<_>>();
+
+    format!("[{}]", chunks.join(", "))
+}
+
+// NOTE: pins are printed with the slices of the input they point
+// to, so pin offset mismatches are easier to relate to the input.
+pub(super) fn fmt_event(event: &Option<TraceEvent>, input: &[u8]) -> String {
+    match event {
+        Some(TraceEvent::Pins(pins)) => {
+            let pins = pins
+                .iter()
+                .map(|&(ref name, offset)| {
+                    let start = offset.min(input.len());
+                    let end = (start + PINNED_SLICE_MAX_LEN).min(input.len());
+
+                    format!("{}: {} {}..", name, offset, fmt_bytes(&input[start..end]))
+                })
+                .collect::<Vec<_>>();
+
+            format!("pins {{ {} }}", pins.join(", "))
+        }
+        Some(event) => event.to_string(),
+        None => "<end of trace>".into(),
+    }
+}
+
+// NOTE: arguments are formatted the same way as `Debug` formats values of the
+// corresponding action parameter types. Expressions can't be evaluated, so
+// they are formatted as code (`setup` can override actions that use them).
+fn format_arg(arg: &ArgValue) -> String {
+    let lit = match arg {
+        ArgValue::Lit(lit) => lit,
+        ArgValue::Expr(expr) => return expr.into_token_stream().to_string(),
+        ArgValue::Byte(b) => return format!("{:?}", b),
+        ArgValue::Offset(o) => return format!("{:?}", o),
+        ArgValue::Pin(p) => return format!("{:?}", p),
+    };
+
+    match lit {
+        Lit::Str(s) => format!("{:?}", s.value()),
+        Lit::ByteStr(s) => format!("{:?}", s.value()),
+        Lit::Byte(b) => format!("{:?}", b.value()),
+        Lit::Char(c) => format!("{:?}", c.value()),
+        Lit::Int(i) => format!("{:?}", i.value()),
+        Lit::Float(f) => format!("{:?}", f.value()),
+        Lit::Bool(b) => format!("{:?}", b.value),
+        Lit::Verbatim(_) => lit.into_token_stream().to_string(),
+    }
+}
+
+/// Runs the interpreter on the chunks and records the trace of action calls,
+/// followed by the parsing error (if any) and the final pins.
+///
+/// Recording actions are added to the action table for each action used in the
+/// grammar. `setup` should add conditions and state getters required by the
+/// grammar; it can also override recording actions (e.g. to make them fallible).
+pub fn interpreter_trace<'a, E>(
+    grammar: &Grammar,
+    setup: impl FnOnce(&mut ActionTable<'a, E>),
+    chunks: &[&[u8]],
+) -> Trace {
+    let trace = Rc::new(RefCell::new(vec![]));
+    let mut actions = ActionTable::new();
+
+    let action_names = grammar
+        .states
+        .iter()
+        .flat_map(|s| s.arms.iter())
+        .flat_map(|a| a.rhs.directives())
+        .flat_map(|d| d.action_calls.iter())
+        .filter_map(|c| match c {
+            ActionCall::UserDefined { name, .. } => Some(name),
+            _ => None,
+        });
+
+    for name in action_names {
+        let trace = Rc::clone(&trace);
+        let event_name = name.clone();
+
+        actions.add_action(name, move |args| {
+            trace.borrow_mut().push(TraceEvent::ActionCall {
+                name: event_name.clone(),
+                args: args.iter().map(format_arg).collect(),
+            });
+
+            Ok(())
+        });
+    }
+
+    let token_trace = Rc::clone(&trace);
+
+    actions.set_token_sink(move |t| {
+        token_trace
+            .borrow_mut()
+            .push(TraceEvent::token(&t.kind, t.start, t.end));
+    });
+
+    setup(&mut actions);
+
+    let mut interpreter = Interpreter::new(grammar, actions).unwrap_or_else(|e| panic!("{}", e));
+    let result = feed_chunks(&mut interpreter, chunks);
+    let mut trace = trace.borrow().clone();
+
+    // NOTE: interpreter errors are caused by the action table
+    // (e.g. by `setup`), so they are not a part of the trace.
+    match result {
+        Err(RunError::Parsing(e)) => trace.push(TraceEvent::error(&e.state, e.offset, e.byte)),
+        Err(RunError::Interpreter(e)) => panic!("{} in `{}` at {}", e.error, e.state, e.offset),
+        Ok(()) => (),
+    }
+
+    trace.push(TraceEvent::pins(
+        interpreter
+            .pins()
+            .iter()
+            .map(|(name, &offset)| (name.as_str(), Some(offset))),
+    ));
+
+    trace
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    curry_parse_macros!($Grammar);
+
+    #[test]
+    fn record_interpreter_trace() {
+        let grammar = parse_ok! {
+            TestGrammar = {
+                foo_state:
+                    "foo" => foo("bar", 1, 'c', b'd', true, 2.5, b"ef"), @pin(start).
+                    'x' => bar?.
+                    _ => baz.
+            }
+        };
+
+        let trace = interpreter_trace(
+            &grammar,
+            |actions| {
+                actions.add_action("bar", |_| Err(()));
+            },
+            &[b"af", b"ooax"],
+        );
+
+        assert_eq!(
+            trace,
+            vec![
+                TraceEvent::action_call("baz", &[]),
+                TraceEvent::action_call(
+                    "foo",
+                    &[&"bar", &1, &'c', &b'd', &true, &2.5, &b"ef".to_vec()]
+                ),
+                TraceEvent::action_call("baz", &[]),
+                TraceEvent::error("foo_state", 5, Some(b'x')),
+                TraceEvent::pins(vec![("start", Some(1)), ("end", None)])
+            ]
+        );
+    }
+
+    #[test]
+    fn record_tokens() {
+        let grammar = parse_ok! {
+            TestGrammar = {
+                foo_state:
+                    '"' => @pin(start), --> str_state.
+                    _ => @emit(Other).
+
+                str_state:
+                    '"' => @emit(Str, start), --> foo_state.
+                    _ => .
+            }
+        };
+
+        assert_eq!(
+            interpreter_trace::<()>(&grammar, |_| (), &[b"a\"b", b"c\"d"]),
+            vec![
+                TraceEvent::token("Other", 0, 1),
+                TraceEvent::token("Str", 1, 5),
+                TraceEvent::token("Other", 5, 6),
+                TraceEvent::pins(vec![("start", Some(1))])
+            ]
+        );
+    }
+}
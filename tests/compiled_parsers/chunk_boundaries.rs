@@ -0,0 +1,115 @@
+use super::generated::grammar;
+use pilot::testing::*;
+
+#[derive(Default)]
+pub struct Recorder {
+    trace: Trace,
+}
+
+impl Recorder {
+    fn record(&mut self, name: &str, args: &[&dyn std::fmt::Debug]) {
+        self.trace.push(TraceEvent::action_call(name, args));
+    }
+}
+
+impl ChunkBoundaries::Actions for Recorder {
+    fn done(&mut self) {
+        self.record("done", &[]);
+    }
+
+    fn doctype(&mut self) {
+        self.record("doctype", &[]);
+    }
+
+    fn cdata(&mut self) {
+        self.record("cdata", &[]);
+    }
+
+    fn end_tag(&mut self, offset: usize) {
+        self.record("end_tag", &[&offset]);
+    }
+
+    fn lt(&mut self, byte: Option<u8>) {
+        self.record("lt", &[&byte]);
+    }
+
+    fn comment_eof(&mut self, comment: Option<&[u8]>) {
+        self.record("comment_eof", &[&comment]);
+    }
+
+    fn comment(&mut self, comment: Option<&[u8]>, offset: usize) {
+        self.record("comment", &[&comment, &offset]);
+    }
+
+    fn bogus_comment_end(&mut self, offset: usize) {
+        self.record("bogus_comment_end", &[&offset]);
+    }
+
+    fn tag_eof(&mut self) {
+        self.record("tag_eof", &[]);
+    }
+
+    fn tag(&mut self, tag: Option<&[u8]>, offset: usize) {
+        self.record("tag", &[&tag, &offset]);
+    }
+
+    fn self_closing_tag(&mut self, tag: Option<&[u8]>) {
+        self.record("self_closing_tag", &[&tag]);
+    }
+
+    fn double_space(&mut self) {
+        self.record("double_space", &[]);
+    }
+}
+
+include!("generated/chunk_boundaries.rs");
+
+struct Parser(ChunkBoundaries::Parser<Recorder>);
+
+impl StreamingParser for Parser {
+    type Error = ChunkBoundaries::ParsingError;
+
+    fn run_parsing_loop(&mut self, input: &[u8], is_last: bool) -> Result<usize, Self::Error> {
+        self.0.run_parsing_loop(input, is_last)
+    }
+}
+
+fn compiled_trace(chunks: &[&[u8]]) -> Trace {
+    let mut parser = Parser(ChunkBoundaries::Parser::new(Recorder::default()));
+
+    let result = feed_chunks(&mut parser, chunks);
+    let pins = *parser.0.pins();
+    let mut trace = parser.0.into_actions().trace;
+
+    if let Err(e) = result {
+        trace.push(TraceEvent::error(e.state, e.offset, e.byte));
+    }
+
+    trace.push(TraceEvent::pins(vec![
+        ("comment", pins.comment),
+        ("doctype", pins.doctype),
+        ("tag", pins.tag),
+    ]));
+
+    trace
+}
+
+#[test]
+fn sequences_and_pins() {
+    let grammar = grammar("chunk_boundaries");
+    let interpreted = |chunks: &[&[u8]]| interpreter_trace::<()>(&grammar, |_| (), chunks);
+
+    let mut test = DifferentialTest::new(&grammar);
+
+    test.iterations = 3000;
+    test.max_input_len = 128;
+
+    test.run(compiled_trace, interpreted)
+        .unwrap_or_else(|e| panic!("{}", e));
+
+    let input = b"a<!DocType x><![CDATA[<b\t\t/><!-- c --!><!--d--></e><f>";
+
+    ChunkInvarianceTest::new(&grammar)
+        .check(input, compiled_trace)
+        .unwrap_or_else(|e| panic!("{}", e));
+}
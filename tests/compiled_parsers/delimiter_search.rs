@@ -0,0 +1,96 @@
+include!("generated/delimiter_search.rs");
+
+const FILLER: u8 = b'a';
+const DELIMITERS: &[u8] = b"<&\0";
+
+fn naive_search(haystack: &[u8], delimiters: &[u8]) -> usize {
+    haystack
+        .iter()
+        .position(|b| delimiters.contains(b))
+        .unwrap_or(haystack.len())
+}
+
+/// Checks the search on the haystacks of lengths `0..=80`, that cover the vector
+/// blocks and the tails of all the kernels, with a delimiter at every position.
+fn check_kernel(name: &str, search: impl Fn(&[u8], &[u8]) -> usize) {
+    for delimiter_count in 1..=DELIMITERS.len() {
+        let delimiters = &DELIMITERS[..delimiter_count];
+
+        for len in 0..=80 {
+            let mut haystack = vec![FILLER; len];
+
+            assert_eq!(
+                search(&haystack, delimiters),
+                len,
+                "{}: no delimiters",
+                name
+            );
+
+            for pos in 0..len {
+                for &delimiter in delimiters {
+                    haystack[pos] = delimiter;
+
+                    assert_eq!(
+                        search(&haystack, delimiters),
+                        naive_search(&haystack, delimiters),
+                        "{}: {:?} at {} of {}",
+                        name,
+                        delimiter as char,
+                        pos,
+                        len
+                    );
+
+                    // NOTE: the second delimiter after the first one shouldn't
+                    // affect the result.
+                    if pos + 1 < len {
+                        haystack[pos + 1] = delimiters[0];
+
+                        assert_eq!(
+                            search(&haystack, delimiters),
+                            pos,
+                            "{}: {:?}",
+                            name,
+                            haystack
+                        );
+
+                        haystack[pos + 1] = FILLER;
+                    }
+
+                    haystack[pos] = FILLER;
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn dispatch() {
+    check_kernel("dispatch", __find_delimiter);
+}
+
+#[test]
+fn swar() {
+    check_kernel("swar", __find_delimiter_swar);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn sse2() {
+    check_kernel("sse2", |h, d| unsafe { __find_delimiter_sse2(h, d) });
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn avx2() {
+    if is_x86_feature_detected!("avx2") {
+        check_kernel("avx2", |h, d| unsafe { __find_delimiter_avx2(h, d) });
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[test]
+fn neon() {
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        check_kernel("neon", |h, d| unsafe { __find_delimiter_neon(h, d) });
+    }
+}
@@ -0,0 +1,130 @@
+use pilot::{Compile, Grammar};
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+const UPDATE_VAR: &str = "UPDATE_GENERATED";
+
+/// Grammars of the checked-in parsers, their code is in `generated/<name>.rs`.
+const GRAMMARS: &[&str] = &[
+    "trivial_states",
+    "chunk_boundaries",
+    "table_dispatch",
+    "utf8",
+    "tokens",
+];
+
+/// Grammar which parser provides the checked-in delimiter search functions.
+const DELIMITER_SEARCH_GRAMMAR: &str = "chunk_boundaries";
+
+fn test_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/compiled_parsers")
+}
+
+pub fn grammar(name: &str) -> Grammar {
+    let path = test_dir().join("grammars").join(format!("{}.txt", name));
+    let src = fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+
+    syn::parse_str(&src).unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+}
+
+fn is_ident(token: &TokenTree, name: &str) -> bool {
+    matches!(token, TokenTree::Ident(i) if i == name)
+}
+
+fn is_attr(pound: &TokenTree, body: &TokenTree) -> bool {
+    matches!(pound, TokenTree::Punct(p) if p.as_char() == '#')
+        && matches!(body, TokenTree::Group(g) if g.delimiter() == Delimiter::Bracket)
+}
+
+// NOTE: delimiter search functions are the last items of the parser module,
+// so they are the module's tokens starting from the `__find_delimiter`
+// function's attributes.
+fn extract_delimiter_search(parser: TokenStream) -> TokenStream {
+    let module = parser
+        .into_iter()
+        .find_map(|t| match t {
+            TokenTree::Group(g) if g.delimiter() == Delimiter::Brace => Some(g.stream()),
+            _ => None,
+        })
+        .expect("parser module body is not found");
+
+    let tokens = module.into_iter().collect::<Vec<_>>();
+
+    let mut start = tokens
+        .windows(2)
+        .position(|w| is_ident(&w[0], "fn") && is_ident(&w[1], "__find_delimiter"))
+        .expect("`__find_delimiter` is not generated");
+
+    while start >= 2 && is_attr(&tokens[start - 2], &tokens[start - 1]) {
+        start -= 2;
+    }
+
+    tokens[start..].iter().cloned().collect()
+}
+
+// NOTE: with the default width rustfmt gives up on the deeply nested matches.
+fn rustfmt(code: TokenStream) -> String {
+    let mut rustfmt = Command::new("rustfmt")
+        .args(["--edition", "2018", "--emit", "stdout"])
+        .args(["--config", "max_width=120"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to run rustfmt");
+
+    rustfmt
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(code.to_string().as_bytes())
+        .unwrap();
+
+    let output = rustfmt.wait_with_output().unwrap();
+
+    assert!(output.status.success(), "rustfmt failed");
+
+    String::from_utf8(output.stdout).unwrap()
+}
+
+// NOTE: the generated code is compared after formatting, since rustfmt
+// changes the tokens (e.g. adds trailing commas and removes braces).
+fn check(name: &str, code: TokenStream) {
+    let path = test_dir().join("generated").join(format!("{}.rs", name));
+
+    let code = format!(
+        "// @generated by the `generated_code_is_up_to_date` test, run it with `{}=1` to update.\n\n{}",
+        UPDATE_VAR,
+        rustfmt(code)
+    );
+
+    if env::var_os(UPDATE_VAR).is_some() {
+        return fs::write(&path, code).unwrap();
+    }
+
+    assert!(
+        fs::read_to_string(&path).unwrap() == code,
+        "{} is outdated, run the tests with `{}=1` to update it",
+        path.display(),
+        UPDATE_VAR
+    );
+}
+
+// NOTE: parsers are checked in without the tracer hooks, so
+// they are not compared when the `tracer` feature is enabled.
+#[test]
+#[cfg_attr(feature = "tracer", ignore)]
+fn generated_code_is_up_to_date() {
+    for name in GRAMMARS {
+        let code = grammar(name).compile();
+
+        if *name == DELIMITER_SEARCH_GRAMMAR {
+            check("delimiter_search", extract_delimiter_search(code.clone()));
+        }
+
+        check(name, code);
+    }
+}
@@ -0,0 +1,107 @@
+// @generated by the `generated_code_is_up_to_date` test, run it with `UPDATE_GENERATED=1` to update.
+
+#[doc = r" Returns the position of the first delimiter in the haystack (or the haystack's"]
+#[doc = r" length if there are no delimiters), using the widest SIMD instruction set"]
+#[doc = r" that is available on the CPU."]
+#[allow(unreachable_code)]
+fn __find_delimiter(haystack: &[u8], delimiters: &[u8]) -> usize {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return unsafe { __find_delimiter_avx2(haystack, delimiters) };
+        }
+        return unsafe { __find_delimiter_sse2(haystack, delimiters) };
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            return unsafe { __find_delimiter_neon(haystack, delimiters) };
+        }
+    }
+    __find_delimiter_swar(haystack, delimiters)
+}
+fn __find_delimiter_swar(haystack: &[u8], delimiters: &[u8]) -> usize {
+    const LO: u64 = 0x0101_0101_0101_0101;
+    const HI: u64 = 0x8080_8080_8080_8080;
+    let has_delimiter = |word: u64| {
+        delimiters.iter().any(|&d| {
+            let x = word ^ (LO * d as u64);
+            x.wrapping_sub(LO) & !x & HI != 0
+        })
+    };
+    let mut pos = 0;
+    while pos + 8 <= haystack.len() {
+        let mut word = [0; 8];
+        word.copy_from_slice(&haystack[pos..pos + 8]);
+        if has_delimiter(u64::from_le_bytes(word)) {
+            break;
+        }
+        pos += 8;
+    }
+    while pos < haystack.len() && !delimiters.contains(&haystack[pos]) {
+        pos += 1;
+    }
+    pos
+}
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse2")]
+unsafe fn __find_delimiter_sse2(haystack: &[u8], delimiters: &[u8]) -> usize {
+    use std::arch::x86_64::*;
+    let mut pos = 0;
+    unsafe {
+        while pos + 16 <= haystack.len() {
+            let chunk = _mm_loadu_si128(haystack.as_ptr().add(pos) as *const __m128i);
+            let mut eq = _mm_setzero_si128();
+            for &d in delimiters {
+                eq = _mm_or_si128(eq, _mm_cmpeq_epi8(chunk, _mm_set1_epi8(d as i8)));
+            }
+            let mask = _mm_movemask_epi8(eq) as u32;
+            if mask != 0 {
+                return pos + mask.trailing_zeros() as usize;
+            }
+            pos += 16;
+        }
+    }
+    pos + __find_delimiter_swar(&haystack[pos..], delimiters)
+}
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn __find_delimiter_avx2(haystack: &[u8], delimiters: &[u8]) -> usize {
+    use std::arch::x86_64::*;
+    let mut pos = 0;
+    unsafe {
+        while pos + 32 <= haystack.len() {
+            let chunk = _mm256_loadu_si256(haystack.as_ptr().add(pos) as *const __m256i);
+            let mut eq = _mm256_setzero_si256();
+            for &d in delimiters {
+                eq = _mm256_or_si256(eq, _mm256_cmpeq_epi8(chunk, _mm256_set1_epi8(d as i8)));
+            }
+            let mask = _mm256_movemask_epi8(eq) as u32;
+            if mask != 0 {
+                return pos + mask.trailing_zeros() as usize;
+            }
+            pos += 32;
+        }
+        pos + __find_delimiter_sse2(&haystack[pos..], delimiters)
+    }
+}
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon")]
+unsafe fn __find_delimiter_neon(haystack: &[u8], delimiters: &[u8]) -> usize {
+    use std::arch::aarch64::*;
+    let mut pos = 0;
+    unsafe {
+        while pos + 16 <= haystack.len() {
+            let chunk = vld1q_u8(haystack.as_ptr().add(pos));
+            let mut eq = vdupq_n_u8(0);
+            for &d in delimiters {
+                eq = vorrq_u8(eq, vceqq_u8(chunk, vdupq_n_u8(d)));
+            }
+            if vmaxvq_u8(eq) != 0 {
+                break;
+            }
+            pos += 16;
+        }
+    }
+    pos + __find_delimiter_swar(&haystack[pos..], delimiters)
+}
@@ -0,0 +1,661 @@
+// @generated by the `generated_code_is_up_to_date` test, run it with `UPDATE_GENERATED=1` to update.
+
+#[allow(
+    non_snake_case,
+    non_camel_case_types,
+    dead_code,
+    unused_imports,
+    unused_parens,
+    unreachable_patterns,
+    clippy::all
+)]
+pub mod TableDispatch {
+    use super::*;
+    use std::ops::{Deref, DerefMut};
+    pub type Error = ::std::convert::Infallible;
+    #[derive(Debug)]
+    pub struct ParsingError {
+        pub state: &'static str,
+        pub offset: usize,
+        pub byte: Option<u8>,
+        pub error: Error,
+    }
+    impl std::fmt::Display for ParsingError {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(f, "parsing error in `{}` at offset {} ", self.state, self.offset)?;
+            match self.byte {
+                Some(b) => write!(f, "(byte 0x{:02x})", b)?,
+                None => write!(f, "(end of input)")?,
+            }
+            write!(f, ": {:?}", self.error)
+        }
+    }
+    impl std::error::Error for ParsingError {}
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum State {
+        data_state,
+        word_state,
+        number_state,
+        tag_state,
+    }
+    impl State {
+        pub fn name(self) -> &'static str {
+            match self {
+                State::data_state => "data_state",
+                State::word_state => "word_state",
+                State::number_state => "number_state",
+                State::tag_state => "tag_state",
+            }
+        }
+    }
+    pub trait Actions {
+        fn done(&mut self);
+        fn data_enter(&mut self);
+        fn number_start(&mut self, arg0: Option<u8>);
+        fn sign(&mut self, arg0: Option<u8>);
+        fn control(&mut self, arg0: Option<u8>, arg1: usize);
+        fn lt(&mut self);
+        fn lead_byte(&mut self, arg0: Option<u8>);
+        fn non_ascii(&mut self);
+        fn amp(&mut self, arg0: usize);
+        fn other(&mut self, arg0: Option<u8>);
+        fn word_eof(&mut self, arg0: usize);
+        fn underscore(&mut self);
+        fn word_end(&mut self, arg0: usize);
+        fn number_eof(&mut self);
+        fn dot(&mut self, arg0: usize);
+        fn hex_prefix(&mut self, arg0: Option<u8>);
+        fn exponent_sign(&mut self, arg0: Option<u8>);
+        fn number_end(&mut self);
+        fn tag_eof(&mut self);
+        fn gt(&mut self);
+        fn tag_mask(&mut self, arg0: Option<u8>);
+    }
+    #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+    pub struct Pins {
+        pub word: Option<usize>,
+    }
+    enum SequenceMatch {
+        Full,
+        Partial,
+        Mismatch,
+    }
+    pub struct Parser<A: Actions> {
+        actions: A,
+        __pins: Pins,
+        __state: State,
+        __state_enter: bool,
+        __is_last_input: bool,
+        __pos: usize,
+        __chunk_offset: usize,
+    }
+    impl<A: Actions> Deref for Parser<A> {
+        type Target = A;
+        fn deref(&self) -> &A {
+            &self.actions
+        }
+    }
+    impl<A: Actions> DerefMut for Parser<A> {
+        fn deref_mut(&mut self) -> &mut A {
+            &mut self.actions
+        }
+    }
+    impl<A: Actions> Parser<A> {
+        pub fn new(actions: A) -> Self {
+            Parser {
+                actions,
+                __pins: Pins::default(),
+                __state: State::data_state,
+                __state_enter: true,
+                __is_last_input: false,
+                __pos: 0,
+                __chunk_offset: 0,
+            }
+        }
+        pub fn state(&self) -> State {
+            self.__state
+        }
+        pub fn pins(&self) -> &Pins {
+            &self.__pins
+        }
+        pub fn into_actions(self) -> A {
+            self.actions
+        }
+        #[doc = r" Parses the chunk of input and returns the number of bytes at the"]
+        #[doc = r" end of the chunk that were not consumed or are pinned. These bytes"]
+        #[doc = r" should be prepended to the next chunk."]
+        pub fn run_parsing_loop(&mut self, input: &[u8], is_last: bool) -> Result<usize, ParsingError> {
+            self.__start_chunk(is_last);
+            let blocked_byte_count = self.__run(input)?;
+            Ok(self.__end_chunk(input, blocked_byte_count))
+        }
+        fn __start_chunk(&mut self, is_last: bool) {
+            self.__is_last_input = is_last;
+        }
+        fn __end_chunk(&mut self, input: &[u8], blocked_byte_count: usize) -> usize {
+            let consumed_byte_count = input.len() - blocked_byte_count;
+            let released_byte_count = consumed_byte_count;
+            self.__chunk_offset += released_byte_count;
+            self.__pos = consumed_byte_count - released_byte_count;
+            let blocked_byte_count = input.len() - released_byte_count;
+            blocked_byte_count
+        }
+        fn __run(&mut self, input: &[u8]) -> Result<usize, ParsingError> {
+            loop {
+                let ch = input.get(self.__pos).cloned();
+                match self.__state {
+                    State::data_state => {
+                        if self.__state_enter {
+                            self.__state_enter = false;
+                            self.data_enter();
+                        }
+                        const ARM_TABLE: [u8; 256] = [
+                            7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8,
+                            7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 7u8, 12u8, 12u8, 12u8,
+                            12u8, 12u8, 12u8, 11u8, 12u8, 12u8, 12u8, 12u8, 6u8, 12u8, 6u8, 12u8, 12u8, 5u8, 5u8, 5u8,
+                            5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 12u8, 12u8, 8u8, 12u8, 12u8, 12u8, 12u8, 4u8, 4u8, 4u8,
+                            4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8,
+                            4u8, 4u8, 4u8, 4u8, 4u8, 12u8, 12u8, 12u8, 12u8, 12u8, 12u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8,
+                            4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8, 4u8,
+                            4u8, 4u8, 12u8, 12u8, 12u8, 12u8, 12u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8,
+                            10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8,
+                            10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8,
+                            10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8,
+                            10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 9u8, 9u8, 9u8, 9u8, 9u8,
+                            9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8,
+                            9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 9u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8,
+                            10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8,
+                            10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8, 10u8,
+                        ];
+                        match ch {
+                            Some(b) => match ARM_TABLE[b as usize] {
+                                4u8 => {
+                                    self.__pins.word = Some((self.__chunk_offset + self.__pos));
+                                    self.__state = State::word_state;
+                                    self.__state_enter = true;
+                                    self.__pos += 1;
+                                }
+                                5u8 => {
+                                    {
+                                        let arg0 = ch;
+                                        self.number_start(arg0)
+                                    };
+                                    self.__state = State::number_state;
+                                    self.__state_enter = true;
+                                    self.__pos += 1;
+                                }
+                                6u8 => {
+                                    {
+                                        let arg0 = ch;
+                                        self.sign(arg0)
+                                    };
+                                    self.__pos += 1;
+                                }
+                                7u8 => {
+                                    {
+                                        let arg0 = ch;
+                                        let arg1 = (self.__chunk_offset + self.__pos);
+                                        self.control(arg0, arg1)
+                                    };
+                                    self.__pos += 1;
+                                }
+                                8u8 => {
+                                    self.lt();
+                                    self.__state = State::tag_state;
+                                    self.__state_enter = true;
+                                    self.__pos += 1;
+                                }
+                                9u8 => {
+                                    {
+                                        let arg0 = ch;
+                                        self.lead_byte(arg0)
+                                    };
+                                    self.__pos += 1;
+                                }
+                                10u8 => {
+                                    self.non_ascii();
+                                    self.__pos += 1;
+                                }
+                                11u8 => {
+                                    {
+                                        let arg0 = (self.__chunk_offset + self.__pos);
+                                        self.amp(arg0)
+                                    };
+                                    self.__pos += 1;
+                                }
+                                12u8 => {
+                                    {
+                                        let arg0 = ch;
+                                        self.other(arg0)
+                                    };
+                                    self.__pos += 1;
+                                }
+                                _ => {
+                                    self.__pos += 1;
+                                }
+                            },
+                            None if !self.__is_last_input => {
+                                return Ok(0);
+                            }
+                            None => {
+                                self.done();
+                                return Ok(0);
+                            }
+                            None => {
+                                return Ok(0);
+                            }
+                        }
+                    }
+                    State::word_state => {
+                        let skipped = {
+                            const SKIPPABLE: [bool; 256] = [
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                true, true, true, true, true, true, true, true, true, true, false, false, false, false,
+                                false, false, false, true, true, true, true, true, true, true, true, true, true, true,
+                                true, true, true, true, true, true, true, true, true, true, true, true, true, true,
+                                true, false, false, false, false, false, false, true, true, true, true, true, true,
+                                true, true, true, true, true, true, true, true, true, true, true, true, true, true,
+                                true, true, true, true, true, true, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false,
+                            ];
+                            self.__skip_by_table(input, &SKIPPABLE)
+                        };
+                        if skipped > 0 {
+                            self.__pos += skipped;
+                            continue;
+                        }
+                        match ch {
+                            None if !self.__is_last_input => {
+                                return Ok(0);
+                            }
+                            None => {
+                                {
+                                    let arg0 = (self.__chunk_offset + self.__pos);
+                                    self.word_eof(arg0)
+                                };
+                                return Ok(0);
+                            }
+                            Some(b'a'..=b'z') | Some(b'A'..=b'Z') | Some(b'0'..=b'9') => {
+                                self.__pos += 1;
+                            }
+                            Some(95u8) => {
+                                self.underscore();
+                                self.__pos += 1;
+                            }
+                            Some(_) => {
+                                {
+                                    let arg0 = (self.__chunk_offset + self.__pos);
+                                    self.word_end(arg0)
+                                };
+                                self.__state = State::data_state;
+                                self.__state_enter = true;
+                            }
+                            Some(_) => {
+                                self.__pos += 1;
+                            }
+                            None => {
+                                return Ok(0);
+                            }
+                        }
+                    }
+                    State::number_state => {
+                        let skipped = {
+                            const SKIPPABLE: [bool; 256] = [
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                true, true, true, true, true, true, true, true, true, true, false, false, false, false,
+                                false, false, false, true, true, true, true, true, true, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, true, false, true, true, true,
+                                true, true, true, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false,
+                            ];
+                            self.__skip_by_table(input, &SKIPPABLE)
+                        };
+                        if skipped > 0 {
+                            self.__pos += skipped;
+                            continue;
+                        }
+                        const ARM_TABLE: [u8; 256] = [
+                            8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8,
+                            8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8,
+                            8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 7u8, 8u8, 7u8, 4u8, 8u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8,
+                            3u8, 3u8, 3u8, 3u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 8u8,
+                            8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 6u8, 8u8,
+                            8u8, 8u8, 8u8, 8u8, 8u8, 5u8, 8u8, 3u8, 3u8, 3u8, 3u8, 3u8, 3u8, 8u8, 8u8, 8u8, 8u8, 8u8,
+                            8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 6u8, 8u8, 8u8, 8u8, 8u8, 8u8,
+                            8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8,
+                            8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8,
+                            8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8,
+                            8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8,
+                            8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8,
+                            8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8,
+                            8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8, 8u8,
+                            8u8, 8u8, 8u8, 8u8,
+                        ];
+                        match ch {
+                            Some(b) => match ARM_TABLE[b as usize] {
+                                3u8 => {
+                                    self.__pos += 1;
+                                }
+                                4u8 => {
+                                    {
+                                        let arg0 = (self.__chunk_offset + self.__pos);
+                                        self.dot(arg0)
+                                    };
+                                    self.__pos += 1;
+                                }
+                                5u8 => {
+                                    self.__pos += 1;
+                                }
+                                6u8 => {
+                                    {
+                                        let arg0 = ch;
+                                        self.hex_prefix(arg0)
+                                    };
+                                    self.__pos += 1;
+                                }
+                                7u8 => {
+                                    {
+                                        let arg0 = ch;
+                                        self.exponent_sign(arg0)
+                                    };
+                                    self.__pos += 1;
+                                }
+                                8u8 => {
+                                    self.number_end();
+                                    self.__state = State::data_state;
+                                    self.__state_enter = true;
+                                }
+                                _ => {
+                                    self.__pos += 1;
+                                }
+                            },
+                            None if !self.__is_last_input => {
+                                return Ok(0);
+                            }
+                            None => {
+                                self.number_eof();
+                                return Ok(0);
+                            }
+                            None => {
+                                return Ok(0);
+                            }
+                        }
+                    }
+                    State::tag_state => {
+                        let skipped = {
+                            const SKIPPABLE: [bool; 256] = [
+                                true, true, true, true, true, true, true, true, true, true, false, true, true, true,
+                                true, true, true, true, true, true, true, true, true, true, true, true, false, true,
+                                true, true, true, true, true, true, true, true, true, true, true, true, true, true,
+                                false, true, true, true, true, true, true, true, true, true, true, true, true, true,
+                                true, true, false, true, true, true, false, true, true, true, true, true, true, true,
+                                true, true, true, true, false, true, true, true, true, true, true, true, true, true,
+                                true, true, true, true, true, true, false, true, true, true, true, true, true, true,
+                                true, true, true, true, true, true, true, true, false, true, true, true, true, true,
+                                true, true, true, true, true, true, true, true, true, true, false, true, true, true,
+                                true, true, true, true, true, true, true, true, true, true, true, true, false, true,
+                                true, true, true, true, true, true, true, true, true, true, true, true, true, true,
+                                false, true, true, true, true, true, true, true, true, true, true, true, true, true,
+                                true, true, false, true, true, true, true, true, true, true, true, true, true, true,
+                                true, true, true, true, false, true, true, true, true, true, true, true, true, true,
+                                true, true, true, true, true, true, false, true, true, true, true, true, true, true,
+                                true, true, true, true, true, true, true, true, false, true, true, true, true, true,
+                                true, true, true, true, true, true, true, true, true, true, false, true, true, true,
+                                true, true, true, true, true, true, true, true, true, true, true, true, false, true,
+                                true, true, true, true,
+                            ];
+                            self.__skip_by_table(input, &SKIPPABLE)
+                        };
+                        if skipped > 0 {
+                            self.__pos += skipped;
+                            continue;
+                        }
+                        const ARM_TABLE: [u8; 256] = [
+                            5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 4u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                            5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 4u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                            5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 4u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                            5u8, 5u8, 5u8, 5u8, 4u8, 5u8, 5u8, 5u8, 3u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                            5u8, 5u8, 4u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                            4u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 4u8, 5u8,
+                            5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 4u8, 5u8, 5u8, 5u8,
+                            5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 4u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                            5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 4u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                            5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 4u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                            5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 4u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                            5u8, 5u8, 5u8, 5u8, 4u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                            5u8, 5u8, 4u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8,
+                            4u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 5u8, 4u8, 5u8,
+                            5u8, 5u8, 5u8, 5u8,
+                        ];
+                        match ch {
+                            Some(b) => match ARM_TABLE[b as usize] {
+                                3u8 => {
+                                    self.gt();
+                                    self.__state = State::data_state;
+                                    self.__state_enter = true;
+                                    self.__pos += 1;
+                                }
+                                4u8 => {
+                                    {
+                                        let arg0 = ch;
+                                        self.tag_mask(arg0)
+                                    };
+                                    self.__pos += 1;
+                                }
+                                5u8 => {
+                                    self.__pos += 1;
+                                }
+                                _ => {
+                                    self.__pos += 1;
+                                }
+                            },
+                            None if !self.__is_last_input => {
+                                return Ok(0);
+                            }
+                            None => {
+                                self.tag_eof();
+                                return Ok(0);
+                            }
+                            None => {
+                                return Ok(0);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        fn __match_sequence(&self, input: &[u8], sequence: &[u8], ignore_case: bool) -> SequenceMatch {
+            let available = &input[self.__pos..];
+            let len = available.len().min(sequence.len());
+            let matches = if ignore_case {
+                available[..len].eq_ignore_ascii_case(&sequence[..len])
+            } else {
+                available[..len] == sequence[..len]
+            };
+            if !matches {
+                SequenceMatch::Mismatch
+            } else if len == sequence.len() {
+                SequenceMatch::Full
+            } else if self.__is_last_input {
+                SequenceMatch::Mismatch
+            } else {
+                SequenceMatch::Partial
+            }
+        }
+        fn __match_sequence_with(
+            &self,
+            input: &[u8],
+            len: usize,
+            element_matches: impl Fn(usize, u8) -> bool,
+        ) -> SequenceMatch {
+            let available = &input[self.__pos..];
+            let matches = available
+                .iter()
+                .take(len)
+                .enumerate()
+                .all(|(i, &b)| element_matches(i, b));
+            if !matches {
+                SequenceMatch::Mismatch
+            } else if available.len() >= len {
+                SequenceMatch::Full
+            } else if self.__is_last_input {
+                SequenceMatch::Mismatch
+            } else {
+                SequenceMatch::Partial
+            }
+        }
+        fn __error(&self, error: Error, state: &'static str, byte: Option<u8>) -> ParsingError {
+            ParsingError {
+                state,
+                offset: self.__chunk_offset + self.__pos,
+                byte,
+                error,
+            }
+        }
+        fn __pin_slice<'i>(&self, input: &'i [u8], pin: Option<usize>) -> Option<&'i [u8]> {
+            pin.map(|p| &input[p - self.__chunk_offset..self.__pos])
+        }
+        fn __skip_until(&self, input: &[u8], delimiters: &[u8]) -> usize {
+            __find_delimiter(&input[self.__pos..], delimiters)
+        }
+        fn __skip_by_table(&self, input: &[u8], skippable: &[bool; 256]) -> usize {
+            input[self.__pos..]
+                .iter()
+                .take_while(|&&b| skippable[b as usize])
+                .count()
+        }
+    }
+    #[doc = r" Returns the position of the first delimiter in the haystack (or the haystack's"]
+    #[doc = r" length if there are no delimiters), using the widest SIMD instruction set"]
+    #[doc = r" that is available on the CPU."]
+    #[allow(unreachable_code)]
+    fn __find_delimiter(haystack: &[u8], delimiters: &[u8]) -> usize {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx2") {
+                return unsafe { __find_delimiter_avx2(haystack, delimiters) };
+            }
+            return unsafe { __find_delimiter_sse2(haystack, delimiters) };
+        }
+        #[cfg(target_arch = "aarch64")]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") {
+                return unsafe { __find_delimiter_neon(haystack, delimiters) };
+            }
+        }
+        __find_delimiter_swar(haystack, delimiters)
+    }
+    fn __find_delimiter_swar(haystack: &[u8], delimiters: &[u8]) -> usize {
+        const LO: u64 = 0x0101_0101_0101_0101;
+        const HI: u64 = 0x8080_8080_8080_8080;
+        let has_delimiter = |word: u64| {
+            delimiters.iter().any(|&d| {
+                let x = word ^ (LO * d as u64);
+                x.wrapping_sub(LO) & !x & HI != 0
+            })
+        };
+        let mut pos = 0;
+        while pos + 8 <= haystack.len() {
+            let mut word = [0; 8];
+            word.copy_from_slice(&haystack[pos..pos + 8]);
+            if has_delimiter(u64::from_le_bytes(word)) {
+                break;
+            }
+            pos += 8;
+        }
+        while pos < haystack.len() && !delimiters.contains(&haystack[pos]) {
+            pos += 1;
+        }
+        pos
+    }
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "sse2")]
+    unsafe fn __find_delimiter_sse2(haystack: &[u8], delimiters: &[u8]) -> usize {
+        use std::arch::x86_64::*;
+        let mut pos = 0;
+        unsafe {
+            while pos + 16 <= haystack.len() {
+                let chunk = _mm_loadu_si128(haystack.as_ptr().add(pos) as *const __m128i);
+                let mut eq = _mm_setzero_si128();
+                for &d in delimiters {
+                    eq = _mm_or_si128(eq, _mm_cmpeq_epi8(chunk, _mm_set1_epi8(d as i8)));
+                }
+                let mask = _mm_movemask_epi8(eq) as u32;
+                if mask != 0 {
+                    return pos + mask.trailing_zeros() as usize;
+                }
+                pos += 16;
+            }
+        }
+        pos + __find_delimiter_swar(&haystack[pos..], delimiters)
+    }
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "avx2")]
+    unsafe fn __find_delimiter_avx2(haystack: &[u8], delimiters: &[u8]) -> usize {
+        use std::arch::x86_64::*;
+        let mut pos = 0;
+        unsafe {
+            while pos + 32 <= haystack.len() {
+                let chunk = _mm256_loadu_si256(haystack.as_ptr().add(pos) as *const __m256i);
+                let mut eq = _mm256_setzero_si256();
+                for &d in delimiters {
+                    eq = _mm256_or_si256(eq, _mm256_cmpeq_epi8(chunk, _mm256_set1_epi8(d as i8)));
+                }
+                let mask = _mm256_movemask_epi8(eq) as u32;
+                if mask != 0 {
+                    return pos + mask.trailing_zeros() as usize;
+                }
+                pos += 32;
+            }
+            pos + __find_delimiter_sse2(&haystack[pos..], delimiters)
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    #[target_feature(enable = "neon")]
+    unsafe fn __find_delimiter_neon(haystack: &[u8], delimiters: &[u8]) -> usize {
+        use std::arch::aarch64::*;
+        let mut pos = 0;
+        unsafe {
+            while pos + 16 <= haystack.len() {
+                let chunk = vld1q_u8(haystack.as_ptr().add(pos));
+                let mut eq = vdupq_n_u8(0);
+                for &d in delimiters {
+                    eq = vorrq_u8(eq, vceqq_u8(chunk, vdupq_n_u8(d)));
+                }
+                if vmaxvq_u8(eq) != 0 {
+                    break;
+                }
+                pos += 16;
+            }
+        }
+        pos + __find_delimiter_swar(&haystack[pos..], delimiters)
+    }
+}
@@ -0,0 +1,606 @@
+// @generated by the `generated_code_is_up_to_date` test, run it with `UPDATE_GENERATED=1` to update.
+
+#[allow(
+    non_snake_case,
+    non_camel_case_types,
+    dead_code,
+    unused_imports,
+    unused_parens,
+    unreachable_patterns,
+    clippy::all
+)]
+pub mod Tokenizer {
+    use super::*;
+    use std::ops::{Deref, DerefMut};
+    pub type Error = ::std::convert::Infallible;
+    #[derive(Debug)]
+    pub struct ParsingError {
+        pub state: &'static str,
+        pub offset: usize,
+        pub byte: Option<u8>,
+        pub error: Error,
+    }
+    impl std::fmt::Display for ParsingError {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(f, "parsing error in `{}` at offset {} ", self.state, self.offset)?;
+            match self.byte {
+                Some(b) => write!(f, "(byte 0x{:02x})", b)?,
+                None => write!(f, "(end of input)")?,
+            }
+            write!(f, ": {:?}", self.error)
+        }
+    }
+    impl std::error::Error for ParsingError {}
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum State {
+        data_state,
+        comment_state,
+        tag_state,
+    }
+    impl State {
+        pub fn name(self) -> &'static str {
+            match self {
+                State::data_state => "data_state",
+                State::comment_state => "comment_state",
+                State::tag_state => "tag_state",
+            }
+        }
+    }
+    pub trait Actions {
+        fn done(&mut self);
+        fn on_token(&mut self, token: Token);
+    }
+    #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+    pub struct Pins {
+        pub start: Option<usize>,
+    }
+    #[doc = r" Token emitted by the parser. Offsets are counted from the start of the input."]
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum Token {
+        BadTagChar { start: usize, end: usize },
+        Char { start: usize, end: usize },
+        Comment { start: usize, end: usize },
+        EofInTag { start: usize, end: usize },
+        Space { start: usize, end: usize },
+        Tag { start: usize, end: usize },
+    }
+    impl Token {
+        pub fn name(self) -> &'static str {
+            match self {
+                Token::BadTagChar { .. } => "BadTagChar",
+                Token::Char { .. } => "Char",
+                Token::Comment { .. } => "Comment",
+                Token::EofInTag { .. } => "EofInTag",
+                Token::Space { .. } => "Space",
+                Token::Tag { .. } => "Tag",
+            }
+        }
+        pub fn start(self) -> usize {
+            match self {
+                Token::BadTagChar { start, .. }
+                | Token::Char { start, .. }
+                | Token::Comment { start, .. }
+                | Token::EofInTag { start, .. }
+                | Token::Space { start, .. }
+                | Token::Tag { start, .. } => start,
+            }
+        }
+        pub fn end(self) -> usize {
+            match self {
+                Token::BadTagChar { end, .. }
+                | Token::Char { end, .. }
+                | Token::Comment { end, .. }
+                | Token::EofInTag { end, .. }
+                | Token::Space { end, .. }
+                | Token::Tag { end, .. } => end,
+            }
+        }
+    }
+    enum SequenceMatch {
+        Full,
+        Partial,
+        Mismatch,
+    }
+    pub struct Parser<A: Actions> {
+        actions: A,
+        __pins: Pins,
+        __state: State,
+        __state_enter: bool,
+        __is_last_input: bool,
+        __pos: usize,
+        __chunk_offset: usize,
+        __tokens: Option<std::collections::VecDeque<Token>>,
+        __is_suspended: bool,
+    }
+    impl<A: Actions> Deref for Parser<A> {
+        type Target = A;
+        fn deref(&self) -> &A {
+            &self.actions
+        }
+    }
+    impl<A: Actions> DerefMut for Parser<A> {
+        fn deref_mut(&mut self) -> &mut A {
+            &mut self.actions
+        }
+    }
+    impl<A: Actions> Parser<A> {
+        pub fn new(actions: A) -> Self {
+            Parser {
+                actions,
+                __pins: Pins::default(),
+                __state: State::data_state,
+                __state_enter: true,
+                __is_last_input: false,
+                __pos: 0,
+                __chunk_offset: 0,
+                __tokens: None,
+                __is_suspended: false,
+            }
+        }
+        pub fn state(&self) -> State {
+            self.__state
+        }
+        pub fn pins(&self) -> &Pins {
+            &self.__pins
+        }
+        pub fn into_actions(self) -> A {
+            self.actions
+        }
+        #[doc = r" Parses the chunk of input and returns the number of bytes at the"]
+        #[doc = r" end of the chunk that were not consumed or are pinned. These bytes"]
+        #[doc = r" should be prepended to the next chunk."]
+        pub fn run_parsing_loop(&mut self, input: &[u8], is_last: bool) -> Result<usize, ParsingError> {
+            self.__start_chunk(is_last);
+            let blocked_byte_count = self.__run(input)?;
+            Ok(self.__end_chunk(input, blocked_byte_count))
+        }
+        fn __start_chunk(&mut self, is_last: bool) {
+            self.__is_last_input = is_last;
+        }
+        fn __end_chunk(&mut self, input: &[u8], blocked_byte_count: usize) -> usize {
+            let consumed_byte_count = input.len() - blocked_byte_count;
+            let released_byte_count = consumed_byte_count;
+            self.__chunk_offset += released_byte_count;
+            self.__pos = consumed_byte_count - released_byte_count;
+            let blocked_byte_count = input.len() - released_byte_count;
+            blocked_byte_count
+        }
+        fn __run(&mut self, input: &[u8]) -> Result<usize, ParsingError> {
+            loop {
+                if matches ! ( self . __tokens , Some ( ref tokens ) if ! tokens . is_empty ( ) ) {
+                    self.__is_suspended = true;
+                    return Ok(0);
+                }
+                let ch = input.get(self.__pos).cloned();
+                match self.__state {
+                    State::data_state => match ch {
+                        None if !self.__is_last_input => {
+                            return Ok(0);
+                        }
+                        None => {
+                            self.done();
+                            return Ok(0);
+                        }
+                        Some(_)
+                            if match self.__match_sequence(input, b"<!--", false) {
+                                SequenceMatch::Full => true,
+                                SequenceMatch::Partial => return Ok(input.len() - self.__pos),
+                                SequenceMatch::Mismatch => false,
+                            } =>
+                        {
+                            self.__pins.start = Some((self.__chunk_offset + self.__pos));
+                            self.__state = State::comment_state;
+                            self.__state_enter = true;
+                            self.__pos += 4usize;
+                        }
+                        Some(60u8) => {
+                            self.__pins.start = Some((self.__chunk_offset + self.__pos));
+                            self.__state = State::tag_state;
+                            self.__state_enter = true;
+                            self.__pos += 1;
+                        }
+                        Some(b' ') | Some(b'\n') | Some(b'\r') | Some(b'\t') | Some(b'\x0C') => {
+                            self.__emit_token(Token::Space {
+                                start: (self.__chunk_offset + self.__pos),
+                                end: (self.__chunk_offset + self.__pos) + 1usize,
+                            });
+                            self.__pos += 1;
+                        }
+                        Some(_) => {
+                            self.__emit_token(Token::Char {
+                                start: (self.__chunk_offset + self.__pos),
+                                end: (self.__chunk_offset + self.__pos) + 1usize,
+                            });
+                            self.__pos += 1;
+                        }
+                        Some(_) => {
+                            self.__pos += 1;
+                        }
+                        None => {
+                            return Ok(0);
+                        }
+                    },
+                    State::comment_state => {
+                        let skipped = self.__skip_until(input, &[45u8]);
+                        if skipped > 0 {
+                            self.__pos += skipped;
+                            continue;
+                        }
+                        match ch {
+                            None if !self.__is_last_input => {
+                                return Ok(0);
+                            }
+                            None => {
+                                self.__emit_token(Token::Comment {
+                                    start: self.__pins.start.unwrap_or((self.__chunk_offset + self.__pos)),
+                                    end: (self.__chunk_offset + self.__pos) + 0usize,
+                                });
+                                return Ok(0);
+                            }
+                            Some(_)
+                                if match self.__match_sequence(input, b"-->", false) {
+                                    SequenceMatch::Full => true,
+                                    SequenceMatch::Partial => return Ok(input.len() - self.__pos),
+                                    SequenceMatch::Mismatch => false,
+                                } =>
+                            {
+                                self.__emit_token(Token::Comment {
+                                    start: self.__pins.start.unwrap_or((self.__chunk_offset + self.__pos)),
+                                    end: (self.__chunk_offset + self.__pos) + 3usize,
+                                });
+                                self.__state = State::data_state;
+                                self.__state_enter = true;
+                                self.__pos += 3usize;
+                            }
+                            Some(_) => {
+                                self.__pos += 1;
+                            }
+                            Some(_) => {
+                                self.__pos += 1;
+                            }
+                            None => {
+                                return Ok(0);
+                            }
+                        }
+                    }
+                    State::tag_state => {
+                        let skipped = {
+                            const SKIPPABLE: [bool; 256] = [
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, true, true, true, true, true, true, true, true,
+                                true, true, true, true, true, true, true, true, true, true, true, true, true, true,
+                                true, true, true, true, false, false, false, false, false, false, true, true, true,
+                                true, true, true, true, true, true, true, true, true, true, true, true, true, true,
+                                true, true, true, true, true, true, true, true, true, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false, false, false, false,
+                                false, false, false, false, false, false, false, false, false,
+                            ];
+                            self.__skip_by_table(input, &SKIPPABLE)
+                        };
+                        if skipped > 0 {
+                            self.__pos += skipped;
+                            continue;
+                        }
+                        match ch {
+                            None if !self.__is_last_input => {
+                                return Ok(0);
+                            }
+                            None => {
+                                self.__emit_token(Token::EofInTag {
+                                    start: self.__pins.start.unwrap_or((self.__chunk_offset + self.__pos)),
+                                    end: (self.__chunk_offset + self.__pos) + 0usize,
+                                });
+                                return Ok(0);
+                            }
+                            Some(62u8) => {
+                                self.__emit_token(Token::Tag {
+                                    start: self.__pins.start.unwrap_or((self.__chunk_offset + self.__pos)),
+                                    end: (self.__chunk_offset + self.__pos) + 1usize,
+                                });
+                                self.__state = State::data_state;
+                                self.__state_enter = true;
+                                self.__pos += 1;
+                            }
+                            Some(b'a'..=b'z') | Some(b'A'..=b'Z') => {
+                                self.__pos += 1;
+                            }
+                            Some(_) => {
+                                self.__emit_token(Token::BadTagChar {
+                                    start: (self.__chunk_offset + self.__pos),
+                                    end: (self.__chunk_offset + self.__pos) + 0usize,
+                                });
+                                self.__state = State::data_state;
+                                self.__state_enter = true;
+                            }
+                            Some(_) => {
+                                self.__pos += 1;
+                            }
+                            None => {
+                                return Ok(0);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        fn __match_sequence(&self, input: &[u8], sequence: &[u8], ignore_case: bool) -> SequenceMatch {
+            let available = &input[self.__pos..];
+            let len = available.len().min(sequence.len());
+            let matches = if ignore_case {
+                available[..len].eq_ignore_ascii_case(&sequence[..len])
+            } else {
+                available[..len] == sequence[..len]
+            };
+            if !matches {
+                SequenceMatch::Mismatch
+            } else if len == sequence.len() {
+                SequenceMatch::Full
+            } else if self.__is_last_input {
+                SequenceMatch::Mismatch
+            } else {
+                SequenceMatch::Partial
+            }
+        }
+        fn __match_sequence_with(
+            &self,
+            input: &[u8],
+            len: usize,
+            element_matches: impl Fn(usize, u8) -> bool,
+        ) -> SequenceMatch {
+            let available = &input[self.__pos..];
+            let matches = available
+                .iter()
+                .take(len)
+                .enumerate()
+                .all(|(i, &b)| element_matches(i, b));
+            if !matches {
+                SequenceMatch::Mismatch
+            } else if available.len() >= len {
+                SequenceMatch::Full
+            } else if self.__is_last_input {
+                SequenceMatch::Mismatch
+            } else {
+                SequenceMatch::Partial
+            }
+        }
+        fn __error(&self, error: Error, state: &'static str, byte: Option<u8>) -> ParsingError {
+            ParsingError {
+                state,
+                offset: self.__chunk_offset + self.__pos,
+                byte,
+                error,
+            }
+        }
+        fn __pin_slice<'i>(&self, input: &'i [u8], pin: Option<usize>) -> Option<&'i [u8]> {
+            pin.map(|p| &input[p - self.__chunk_offset..self.__pos])
+        }
+        fn __skip_until(&self, input: &[u8], delimiters: &[u8]) -> usize {
+            __find_delimiter(&input[self.__pos..], delimiters)
+        }
+        fn __skip_by_table(&self, input: &[u8], skippable: &[bool; 256]) -> usize {
+            input[self.__pos..]
+                .iter()
+                .take_while(|&&b| skippable[b as usize])
+                .count()
+        }
+    }
+    #[doc = r" Iterator over the tokens emitted by the parser. The parser is driven only"]
+    #[doc = r" until it emits the next token, so the input is parsed lazily."]
+    #[doc = r""]
+    #[doc = r" Tokens are not passed to `Actions::on_token` while the iterator exists."]
+    #[doc = r" If the iterator is dropped early, the rest of its input is discarded"]
+    #[doc = r" and the parser resumes after the parsed bytes."]
+    pub struct Tokens<'p, A: Actions, C: Iterator>
+    where
+        C::Item: AsRef<[u8]>,
+    {
+        parser: &'p mut Parser<A>,
+        chunks: std::iter::Peekable<C>,
+        #[doc = r" Current chunk prepended with the blocked bytes of the previous one."]
+        input: Vec<u8>,
+        is_in_chunk: bool,
+        is_done: bool,
+        error: Option<ParsingError>,
+    }
+    impl<'p, A: Actions, C: Iterator> Drop for Tokens<'p, A, C>
+    where
+        C::Item: AsRef<[u8]>,
+    {
+        fn drop(&mut self) {
+            if let Some(tokens) = self.parser.__tokens.take() {
+                for token in tokens {
+                    self.parser.actions.on_token(token);
+                }
+            }
+            self.parser.__chunk_offset += self.parser.__pos;
+            self.parser.__pos = 0;
+            self.parser.__is_suspended = false;
+        }
+    }
+    impl<'p, A: Actions, C: Iterator> Iterator for Tokens<'p, A, C>
+    where
+        C::Item: AsRef<[u8]>,
+    {
+        type Item = Result<Token, ParsingError>;
+        fn next(&mut self) -> Option<Self::Item> {
+            loop {
+                if let Some(token) = self.parser.__tokens.as_mut().and_then(|t| t.pop_front()) {
+                    return Some(Ok(token));
+                }
+                if let Some(error) = self.error.take() {
+                    self.is_done = true;
+                    return Some(Err(error));
+                }
+                if self.is_done {
+                    return None;
+                }
+                if !self.is_in_chunk {
+                    if let Some(chunk) = self.chunks.next() {
+                        self.input.extend_from_slice(chunk.as_ref());
+                    }
+                    let is_last = self.chunks.peek().is_none();
+                    self.parser.__start_chunk(is_last);
+                    self.is_in_chunk = true;
+                }
+                match self.parser.__run(&self.input) {
+                    Ok(_) if self.parser.__is_suspended => self.parser.__is_suspended = false,
+                    Ok(blocked_byte_count) => {
+                        let blocked_byte_count = self.parser.__end_chunk(&self.input, blocked_byte_count);
+                        let consumed_byte_count = self.input.len() - blocked_byte_count;
+                        self.input.drain(..consumed_byte_count);
+                        self.is_in_chunk = false;
+                        self.is_done = self.parser.__is_last_input;
+                    }
+                    Err(error) => self.error = Some(error),
+                }
+            }
+        }
+    }
+    impl<A: Actions> Parser<A> {
+        #[doc = r" Returns iterator over the tokens of the input that is parsed as the last chunk."]
+        pub fn tokens<'i>(&mut self, input: &'i [u8]) -> Tokens<'_, A, std::iter::Once<&'i [u8]>> {
+            self.tokens_from_chunks(std::iter::once(input))
+        }
+        #[doc = r" Returns iterator over the tokens of the chunked input. Chunks are requested"]
+        #[doc = r" when the parser needs more input (and one more chunk ahead to find out if"]
+        #[doc = r" the current chunk is the last one)."]
+        pub fn tokens_from_chunks<C: IntoIterator>(&mut self, chunks: C) -> Tokens<'_, A, C::IntoIter>
+        where
+            C::Item: AsRef<[u8]>,
+        {
+            self.__tokens = Some(Default::default());
+            Tokens {
+                parser: self,
+                chunks: chunks.into_iter().peekable(),
+                input: Vec::new(),
+                is_in_chunk: false,
+                is_done: false,
+                error: None,
+            }
+        }
+        fn __emit_token(&mut self, token: Token) {
+            match self.__tokens {
+                Some(ref mut tokens) => tokens.push_back(token),
+                None => self.actions.on_token(token),
+            }
+        }
+    }
+    #[doc = r" Returns the position of the first delimiter in the haystack (or the haystack's"]
+    #[doc = r" length if there are no delimiters), using the widest SIMD instruction set"]
+    #[doc = r" that is available on the CPU."]
+    #[allow(unreachable_code)]
+    fn __find_delimiter(haystack: &[u8], delimiters: &[u8]) -> usize {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx2") {
+                return unsafe { __find_delimiter_avx2(haystack, delimiters) };
+            }
+            return unsafe { __find_delimiter_sse2(haystack, delimiters) };
+        }
+        #[cfg(target_arch = "aarch64")]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") {
+                return unsafe { __find_delimiter_neon(haystack, delimiters) };
+            }
+        }
+        __find_delimiter_swar(haystack, delimiters)
+    }
+    fn __find_delimiter_swar(haystack: &[u8], delimiters: &[u8]) -> usize {
+        const LO: u64 = 0x0101_0101_0101_0101;
+        const HI: u64 = 0x8080_8080_8080_8080;
+        let has_delimiter = |word: u64| {
+            delimiters.iter().any(|&d| {
+                let x = word ^ (LO * d as u64);
+                x.wrapping_sub(LO) & !x & HI != 0
+            })
+        };
+        let mut pos = 0;
+        while pos + 8 <= haystack.len() {
+            let mut word = [0; 8];
+            word.copy_from_slice(&haystack[pos..pos + 8]);
+            if has_delimiter(u64::from_le_bytes(word)) {
+                break;
+            }
+            pos += 8;
+        }
+        while pos < haystack.len() && !delimiters.contains(&haystack[pos]) {
+            pos += 1;
+        }
+        pos
+    }
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "sse2")]
+    unsafe fn __find_delimiter_sse2(haystack: &[u8], delimiters: &[u8]) -> usize {
+        use std::arch::x86_64::*;
+        let mut pos = 0;
+        unsafe {
+            while pos + 16 <= haystack.len() {
+                let chunk = _mm_loadu_si128(haystack.as_ptr().add(pos) as *const __m128i);
+                let mut eq = _mm_setzero_si128();
+                for &d in delimiters {
+                    eq = _mm_or_si128(eq, _mm_cmpeq_epi8(chunk, _mm_set1_epi8(d as i8)));
+                }
+                let mask = _mm_movemask_epi8(eq) as u32;
+                if mask != 0 {
+                    return pos + mask.trailing_zeros() as usize;
+                }
+                pos += 16;
+            }
+        }
+        pos + __find_delimiter_swar(&haystack[pos..], delimiters)
+    }
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "avx2")]
+    unsafe fn __find_delimiter_avx2(haystack: &[u8], delimiters: &[u8]) -> usize {
+        use std::arch::x86_64::*;
+        let mut pos = 0;
+        unsafe {
+            while pos + 32 <= haystack.len() {
+                let chunk = _mm256_loadu_si256(haystack.as_ptr().add(pos) as *const __m256i);
+                let mut eq = _mm256_setzero_si256();
+                for &d in delimiters {
+                    eq = _mm256_or_si256(eq, _mm256_cmpeq_epi8(chunk, _mm256_set1_epi8(d as i8)));
+                }
+                let mask = _mm256_movemask_epi8(eq) as u32;
+                if mask != 0 {
+                    return pos + mask.trailing_zeros() as usize;
+                }
+                pos += 32;
+            }
+            pos + __find_delimiter_sse2(&haystack[pos..], delimiters)
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    #[target_feature(enable = "neon")]
+    unsafe fn __find_delimiter_neon(haystack: &[u8], delimiters: &[u8]) -> usize {
+        use std::arch::aarch64::*;
+        let mut pos = 0;
+        unsafe {
+            while pos + 16 <= haystack.len() {
+                let chunk = vld1q_u8(haystack.as_ptr().add(pos));
+                let mut eq = vdupq_n_u8(0);
+                for &d in delimiters {
+                    eq = vorrq_u8(eq, vceqq_u8(chunk, vdupq_n_u8(d)));
+                }
+                if vmaxvq_u8(eq) != 0 {
+                    break;
+                }
+                pos += 16;
+            }
+        }
+        pos + __find_delimiter_swar(&haystack[pos..], delimiters)
+    }
+}
@@ -0,0 +1,1992 @@
+// @generated by the `generated_code_is_up_to_date` test, run it with `UPDATE_GENERATED=1` to update.
+
+#[allow(
+    non_snake_case,
+    non_camel_case_types,
+    dead_code,
+    unused_imports,
+    unused_parens,
+    unreachable_patterns,
+    clippy::all
+)]
+pub mod Utf8 {
+    use super::*;
+    use std::ops::{Deref, DerefMut};
+    pub type Error = ::std::convert::Infallible;
+    #[derive(Debug)]
+    pub struct ParsingError {
+        pub state: &'static str,
+        pub offset: usize,
+        pub byte: Option<u8>,
+        pub error: Error,
+    }
+    impl std::fmt::Display for ParsingError {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(f, "parsing error in `{}` at offset {} ", self.state, self.offset)?;
+            match self.byte {
+                Some(b) => write!(f, "(byte 0x{:02x})", b)?,
+                None => write!(f, "(end of input)")?,
+            }
+            write!(f, ": {:?}", self.error)
+        }
+    }
+    impl std::error::Error for ParsingError {}
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum State {
+        data_state,
+        ident_state,
+    }
+    impl State {
+        pub fn name(self) -> &'static str {
+            match self {
+                State::data_state => "data_state",
+                State::ident_state => "ident_state",
+            }
+        }
+    }
+    pub trait Actions {
+        fn done(&mut self);
+        fn ident_start(&mut self, arg0: usize);
+        fn space(&mut self, arg0: usize);
+        fn masked(&mut self, arg0: Option<u8>);
+        fn invalid(&mut self, arg0: usize);
+        fn other(&mut self, arg0: usize);
+        fn ident_eof(&mut self, arg0: usize);
+        fn ident_end(&mut self, arg0: usize);
+    }
+    #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+    pub struct Pins {}
+    enum SequenceMatch {
+        Full,
+        Partial,
+        Mismatch,
+    }
+    enum Utf8Sequence {
+        CodePoint(u32, usize),
+        Invalid(usize),
+        Blocked,
+    }
+    fn __decode_utf8(bytes: &[u8], is_last_input: bool) -> Utf8Sequence {
+        let first = bytes[0];
+        let (len, mut code_point, mut next) = match first {
+            0x00..=0x7f => return Utf8Sequence::CodePoint(u32::from(first), 1),
+            0xc2..=0xdf => (2, u32::from(first & 0x1f), (0x80, 0xbf)),
+            0xe0 => (3, 0, (0xa0, 0xbf)),
+            0xed => (3, 0xd, (0x80, 0x9f)),
+            0xe1..=0xef => (3, u32::from(first & 0x0f), (0x80, 0xbf)),
+            0xf0 => (4, 0, (0x90, 0xbf)),
+            0xf4 => (4, 0x4, (0x80, 0x8f)),
+            0xf1..=0xf3 => (4, u32::from(first & 0x07), (0x80, 0xbf)),
+            _ => return Utf8Sequence::Invalid(1),
+        };
+        for depth in 1..len {
+            match bytes.get(depth) {
+                Some(&b) if b >= next.0 && b <= next.1 => {
+                    code_point = (code_point << 6) | u32::from(b & 0x3f);
+                    next = (0x80, 0xbf);
+                }
+                Some(_) => return Utf8Sequence::Invalid(depth),
+                None if is_last_input => return Utf8Sequence::Invalid(depth),
+                None => return Utf8Sequence::Blocked,
+            }
+        }
+        Utf8Sequence::CodePoint(code_point, len)
+    }
+    fn __has_code_point(ranges: &[(u32, u32)], c: u32) -> bool {
+        ranges
+            .binary_search_by(|&(start, end)| {
+                if end < c {
+                    std::cmp::Ordering::Less
+                } else if start > c {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+    static __XID_START_RANGES: &[(u32, u32)] = &[
+        (65u32, 90u32),
+        (97u32, 122u32),
+        (170u32, 170u32),
+        (181u32, 181u32),
+        (186u32, 186u32),
+        (192u32, 214u32),
+        (216u32, 246u32),
+        (248u32, 705u32),
+        (710u32, 721u32),
+        (736u32, 740u32),
+        (748u32, 748u32),
+        (750u32, 750u32),
+        (880u32, 884u32),
+        (886u32, 887u32),
+        (891u32, 893u32),
+        (895u32, 895u32),
+        (902u32, 902u32),
+        (904u32, 906u32),
+        (908u32, 908u32),
+        (910u32, 929u32),
+        (931u32, 1013u32),
+        (1015u32, 1153u32),
+        (1162u32, 1327u32),
+        (1329u32, 1366u32),
+        (1369u32, 1369u32),
+        (1376u32, 1416u32),
+        (1488u32, 1514u32),
+        (1519u32, 1522u32),
+        (1568u32, 1610u32),
+        (1646u32, 1647u32),
+        (1649u32, 1747u32),
+        (1749u32, 1749u32),
+        (1765u32, 1766u32),
+        (1774u32, 1775u32),
+        (1786u32, 1788u32),
+        (1791u32, 1791u32),
+        (1808u32, 1808u32),
+        (1810u32, 1839u32),
+        (1869u32, 1957u32),
+        (1969u32, 1969u32),
+        (1994u32, 2026u32),
+        (2036u32, 2037u32),
+        (2042u32, 2042u32),
+        (2048u32, 2069u32),
+        (2074u32, 2074u32),
+        (2084u32, 2084u32),
+        (2088u32, 2088u32),
+        (2112u32, 2136u32),
+        (2144u32, 2154u32),
+        (2160u32, 2183u32),
+        (2185u32, 2190u32),
+        (2208u32, 2249u32),
+        (2308u32, 2361u32),
+        (2365u32, 2365u32),
+        (2384u32, 2384u32),
+        (2392u32, 2401u32),
+        (2417u32, 2432u32),
+        (2437u32, 2444u32),
+        (2447u32, 2448u32),
+        (2451u32, 2472u32),
+        (2474u32, 2480u32),
+        (2482u32, 2482u32),
+        (2486u32, 2489u32),
+        (2493u32, 2493u32),
+        (2510u32, 2510u32),
+        (2524u32, 2525u32),
+        (2527u32, 2529u32),
+        (2544u32, 2545u32),
+        (2556u32, 2556u32),
+        (2565u32, 2570u32),
+        (2575u32, 2576u32),
+        (2579u32, 2600u32),
+        (2602u32, 2608u32),
+        (2610u32, 2611u32),
+        (2613u32, 2614u32),
+        (2616u32, 2617u32),
+        (2649u32, 2652u32),
+        (2654u32, 2654u32),
+        (2674u32, 2676u32),
+        (2693u32, 2701u32),
+        (2703u32, 2705u32),
+        (2707u32, 2728u32),
+        (2730u32, 2736u32),
+        (2738u32, 2739u32),
+        (2741u32, 2745u32),
+        (2749u32, 2749u32),
+        (2768u32, 2768u32),
+        (2784u32, 2785u32),
+        (2809u32, 2809u32),
+        (2821u32, 2828u32),
+        (2831u32, 2832u32),
+        (2835u32, 2856u32),
+        (2858u32, 2864u32),
+        (2866u32, 2867u32),
+        (2869u32, 2873u32),
+        (2877u32, 2877u32),
+        (2908u32, 2909u32),
+        (2911u32, 2913u32),
+        (2929u32, 2929u32),
+        (2947u32, 2947u32),
+        (2949u32, 2954u32),
+        (2958u32, 2960u32),
+        (2962u32, 2965u32),
+        (2969u32, 2970u32),
+        (2972u32, 2972u32),
+        (2974u32, 2975u32),
+        (2979u32, 2980u32),
+        (2984u32, 2986u32),
+        (2990u32, 3001u32),
+        (3024u32, 3024u32),
+        (3077u32, 3084u32),
+        (3086u32, 3088u32),
+        (3090u32, 3112u32),
+        (3114u32, 3129u32),
+        (3133u32, 3133u32),
+        (3160u32, 3162u32),
+        (3165u32, 3165u32),
+        (3168u32, 3169u32),
+        (3200u32, 3200u32),
+        (3205u32, 3212u32),
+        (3214u32, 3216u32),
+        (3218u32, 3240u32),
+        (3242u32, 3251u32),
+        (3253u32, 3257u32),
+        (3261u32, 3261u32),
+        (3293u32, 3294u32),
+        (3296u32, 3297u32),
+        (3313u32, 3314u32),
+        (3332u32, 3340u32),
+        (3342u32, 3344u32),
+        (3346u32, 3386u32),
+        (3389u32, 3389u32),
+        (3406u32, 3406u32),
+        (3412u32, 3414u32),
+        (3423u32, 3425u32),
+        (3450u32, 3455u32),
+        (3461u32, 3478u32),
+        (3482u32, 3505u32),
+        (3507u32, 3515u32),
+        (3517u32, 3517u32),
+        (3520u32, 3526u32),
+        (3585u32, 3632u32),
+        (3634u32, 3634u32),
+        (3648u32, 3654u32),
+        (3713u32, 3714u32),
+        (3716u32, 3716u32),
+        (3718u32, 3722u32),
+        (3724u32, 3747u32),
+        (3749u32, 3749u32),
+        (3751u32, 3760u32),
+        (3762u32, 3762u32),
+        (3773u32, 3773u32),
+        (3776u32, 3780u32),
+        (3782u32, 3782u32),
+        (3804u32, 3807u32),
+        (3840u32, 3840u32),
+        (3904u32, 3911u32),
+        (3913u32, 3948u32),
+        (3976u32, 3980u32),
+        (4096u32, 4138u32),
+        (4159u32, 4159u32),
+        (4176u32, 4181u32),
+        (4186u32, 4189u32),
+        (4193u32, 4193u32),
+        (4197u32, 4198u32),
+        (4206u32, 4208u32),
+        (4213u32, 4225u32),
+        (4238u32, 4238u32),
+        (4256u32, 4293u32),
+        (4295u32, 4295u32),
+        (4301u32, 4301u32),
+        (4304u32, 4346u32),
+        (4348u32, 4680u32),
+        (4682u32, 4685u32),
+        (4688u32, 4694u32),
+        (4696u32, 4696u32),
+        (4698u32, 4701u32),
+        (4704u32, 4744u32),
+        (4746u32, 4749u32),
+        (4752u32, 4784u32),
+        (4786u32, 4789u32),
+        (4792u32, 4798u32),
+        (4800u32, 4800u32),
+        (4802u32, 4805u32),
+        (4808u32, 4822u32),
+        (4824u32, 4880u32),
+        (4882u32, 4885u32),
+        (4888u32, 4954u32),
+        (4992u32, 5007u32),
+        (5024u32, 5109u32),
+        (5112u32, 5117u32),
+        (5121u32, 5740u32),
+        (5743u32, 5759u32),
+        (5761u32, 5786u32),
+        (5792u32, 5866u32),
+        (5870u32, 5880u32),
+        (5888u32, 5905u32),
+        (5919u32, 5937u32),
+        (5952u32, 5969u32),
+        (5984u32, 5996u32),
+        (5998u32, 6000u32),
+        (6016u32, 6067u32),
+        (6103u32, 6103u32),
+        (6108u32, 6108u32),
+        (6176u32, 6264u32),
+        (6272u32, 6312u32),
+        (6314u32, 6314u32),
+        (6320u32, 6389u32),
+        (6400u32, 6430u32),
+        (6480u32, 6509u32),
+        (6512u32, 6516u32),
+        (6528u32, 6571u32),
+        (6576u32, 6601u32),
+        (6656u32, 6678u32),
+        (6688u32, 6740u32),
+        (6823u32, 6823u32),
+        (6917u32, 6963u32),
+        (6981u32, 6988u32),
+        (7043u32, 7072u32),
+        (7086u32, 7087u32),
+        (7098u32, 7141u32),
+        (7168u32, 7203u32),
+        (7245u32, 7247u32),
+        (7258u32, 7293u32),
+        (7296u32, 7306u32),
+        (7312u32, 7354u32),
+        (7357u32, 7359u32),
+        (7401u32, 7404u32),
+        (7406u32, 7411u32),
+        (7413u32, 7414u32),
+        (7418u32, 7418u32),
+        (7424u32, 7615u32),
+        (7680u32, 7957u32),
+        (7960u32, 7965u32),
+        (7968u32, 8005u32),
+        (8008u32, 8013u32),
+        (8016u32, 8023u32),
+        (8025u32, 8025u32),
+        (8027u32, 8027u32),
+        (8029u32, 8029u32),
+        (8031u32, 8061u32),
+        (8064u32, 8116u32),
+        (8118u32, 8124u32),
+        (8126u32, 8126u32),
+        (8130u32, 8132u32),
+        (8134u32, 8140u32),
+        (8144u32, 8147u32),
+        (8150u32, 8155u32),
+        (8160u32, 8172u32),
+        (8178u32, 8180u32),
+        (8182u32, 8188u32),
+        (8305u32, 8305u32),
+        (8319u32, 8319u32),
+        (8336u32, 8348u32),
+        (8450u32, 8450u32),
+        (8455u32, 8455u32),
+        (8458u32, 8467u32),
+        (8469u32, 8469u32),
+        (8472u32, 8477u32),
+        (8484u32, 8484u32),
+        (8486u32, 8486u32),
+        (8488u32, 8488u32),
+        (8490u32, 8505u32),
+        (8508u32, 8511u32),
+        (8517u32, 8521u32),
+        (8526u32, 8526u32),
+        (8544u32, 8584u32),
+        (11264u32, 11492u32),
+        (11499u32, 11502u32),
+        (11506u32, 11507u32),
+        (11520u32, 11557u32),
+        (11559u32, 11559u32),
+        (11565u32, 11565u32),
+        (11568u32, 11623u32),
+        (11631u32, 11631u32),
+        (11648u32, 11670u32),
+        (11680u32, 11686u32),
+        (11688u32, 11694u32),
+        (11696u32, 11702u32),
+        (11704u32, 11710u32),
+        (11712u32, 11718u32),
+        (11720u32, 11726u32),
+        (11728u32, 11734u32),
+        (11736u32, 11742u32),
+        (12293u32, 12295u32),
+        (12321u32, 12329u32),
+        (12337u32, 12341u32),
+        (12344u32, 12348u32),
+        (12353u32, 12438u32),
+        (12445u32, 12447u32),
+        (12449u32, 12538u32),
+        (12540u32, 12543u32),
+        (12549u32, 12591u32),
+        (12593u32, 12686u32),
+        (12704u32, 12735u32),
+        (12784u32, 12799u32),
+        (13312u32, 19903u32),
+        (19968u32, 42124u32),
+        (42192u32, 42237u32),
+        (42240u32, 42508u32),
+        (42512u32, 42527u32),
+        (42538u32, 42539u32),
+        (42560u32, 42606u32),
+        (42623u32, 42653u32),
+        (42656u32, 42735u32),
+        (42775u32, 42783u32),
+        (42786u32, 42888u32),
+        (42891u32, 42957u32),
+        (42960u32, 42961u32),
+        (42963u32, 42963u32),
+        (42965u32, 42972u32),
+        (42994u32, 43009u32),
+        (43011u32, 43013u32),
+        (43015u32, 43018u32),
+        (43020u32, 43042u32),
+        (43072u32, 43123u32),
+        (43138u32, 43187u32),
+        (43250u32, 43255u32),
+        (43259u32, 43259u32),
+        (43261u32, 43262u32),
+        (43274u32, 43301u32),
+        (43312u32, 43334u32),
+        (43360u32, 43388u32),
+        (43396u32, 43442u32),
+        (43471u32, 43471u32),
+        (43488u32, 43492u32),
+        (43494u32, 43503u32),
+        (43514u32, 43518u32),
+        (43520u32, 43560u32),
+        (43584u32, 43586u32),
+        (43588u32, 43595u32),
+        (43616u32, 43638u32),
+        (43642u32, 43642u32),
+        (43646u32, 43695u32),
+        (43697u32, 43697u32),
+        (43701u32, 43702u32),
+        (43705u32, 43709u32),
+        (43712u32, 43712u32),
+        (43714u32, 43714u32),
+        (43739u32, 43741u32),
+        (43744u32, 43754u32),
+        (43762u32, 43764u32),
+        (43777u32, 43782u32),
+        (43785u32, 43790u32),
+        (43793u32, 43798u32),
+        (43808u32, 43814u32),
+        (43816u32, 43822u32),
+        (43824u32, 43866u32),
+        (43868u32, 43881u32),
+        (43888u32, 44002u32),
+        (44032u32, 55203u32),
+        (55216u32, 55238u32),
+        (55243u32, 55291u32),
+        (63744u32, 64109u32),
+        (64112u32, 64217u32),
+        (64256u32, 64262u32),
+        (64275u32, 64279u32),
+        (64285u32, 64285u32),
+        (64287u32, 64296u32),
+        (64298u32, 64310u32),
+        (64312u32, 64316u32),
+        (64318u32, 64318u32),
+        (64320u32, 64321u32),
+        (64323u32, 64324u32),
+        (64326u32, 64433u32),
+        (64467u32, 64605u32),
+        (64612u32, 64829u32),
+        (64848u32, 64911u32),
+        (64914u32, 64967u32),
+        (65008u32, 65017u32),
+        (65137u32, 65137u32),
+        (65139u32, 65139u32),
+        (65143u32, 65143u32),
+        (65145u32, 65145u32),
+        (65147u32, 65147u32),
+        (65149u32, 65149u32),
+        (65151u32, 65276u32),
+        (65313u32, 65338u32),
+        (65345u32, 65370u32),
+        (65382u32, 65437u32),
+        (65440u32, 65470u32),
+        (65474u32, 65479u32),
+        (65482u32, 65487u32),
+        (65490u32, 65495u32),
+        (65498u32, 65500u32),
+        (65536u32, 65547u32),
+        (65549u32, 65574u32),
+        (65576u32, 65594u32),
+        (65596u32, 65597u32),
+        (65599u32, 65613u32),
+        (65616u32, 65629u32),
+        (65664u32, 65786u32),
+        (65856u32, 65908u32),
+        (66176u32, 66204u32),
+        (66208u32, 66256u32),
+        (66304u32, 66335u32),
+        (66349u32, 66378u32),
+        (66384u32, 66421u32),
+        (66432u32, 66461u32),
+        (66464u32, 66499u32),
+        (66504u32, 66511u32),
+        (66513u32, 66517u32),
+        (66560u32, 66717u32),
+        (66736u32, 66771u32),
+        (66776u32, 66811u32),
+        (66816u32, 66855u32),
+        (66864u32, 66915u32),
+        (66928u32, 66938u32),
+        (66940u32, 66954u32),
+        (66956u32, 66962u32),
+        (66964u32, 66965u32),
+        (66967u32, 66977u32),
+        (66979u32, 66993u32),
+        (66995u32, 67001u32),
+        (67003u32, 67004u32),
+        (67008u32, 67059u32),
+        (67072u32, 67382u32),
+        (67392u32, 67413u32),
+        (67424u32, 67431u32),
+        (67456u32, 67461u32),
+        (67463u32, 67504u32),
+        (67506u32, 67514u32),
+        (67584u32, 67589u32),
+        (67592u32, 67592u32),
+        (67594u32, 67637u32),
+        (67639u32, 67640u32),
+        (67644u32, 67644u32),
+        (67647u32, 67669u32),
+        (67680u32, 67702u32),
+        (67712u32, 67742u32),
+        (67808u32, 67826u32),
+        (67828u32, 67829u32),
+        (67840u32, 67861u32),
+        (67872u32, 67897u32),
+        (67968u32, 68023u32),
+        (68030u32, 68031u32),
+        (68096u32, 68096u32),
+        (68112u32, 68115u32),
+        (68117u32, 68119u32),
+        (68121u32, 68149u32),
+        (68192u32, 68220u32),
+        (68224u32, 68252u32),
+        (68288u32, 68295u32),
+        (68297u32, 68324u32),
+        (68352u32, 68405u32),
+        (68416u32, 68437u32),
+        (68448u32, 68466u32),
+        (68480u32, 68497u32),
+        (68608u32, 68680u32),
+        (68736u32, 68786u32),
+        (68800u32, 68850u32),
+        (68864u32, 68899u32),
+        (68938u32, 68965u32),
+        (68975u32, 68997u32),
+        (69248u32, 69289u32),
+        (69296u32, 69297u32),
+        (69314u32, 69316u32),
+        (69376u32, 69404u32),
+        (69415u32, 69415u32),
+        (69424u32, 69445u32),
+        (69488u32, 69505u32),
+        (69552u32, 69572u32),
+        (69600u32, 69622u32),
+        (69635u32, 69687u32),
+        (69745u32, 69746u32),
+        (69749u32, 69749u32),
+        (69763u32, 69807u32),
+        (69840u32, 69864u32),
+        (69891u32, 69926u32),
+        (69956u32, 69956u32),
+        (69959u32, 69959u32),
+        (69968u32, 70002u32),
+        (70006u32, 70006u32),
+        (70019u32, 70066u32),
+        (70081u32, 70084u32),
+        (70106u32, 70106u32),
+        (70108u32, 70108u32),
+        (70144u32, 70161u32),
+        (70163u32, 70187u32),
+        (70207u32, 70208u32),
+        (70272u32, 70278u32),
+        (70280u32, 70280u32),
+        (70282u32, 70285u32),
+        (70287u32, 70301u32),
+        (70303u32, 70312u32),
+        (70320u32, 70366u32),
+        (70405u32, 70412u32),
+        (70415u32, 70416u32),
+        (70419u32, 70440u32),
+        (70442u32, 70448u32),
+        (70450u32, 70451u32),
+        (70453u32, 70457u32),
+        (70461u32, 70461u32),
+        (70480u32, 70480u32),
+        (70493u32, 70497u32),
+        (70528u32, 70537u32),
+        (70539u32, 70539u32),
+        (70542u32, 70542u32),
+        (70544u32, 70581u32),
+        (70583u32, 70583u32),
+        (70609u32, 70609u32),
+        (70611u32, 70611u32),
+        (70656u32, 70708u32),
+        (70727u32, 70730u32),
+        (70751u32, 70753u32),
+        (70784u32, 70831u32),
+        (70852u32, 70853u32),
+        (70855u32, 70855u32),
+        (71040u32, 71086u32),
+        (71128u32, 71131u32),
+        (71168u32, 71215u32),
+        (71236u32, 71236u32),
+        (71296u32, 71338u32),
+        (71352u32, 71352u32),
+        (71424u32, 71450u32),
+        (71488u32, 71494u32),
+        (71680u32, 71723u32),
+        (71840u32, 71903u32),
+        (71935u32, 71942u32),
+        (71945u32, 71945u32),
+        (71948u32, 71955u32),
+        (71957u32, 71958u32),
+        (71960u32, 71983u32),
+        (71999u32, 71999u32),
+        (72001u32, 72001u32),
+        (72096u32, 72103u32),
+        (72106u32, 72144u32),
+        (72161u32, 72161u32),
+        (72163u32, 72163u32),
+        (72192u32, 72192u32),
+        (72203u32, 72242u32),
+        (72250u32, 72250u32),
+        (72272u32, 72272u32),
+        (72284u32, 72329u32),
+        (72349u32, 72349u32),
+        (72368u32, 72440u32),
+        (72640u32, 72672u32),
+        (72704u32, 72712u32),
+        (72714u32, 72750u32),
+        (72768u32, 72768u32),
+        (72818u32, 72847u32),
+        (72960u32, 72966u32),
+        (72968u32, 72969u32),
+        (72971u32, 73008u32),
+        (73030u32, 73030u32),
+        (73056u32, 73061u32),
+        (73063u32, 73064u32),
+        (73066u32, 73097u32),
+        (73112u32, 73112u32),
+        (73440u32, 73458u32),
+        (73474u32, 73474u32),
+        (73476u32, 73488u32),
+        (73490u32, 73523u32),
+        (73648u32, 73648u32),
+        (73728u32, 74649u32),
+        (74752u32, 74862u32),
+        (74880u32, 75075u32),
+        (77712u32, 77808u32),
+        (77824u32, 78895u32),
+        (78913u32, 78918u32),
+        (78944u32, 82938u32),
+        (82944u32, 83526u32),
+        (90368u32, 90397u32),
+        (92160u32, 92728u32),
+        (92736u32, 92766u32),
+        (92784u32, 92862u32),
+        (92880u32, 92909u32),
+        (92928u32, 92975u32),
+        (92992u32, 92995u32),
+        (93027u32, 93047u32),
+        (93053u32, 93071u32),
+        (93504u32, 93548u32),
+        (93760u32, 93823u32),
+        (93952u32, 94026u32),
+        (94032u32, 94032u32),
+        (94099u32, 94111u32),
+        (94176u32, 94177u32),
+        (94179u32, 94179u32),
+        (94208u32, 100343u32),
+        (100352u32, 101589u32),
+        (101631u32, 101640u32),
+        (110576u32, 110579u32),
+        (110581u32, 110587u32),
+        (110589u32, 110590u32),
+        (110592u32, 110882u32),
+        (110898u32, 110898u32),
+        (110928u32, 110930u32),
+        (110933u32, 110933u32),
+        (110948u32, 110951u32),
+        (110960u32, 111355u32),
+        (113664u32, 113770u32),
+        (113776u32, 113788u32),
+        (113792u32, 113800u32),
+        (113808u32, 113817u32),
+        (119808u32, 119892u32),
+        (119894u32, 119964u32),
+        (119966u32, 119967u32),
+        (119970u32, 119970u32),
+        (119973u32, 119974u32),
+        (119977u32, 119980u32),
+        (119982u32, 119993u32),
+        (119995u32, 119995u32),
+        (119997u32, 120003u32),
+        (120005u32, 120069u32),
+        (120071u32, 120074u32),
+        (120077u32, 120084u32),
+        (120086u32, 120092u32),
+        (120094u32, 120121u32),
+        (120123u32, 120126u32),
+        (120128u32, 120132u32),
+        (120134u32, 120134u32),
+        (120138u32, 120144u32),
+        (120146u32, 120485u32),
+        (120488u32, 120512u32),
+        (120514u32, 120538u32),
+        (120540u32, 120570u32),
+        (120572u32, 120596u32),
+        (120598u32, 120628u32),
+        (120630u32, 120654u32),
+        (120656u32, 120686u32),
+        (120688u32, 120712u32),
+        (120714u32, 120744u32),
+        (120746u32, 120770u32),
+        (120772u32, 120779u32),
+        (122624u32, 122654u32),
+        (122661u32, 122666u32),
+        (122928u32, 122989u32),
+        (123136u32, 123180u32),
+        (123191u32, 123197u32),
+        (123214u32, 123214u32),
+        (123536u32, 123565u32),
+        (123584u32, 123627u32),
+        (124112u32, 124139u32),
+        (124368u32, 124397u32),
+        (124400u32, 124400u32),
+        (124896u32, 124902u32),
+        (124904u32, 124907u32),
+        (124909u32, 124910u32),
+        (124912u32, 124926u32),
+        (124928u32, 125124u32),
+        (125184u32, 125251u32),
+        (125259u32, 125259u32),
+        (126464u32, 126467u32),
+        (126469u32, 126495u32),
+        (126497u32, 126498u32),
+        (126500u32, 126500u32),
+        (126503u32, 126503u32),
+        (126505u32, 126514u32),
+        (126516u32, 126519u32),
+        (126521u32, 126521u32),
+        (126523u32, 126523u32),
+        (126530u32, 126530u32),
+        (126535u32, 126535u32),
+        (126537u32, 126537u32),
+        (126539u32, 126539u32),
+        (126541u32, 126543u32),
+        (126545u32, 126546u32),
+        (126548u32, 126548u32),
+        (126551u32, 126551u32),
+        (126553u32, 126553u32),
+        (126555u32, 126555u32),
+        (126557u32, 126557u32),
+        (126559u32, 126559u32),
+        (126561u32, 126562u32),
+        (126564u32, 126564u32),
+        (126567u32, 126570u32),
+        (126572u32, 126578u32),
+        (126580u32, 126583u32),
+        (126585u32, 126588u32),
+        (126590u32, 126590u32),
+        (126592u32, 126601u32),
+        (126603u32, 126619u32),
+        (126625u32, 126627u32),
+        (126629u32, 126633u32),
+        (126635u32, 126651u32),
+        (131072u32, 173791u32),
+        (173824u32, 177977u32),
+        (177984u32, 178205u32),
+        (178208u32, 183969u32),
+        (183984u32, 191456u32),
+        (191472u32, 192093u32),
+        (194560u32, 195101u32),
+        (196608u32, 201546u32),
+        (201552u32, 205743u32),
+    ];
+    static __WHITE_SPACE_RANGES: &[(u32, u32)] = &[
+        (9u32, 13u32),
+        (32u32, 32u32),
+        (133u32, 133u32),
+        (160u32, 160u32),
+        (5760u32, 5760u32),
+        (8192u32, 8202u32),
+        (8232u32, 8233u32),
+        (8239u32, 8239u32),
+        (8287u32, 8287u32),
+        (12288u32, 12288u32),
+    ];
+    static __XID_CONTINUE_RANGES: &[(u32, u32)] = &[
+        (48u32, 57u32),
+        (65u32, 90u32),
+        (95u32, 95u32),
+        (97u32, 122u32),
+        (170u32, 170u32),
+        (181u32, 181u32),
+        (183u32, 183u32),
+        (186u32, 186u32),
+        (192u32, 214u32),
+        (216u32, 246u32),
+        (248u32, 705u32),
+        (710u32, 721u32),
+        (736u32, 740u32),
+        (748u32, 748u32),
+        (750u32, 750u32),
+        (768u32, 884u32),
+        (886u32, 887u32),
+        (891u32, 893u32),
+        (895u32, 895u32),
+        (902u32, 906u32),
+        (908u32, 908u32),
+        (910u32, 929u32),
+        (931u32, 1013u32),
+        (1015u32, 1153u32),
+        (1155u32, 1159u32),
+        (1162u32, 1327u32),
+        (1329u32, 1366u32),
+        (1369u32, 1369u32),
+        (1376u32, 1416u32),
+        (1425u32, 1469u32),
+        (1471u32, 1471u32),
+        (1473u32, 1474u32),
+        (1476u32, 1477u32),
+        (1479u32, 1479u32),
+        (1488u32, 1514u32),
+        (1519u32, 1522u32),
+        (1552u32, 1562u32),
+        (1568u32, 1641u32),
+        (1646u32, 1747u32),
+        (1749u32, 1756u32),
+        (1759u32, 1768u32),
+        (1770u32, 1788u32),
+        (1791u32, 1791u32),
+        (1808u32, 1866u32),
+        (1869u32, 1969u32),
+        (1984u32, 2037u32),
+        (2042u32, 2042u32),
+        (2045u32, 2045u32),
+        (2048u32, 2093u32),
+        (2112u32, 2139u32),
+        (2144u32, 2154u32),
+        (2160u32, 2183u32),
+        (2185u32, 2190u32),
+        (2199u32, 2273u32),
+        (2275u32, 2403u32),
+        (2406u32, 2415u32),
+        (2417u32, 2435u32),
+        (2437u32, 2444u32),
+        (2447u32, 2448u32),
+        (2451u32, 2472u32),
+        (2474u32, 2480u32),
+        (2482u32, 2482u32),
+        (2486u32, 2489u32),
+        (2492u32, 2500u32),
+        (2503u32, 2504u32),
+        (2507u32, 2510u32),
+        (2519u32, 2519u32),
+        (2524u32, 2525u32),
+        (2527u32, 2531u32),
+        (2534u32, 2545u32),
+        (2556u32, 2556u32),
+        (2558u32, 2558u32),
+        (2561u32, 2563u32),
+        (2565u32, 2570u32),
+        (2575u32, 2576u32),
+        (2579u32, 2600u32),
+        (2602u32, 2608u32),
+        (2610u32, 2611u32),
+        (2613u32, 2614u32),
+        (2616u32, 2617u32),
+        (2620u32, 2620u32),
+        (2622u32, 2626u32),
+        (2631u32, 2632u32),
+        (2635u32, 2637u32),
+        (2641u32, 2641u32),
+        (2649u32, 2652u32),
+        (2654u32, 2654u32),
+        (2662u32, 2677u32),
+        (2689u32, 2691u32),
+        (2693u32, 2701u32),
+        (2703u32, 2705u32),
+        (2707u32, 2728u32),
+        (2730u32, 2736u32),
+        (2738u32, 2739u32),
+        (2741u32, 2745u32),
+        (2748u32, 2757u32),
+        (2759u32, 2761u32),
+        (2763u32, 2765u32),
+        (2768u32, 2768u32),
+        (2784u32, 2787u32),
+        (2790u32, 2799u32),
+        (2809u32, 2815u32),
+        (2817u32, 2819u32),
+        (2821u32, 2828u32),
+        (2831u32, 2832u32),
+        (2835u32, 2856u32),
+        (2858u32, 2864u32),
+        (2866u32, 2867u32),
+        (2869u32, 2873u32),
+        (2876u32, 2884u32),
+        (2887u32, 2888u32),
+        (2891u32, 2893u32),
+        (2901u32, 2903u32),
+        (2908u32, 2909u32),
+        (2911u32, 2915u32),
+        (2918u32, 2927u32),
+        (2929u32, 2929u32),
+        (2946u32, 2947u32),
+        (2949u32, 2954u32),
+        (2958u32, 2960u32),
+        (2962u32, 2965u32),
+        (2969u32, 2970u32),
+        (2972u32, 2972u32),
+        (2974u32, 2975u32),
+        (2979u32, 2980u32),
+        (2984u32, 2986u32),
+        (2990u32, 3001u32),
+        (3006u32, 3010u32),
+        (3014u32, 3016u32),
+        (3018u32, 3021u32),
+        (3024u32, 3024u32),
+        (3031u32, 3031u32),
+        (3046u32, 3055u32),
+        (3072u32, 3084u32),
+        (3086u32, 3088u32),
+        (3090u32, 3112u32),
+        (3114u32, 3129u32),
+        (3132u32, 3140u32),
+        (3142u32, 3144u32),
+        (3146u32, 3149u32),
+        (3157u32, 3158u32),
+        (3160u32, 3162u32),
+        (3165u32, 3165u32),
+        (3168u32, 3171u32),
+        (3174u32, 3183u32),
+        (3200u32, 3203u32),
+        (3205u32, 3212u32),
+        (3214u32, 3216u32),
+        (3218u32, 3240u32),
+        (3242u32, 3251u32),
+        (3253u32, 3257u32),
+        (3260u32, 3268u32),
+        (3270u32, 3272u32),
+        (3274u32, 3277u32),
+        (3285u32, 3286u32),
+        (3293u32, 3294u32),
+        (3296u32, 3299u32),
+        (3302u32, 3311u32),
+        (3313u32, 3315u32),
+        (3328u32, 3340u32),
+        (3342u32, 3344u32),
+        (3346u32, 3396u32),
+        (3398u32, 3400u32),
+        (3402u32, 3406u32),
+        (3412u32, 3415u32),
+        (3423u32, 3427u32),
+        (3430u32, 3439u32),
+        (3450u32, 3455u32),
+        (3457u32, 3459u32),
+        (3461u32, 3478u32),
+        (3482u32, 3505u32),
+        (3507u32, 3515u32),
+        (3517u32, 3517u32),
+        (3520u32, 3526u32),
+        (3530u32, 3530u32),
+        (3535u32, 3540u32),
+        (3542u32, 3542u32),
+        (3544u32, 3551u32),
+        (3558u32, 3567u32),
+        (3570u32, 3571u32),
+        (3585u32, 3642u32),
+        (3648u32, 3662u32),
+        (3664u32, 3673u32),
+        (3713u32, 3714u32),
+        (3716u32, 3716u32),
+        (3718u32, 3722u32),
+        (3724u32, 3747u32),
+        (3749u32, 3749u32),
+        (3751u32, 3773u32),
+        (3776u32, 3780u32),
+        (3782u32, 3782u32),
+        (3784u32, 3790u32),
+        (3792u32, 3801u32),
+        (3804u32, 3807u32),
+        (3840u32, 3840u32),
+        (3864u32, 3865u32),
+        (3872u32, 3881u32),
+        (3893u32, 3893u32),
+        (3895u32, 3895u32),
+        (3897u32, 3897u32),
+        (3902u32, 3911u32),
+        (3913u32, 3948u32),
+        (3953u32, 3972u32),
+        (3974u32, 3991u32),
+        (3993u32, 4028u32),
+        (4038u32, 4038u32),
+        (4096u32, 4169u32),
+        (4176u32, 4253u32),
+        (4256u32, 4293u32),
+        (4295u32, 4295u32),
+        (4301u32, 4301u32),
+        (4304u32, 4346u32),
+        (4348u32, 4680u32),
+        (4682u32, 4685u32),
+        (4688u32, 4694u32),
+        (4696u32, 4696u32),
+        (4698u32, 4701u32),
+        (4704u32, 4744u32),
+        (4746u32, 4749u32),
+        (4752u32, 4784u32),
+        (4786u32, 4789u32),
+        (4792u32, 4798u32),
+        (4800u32, 4800u32),
+        (4802u32, 4805u32),
+        (4808u32, 4822u32),
+        (4824u32, 4880u32),
+        (4882u32, 4885u32),
+        (4888u32, 4954u32),
+        (4957u32, 4959u32),
+        (4969u32, 4977u32),
+        (4992u32, 5007u32),
+        (5024u32, 5109u32),
+        (5112u32, 5117u32),
+        (5121u32, 5740u32),
+        (5743u32, 5759u32),
+        (5761u32, 5786u32),
+        (5792u32, 5866u32),
+        (5870u32, 5880u32),
+        (5888u32, 5909u32),
+        (5919u32, 5940u32),
+        (5952u32, 5971u32),
+        (5984u32, 5996u32),
+        (5998u32, 6000u32),
+        (6002u32, 6003u32),
+        (6016u32, 6099u32),
+        (6103u32, 6103u32),
+        (6108u32, 6109u32),
+        (6112u32, 6121u32),
+        (6155u32, 6157u32),
+        (6159u32, 6169u32),
+        (6176u32, 6264u32),
+        (6272u32, 6314u32),
+        (6320u32, 6389u32),
+        (6400u32, 6430u32),
+        (6432u32, 6443u32),
+        (6448u32, 6459u32),
+        (6470u32, 6509u32),
+        (6512u32, 6516u32),
+        (6528u32, 6571u32),
+        (6576u32, 6601u32),
+        (6608u32, 6618u32),
+        (6656u32, 6683u32),
+        (6688u32, 6750u32),
+        (6752u32, 6780u32),
+        (6783u32, 6793u32),
+        (6800u32, 6809u32),
+        (6823u32, 6823u32),
+        (6832u32, 6845u32),
+        (6847u32, 6862u32),
+        (6912u32, 6988u32),
+        (6992u32, 7001u32),
+        (7019u32, 7027u32),
+        (7040u32, 7155u32),
+        (7168u32, 7223u32),
+        (7232u32, 7241u32),
+        (7245u32, 7293u32),
+        (7296u32, 7306u32),
+        (7312u32, 7354u32),
+        (7357u32, 7359u32),
+        (7376u32, 7378u32),
+        (7380u32, 7418u32),
+        (7424u32, 7957u32),
+        (7960u32, 7965u32),
+        (7968u32, 8005u32),
+        (8008u32, 8013u32),
+        (8016u32, 8023u32),
+        (8025u32, 8025u32),
+        (8027u32, 8027u32),
+        (8029u32, 8029u32),
+        (8031u32, 8061u32),
+        (8064u32, 8116u32),
+        (8118u32, 8124u32),
+        (8126u32, 8126u32),
+        (8130u32, 8132u32),
+        (8134u32, 8140u32),
+        (8144u32, 8147u32),
+        (8150u32, 8155u32),
+        (8160u32, 8172u32),
+        (8178u32, 8180u32),
+        (8182u32, 8188u32),
+        (8204u32, 8205u32),
+        (8255u32, 8256u32),
+        (8276u32, 8276u32),
+        (8305u32, 8305u32),
+        (8319u32, 8319u32),
+        (8336u32, 8348u32),
+        (8400u32, 8412u32),
+        (8417u32, 8417u32),
+        (8421u32, 8432u32),
+        (8450u32, 8450u32),
+        (8455u32, 8455u32),
+        (8458u32, 8467u32),
+        (8469u32, 8469u32),
+        (8472u32, 8477u32),
+        (8484u32, 8484u32),
+        (8486u32, 8486u32),
+        (8488u32, 8488u32),
+        (8490u32, 8505u32),
+        (8508u32, 8511u32),
+        (8517u32, 8521u32),
+        (8526u32, 8526u32),
+        (8544u32, 8584u32),
+        (11264u32, 11492u32),
+        (11499u32, 11507u32),
+        (11520u32, 11557u32),
+        (11559u32, 11559u32),
+        (11565u32, 11565u32),
+        (11568u32, 11623u32),
+        (11631u32, 11631u32),
+        (11647u32, 11670u32),
+        (11680u32, 11686u32),
+        (11688u32, 11694u32),
+        (11696u32, 11702u32),
+        (11704u32, 11710u32),
+        (11712u32, 11718u32),
+        (11720u32, 11726u32),
+        (11728u32, 11734u32),
+        (11736u32, 11742u32),
+        (11744u32, 11775u32),
+        (12293u32, 12295u32),
+        (12321u32, 12335u32),
+        (12337u32, 12341u32),
+        (12344u32, 12348u32),
+        (12353u32, 12438u32),
+        (12441u32, 12442u32),
+        (12445u32, 12447u32),
+        (12449u32, 12543u32),
+        (12549u32, 12591u32),
+        (12593u32, 12686u32),
+        (12704u32, 12735u32),
+        (12784u32, 12799u32),
+        (13312u32, 19903u32),
+        (19968u32, 42124u32),
+        (42192u32, 42237u32),
+        (42240u32, 42508u32),
+        (42512u32, 42539u32),
+        (42560u32, 42607u32),
+        (42612u32, 42621u32),
+        (42623u32, 42737u32),
+        (42775u32, 42783u32),
+        (42786u32, 42888u32),
+        (42891u32, 42957u32),
+        (42960u32, 42961u32),
+        (42963u32, 42963u32),
+        (42965u32, 42972u32),
+        (42994u32, 43047u32),
+        (43052u32, 43052u32),
+        (43072u32, 43123u32),
+        (43136u32, 43205u32),
+        (43216u32, 43225u32),
+        (43232u32, 43255u32),
+        (43259u32, 43259u32),
+        (43261u32, 43309u32),
+        (43312u32, 43347u32),
+        (43360u32, 43388u32),
+        (43392u32, 43456u32),
+        (43471u32, 43481u32),
+        (43488u32, 43518u32),
+        (43520u32, 43574u32),
+        (43584u32, 43597u32),
+        (43600u32, 43609u32),
+        (43616u32, 43638u32),
+        (43642u32, 43714u32),
+        (43739u32, 43741u32),
+        (43744u32, 43759u32),
+        (43762u32, 43766u32),
+        (43777u32, 43782u32),
+        (43785u32, 43790u32),
+        (43793u32, 43798u32),
+        (43808u32, 43814u32),
+        (43816u32, 43822u32),
+        (43824u32, 43866u32),
+        (43868u32, 43881u32),
+        (43888u32, 44010u32),
+        (44012u32, 44013u32),
+        (44016u32, 44025u32),
+        (44032u32, 55203u32),
+        (55216u32, 55238u32),
+        (55243u32, 55291u32),
+        (63744u32, 64109u32),
+        (64112u32, 64217u32),
+        (64256u32, 64262u32),
+        (64275u32, 64279u32),
+        (64285u32, 64296u32),
+        (64298u32, 64310u32),
+        (64312u32, 64316u32),
+        (64318u32, 64318u32),
+        (64320u32, 64321u32),
+        (64323u32, 64324u32),
+        (64326u32, 64433u32),
+        (64467u32, 64605u32),
+        (64612u32, 64829u32),
+        (64848u32, 64911u32),
+        (64914u32, 64967u32),
+        (65008u32, 65017u32),
+        (65024u32, 65039u32),
+        (65056u32, 65071u32),
+        (65075u32, 65076u32),
+        (65101u32, 65103u32),
+        (65137u32, 65137u32),
+        (65139u32, 65139u32),
+        (65143u32, 65143u32),
+        (65145u32, 65145u32),
+        (65147u32, 65147u32),
+        (65149u32, 65149u32),
+        (65151u32, 65276u32),
+        (65296u32, 65305u32),
+        (65313u32, 65338u32),
+        (65343u32, 65343u32),
+        (65345u32, 65370u32),
+        (65381u32, 65470u32),
+        (65474u32, 65479u32),
+        (65482u32, 65487u32),
+        (65490u32, 65495u32),
+        (65498u32, 65500u32),
+        (65536u32, 65547u32),
+        (65549u32, 65574u32),
+        (65576u32, 65594u32),
+        (65596u32, 65597u32),
+        (65599u32, 65613u32),
+        (65616u32, 65629u32),
+        (65664u32, 65786u32),
+        (65856u32, 65908u32),
+        (66045u32, 66045u32),
+        (66176u32, 66204u32),
+        (66208u32, 66256u32),
+        (66272u32, 66272u32),
+        (66304u32, 66335u32),
+        (66349u32, 66378u32),
+        (66384u32, 66426u32),
+        (66432u32, 66461u32),
+        (66464u32, 66499u32),
+        (66504u32, 66511u32),
+        (66513u32, 66517u32),
+        (66560u32, 66717u32),
+        (66720u32, 66729u32),
+        (66736u32, 66771u32),
+        (66776u32, 66811u32),
+        (66816u32, 66855u32),
+        (66864u32, 66915u32),
+        (66928u32, 66938u32),
+        (66940u32, 66954u32),
+        (66956u32, 66962u32),
+        (66964u32, 66965u32),
+        (66967u32, 66977u32),
+        (66979u32, 66993u32),
+        (66995u32, 67001u32),
+        (67003u32, 67004u32),
+        (67008u32, 67059u32),
+        (67072u32, 67382u32),
+        (67392u32, 67413u32),
+        (67424u32, 67431u32),
+        (67456u32, 67461u32),
+        (67463u32, 67504u32),
+        (67506u32, 67514u32),
+        (67584u32, 67589u32),
+        (67592u32, 67592u32),
+        (67594u32, 67637u32),
+        (67639u32, 67640u32),
+        (67644u32, 67644u32),
+        (67647u32, 67669u32),
+        (67680u32, 67702u32),
+        (67712u32, 67742u32),
+        (67808u32, 67826u32),
+        (67828u32, 67829u32),
+        (67840u32, 67861u32),
+        (67872u32, 67897u32),
+        (67968u32, 68023u32),
+        (68030u32, 68031u32),
+        (68096u32, 68099u32),
+        (68101u32, 68102u32),
+        (68108u32, 68115u32),
+        (68117u32, 68119u32),
+        (68121u32, 68149u32),
+        (68152u32, 68154u32),
+        (68159u32, 68159u32),
+        (68192u32, 68220u32),
+        (68224u32, 68252u32),
+        (68288u32, 68295u32),
+        (68297u32, 68326u32),
+        (68352u32, 68405u32),
+        (68416u32, 68437u32),
+        (68448u32, 68466u32),
+        (68480u32, 68497u32),
+        (68608u32, 68680u32),
+        (68736u32, 68786u32),
+        (68800u32, 68850u32),
+        (68864u32, 68903u32),
+        (68912u32, 68921u32),
+        (68928u32, 68965u32),
+        (68969u32, 68973u32),
+        (68975u32, 68997u32),
+        (69248u32, 69289u32),
+        (69291u32, 69292u32),
+        (69296u32, 69297u32),
+        (69314u32, 69316u32),
+        (69372u32, 69404u32),
+        (69415u32, 69415u32),
+        (69424u32, 69456u32),
+        (69488u32, 69509u32),
+        (69552u32, 69572u32),
+        (69600u32, 69622u32),
+        (69632u32, 69702u32),
+        (69734u32, 69749u32),
+        (69759u32, 69818u32),
+        (69826u32, 69826u32),
+        (69840u32, 69864u32),
+        (69872u32, 69881u32),
+        (69888u32, 69940u32),
+        (69942u32, 69951u32),
+        (69956u32, 69959u32),
+        (69968u32, 70003u32),
+        (70006u32, 70006u32),
+        (70016u32, 70084u32),
+        (70089u32, 70092u32),
+        (70094u32, 70106u32),
+        (70108u32, 70108u32),
+        (70144u32, 70161u32),
+        (70163u32, 70199u32),
+        (70206u32, 70209u32),
+        (70272u32, 70278u32),
+        (70280u32, 70280u32),
+        (70282u32, 70285u32),
+        (70287u32, 70301u32),
+        (70303u32, 70312u32),
+        (70320u32, 70378u32),
+        (70384u32, 70393u32),
+        (70400u32, 70403u32),
+        (70405u32, 70412u32),
+        (70415u32, 70416u32),
+        (70419u32, 70440u32),
+        (70442u32, 70448u32),
+        (70450u32, 70451u32),
+        (70453u32, 70457u32),
+        (70459u32, 70468u32),
+        (70471u32, 70472u32),
+        (70475u32, 70477u32),
+        (70480u32, 70480u32),
+        (70487u32, 70487u32),
+        (70493u32, 70499u32),
+        (70502u32, 70508u32),
+        (70512u32, 70516u32),
+        (70528u32, 70537u32),
+        (70539u32, 70539u32),
+        (70542u32, 70542u32),
+        (70544u32, 70581u32),
+        (70583u32, 70592u32),
+        (70594u32, 70594u32),
+        (70597u32, 70597u32),
+        (70599u32, 70602u32),
+        (70604u32, 70611u32),
+        (70625u32, 70626u32),
+        (70656u32, 70730u32),
+        (70736u32, 70745u32),
+        (70750u32, 70753u32),
+        (70784u32, 70853u32),
+        (70855u32, 70855u32),
+        (70864u32, 70873u32),
+        (71040u32, 71093u32),
+        (71096u32, 71104u32),
+        (71128u32, 71133u32),
+        (71168u32, 71232u32),
+        (71236u32, 71236u32),
+        (71248u32, 71257u32),
+        (71296u32, 71352u32),
+        (71360u32, 71369u32),
+        (71376u32, 71395u32),
+        (71424u32, 71450u32),
+        (71453u32, 71467u32),
+        (71472u32, 71481u32),
+        (71488u32, 71494u32),
+        (71680u32, 71738u32),
+        (71840u32, 71913u32),
+        (71935u32, 71942u32),
+        (71945u32, 71945u32),
+        (71948u32, 71955u32),
+        (71957u32, 71958u32),
+        (71960u32, 71989u32),
+        (71991u32, 71992u32),
+        (71995u32, 72003u32),
+        (72016u32, 72025u32),
+        (72096u32, 72103u32),
+        (72106u32, 72151u32),
+        (72154u32, 72161u32),
+        (72163u32, 72164u32),
+        (72192u32, 72254u32),
+        (72263u32, 72263u32),
+        (72272u32, 72345u32),
+        (72349u32, 72349u32),
+        (72368u32, 72440u32),
+        (72640u32, 72672u32),
+        (72688u32, 72697u32),
+        (72704u32, 72712u32),
+        (72714u32, 72758u32),
+        (72760u32, 72768u32),
+        (72784u32, 72793u32),
+        (72818u32, 72847u32),
+        (72850u32, 72871u32),
+        (72873u32, 72886u32),
+        (72960u32, 72966u32),
+        (72968u32, 72969u32),
+        (72971u32, 73014u32),
+        (73018u32, 73018u32),
+        (73020u32, 73021u32),
+        (73023u32, 73031u32),
+        (73040u32, 73049u32),
+        (73056u32, 73061u32),
+        (73063u32, 73064u32),
+        (73066u32, 73102u32),
+        (73104u32, 73105u32),
+        (73107u32, 73112u32),
+        (73120u32, 73129u32),
+        (73440u32, 73462u32),
+        (73472u32, 73488u32),
+        (73490u32, 73530u32),
+        (73534u32, 73538u32),
+        (73552u32, 73562u32),
+        (73648u32, 73648u32),
+        (73728u32, 74649u32),
+        (74752u32, 74862u32),
+        (74880u32, 75075u32),
+        (77712u32, 77808u32),
+        (77824u32, 78895u32),
+        (78912u32, 78933u32),
+        (78944u32, 82938u32),
+        (82944u32, 83526u32),
+        (90368u32, 90425u32),
+        (92160u32, 92728u32),
+        (92736u32, 92766u32),
+        (92768u32, 92777u32),
+        (92784u32, 92862u32),
+        (92864u32, 92873u32),
+        (92880u32, 92909u32),
+        (92912u32, 92916u32),
+        (92928u32, 92982u32),
+        (92992u32, 92995u32),
+        (93008u32, 93017u32),
+        (93027u32, 93047u32),
+        (93053u32, 93071u32),
+        (93504u32, 93548u32),
+        (93552u32, 93561u32),
+        (93760u32, 93823u32),
+        (93952u32, 94026u32),
+        (94031u32, 94087u32),
+        (94095u32, 94111u32),
+        (94176u32, 94177u32),
+        (94179u32, 94180u32),
+        (94192u32, 94193u32),
+        (94208u32, 100343u32),
+        (100352u32, 101589u32),
+        (101631u32, 101640u32),
+        (110576u32, 110579u32),
+        (110581u32, 110587u32),
+        (110589u32, 110590u32),
+        (110592u32, 110882u32),
+        (110898u32, 110898u32),
+        (110928u32, 110930u32),
+        (110933u32, 110933u32),
+        (110948u32, 110951u32),
+        (110960u32, 111355u32),
+        (113664u32, 113770u32),
+        (113776u32, 113788u32),
+        (113792u32, 113800u32),
+        (113808u32, 113817u32),
+        (113821u32, 113822u32),
+        (118000u32, 118009u32),
+        (118528u32, 118573u32),
+        (118576u32, 118598u32),
+        (119141u32, 119145u32),
+        (119149u32, 119154u32),
+        (119163u32, 119170u32),
+        (119173u32, 119179u32),
+        (119210u32, 119213u32),
+        (119362u32, 119364u32),
+        (119808u32, 119892u32),
+        (119894u32, 119964u32),
+        (119966u32, 119967u32),
+        (119970u32, 119970u32),
+        (119973u32, 119974u32),
+        (119977u32, 119980u32),
+        (119982u32, 119993u32),
+        (119995u32, 119995u32),
+        (119997u32, 120003u32),
+        (120005u32, 120069u32),
+        (120071u32, 120074u32),
+        (120077u32, 120084u32),
+        (120086u32, 120092u32),
+        (120094u32, 120121u32),
+        (120123u32, 120126u32),
+        (120128u32, 120132u32),
+        (120134u32, 120134u32),
+        (120138u32, 120144u32),
+        (120146u32, 120485u32),
+        (120488u32, 120512u32),
+        (120514u32, 120538u32),
+        (120540u32, 120570u32),
+        (120572u32, 120596u32),
+        (120598u32, 120628u32),
+        (120630u32, 120654u32),
+        (120656u32, 120686u32),
+        (120688u32, 120712u32),
+        (120714u32, 120744u32),
+        (120746u32, 120770u32),
+        (120772u32, 120779u32),
+        (120782u32, 120831u32),
+        (121344u32, 121398u32),
+        (121403u32, 121452u32),
+        (121461u32, 121461u32),
+        (121476u32, 121476u32),
+        (121499u32, 121503u32),
+        (121505u32, 121519u32),
+        (122624u32, 122654u32),
+        (122661u32, 122666u32),
+        (122880u32, 122886u32),
+        (122888u32, 122904u32),
+        (122907u32, 122913u32),
+        (122915u32, 122916u32),
+        (122918u32, 122922u32),
+        (122928u32, 122989u32),
+        (123023u32, 123023u32),
+        (123136u32, 123180u32),
+        (123184u32, 123197u32),
+        (123200u32, 123209u32),
+        (123214u32, 123214u32),
+        (123536u32, 123566u32),
+        (123584u32, 123641u32),
+        (124112u32, 124153u32),
+        (124368u32, 124410u32),
+        (124896u32, 124902u32),
+        (124904u32, 124907u32),
+        (124909u32, 124910u32),
+        (124912u32, 124926u32),
+        (124928u32, 125124u32),
+        (125136u32, 125142u32),
+        (125184u32, 125259u32),
+        (125264u32, 125273u32),
+        (126464u32, 126467u32),
+        (126469u32, 126495u32),
+        (126497u32, 126498u32),
+        (126500u32, 126500u32),
+        (126503u32, 126503u32),
+        (126505u32, 126514u32),
+        (126516u32, 126519u32),
+        (126521u32, 126521u32),
+        (126523u32, 126523u32),
+        (126530u32, 126530u32),
+        (126535u32, 126535u32),
+        (126537u32, 126537u32),
+        (126539u32, 126539u32),
+        (126541u32, 126543u32),
+        (126545u32, 126546u32),
+        (126548u32, 126548u32),
+        (126551u32, 126551u32),
+        (126553u32, 126553u32),
+        (126555u32, 126555u32),
+        (126557u32, 126557u32),
+        (126559u32, 126559u32),
+        (126561u32, 126562u32),
+        (126564u32, 126564u32),
+        (126567u32, 126570u32),
+        (126572u32, 126578u32),
+        (126580u32, 126583u32),
+        (126585u32, 126588u32),
+        (126590u32, 126590u32),
+        (126592u32, 126601u32),
+        (126603u32, 126619u32),
+        (126625u32, 126627u32),
+        (126629u32, 126633u32),
+        (126635u32, 126651u32),
+        (130032u32, 130041u32),
+        (131072u32, 173791u32),
+        (173824u32, 177977u32),
+        (177984u32, 178205u32),
+        (178208u32, 183969u32),
+        (183984u32, 191456u32),
+        (191472u32, 192093u32),
+        (194560u32, 195101u32),
+        (196608u32, 201546u32),
+        (201552u32, 205743u32),
+        (917760u32, 917999u32),
+    ];
+    pub struct Parser<A: Actions> {
+        actions: A,
+        __pins: Pins,
+        __state: State,
+        __state_enter: bool,
+        __is_last_input: bool,
+        __pos: usize,
+        __chunk_offset: usize,
+    }
+    impl<A: Actions> Deref for Parser<A> {
+        type Target = A;
+        fn deref(&self) -> &A {
+            &self.actions
+        }
+    }
+    impl<A: Actions> DerefMut for Parser<A> {
+        fn deref_mut(&mut self) -> &mut A {
+            &mut self.actions
+        }
+    }
+    impl<A: Actions> Parser<A> {
+        pub fn new(actions: A) -> Self {
+            Parser {
+                actions,
+                __pins: Pins::default(),
+                __state: State::data_state,
+                __state_enter: true,
+                __is_last_input: false,
+                __pos: 0,
+                __chunk_offset: 0,
+            }
+        }
+        pub fn state(&self) -> State {
+            self.__state
+        }
+        pub fn pins(&self) -> &Pins {
+            &self.__pins
+        }
+        pub fn into_actions(self) -> A {
+            self.actions
+        }
+        #[doc = r" Parses the chunk of input and returns the number of bytes at the"]
+        #[doc = r" end of the chunk that were not consumed or are pinned. These bytes"]
+        #[doc = r" should be prepended to the next chunk."]
+        pub fn run_parsing_loop(&mut self, input: &[u8], is_last: bool) -> Result<usize, ParsingError> {
+            self.__start_chunk(is_last);
+            let blocked_byte_count = self.__run(input)?;
+            Ok(self.__end_chunk(input, blocked_byte_count))
+        }
+        fn __start_chunk(&mut self, is_last: bool) {
+            self.__is_last_input = is_last;
+        }
+        fn __end_chunk(&mut self, input: &[u8], blocked_byte_count: usize) -> usize {
+            let consumed_byte_count = input.len() - blocked_byte_count;
+            let released_byte_count = consumed_byte_count;
+            self.__chunk_offset += released_byte_count;
+            self.__pos = consumed_byte_count - released_byte_count;
+            let blocked_byte_count = input.len() - released_byte_count;
+            blocked_byte_count
+        }
+        fn __run(&mut self, input: &[u8]) -> Result<usize, ParsingError> {
+            loop {
+                let ch = input.get(self.__pos).cloned();
+                match self.__state {
+                    State::data_state => {
+                        let mut code_point_len: usize;
+                        match ch {
+                            None if !self.__is_last_input => {
+                                return Ok(0);
+                            }
+                            None => {
+                                self.done();
+                                return Ok(0);
+                            }
+                            Some(_)
+                                if {
+                                    code_point_len = match __decode_utf8(&input[self.__pos..], self.__is_last_input) {
+                                        Utf8Sequence::CodePoint(c, len) if __has_code_point(__XID_START_RANGES, c) => {
+                                            len
+                                        }
+                                        Utf8Sequence::Blocked => return Ok(input.len() - self.__pos),
+                                        _ => 0,
+                                    };
+                                    code_point_len > 0
+                                } =>
+                            {
+                                {
+                                    let arg0 = (self.__chunk_offset + self.__pos);
+                                    self.ident_start(arg0)
+                                };
+                                self.__state = State::ident_state;
+                                self.__state_enter = true;
+                                self.__pos += code_point_len;
+                            }
+                            Some(_)
+                                if {
+                                    code_point_len = match __decode_utf8(&input[self.__pos..], self.__is_last_input) {
+                                        Utf8Sequence::CodePoint(c, len)
+                                            if __has_code_point(__WHITE_SPACE_RANGES, c) =>
+                                        {
+                                            len
+                                        }
+                                        Utf8Sequence::Blocked => return Ok(input.len() - self.__pos),
+                                        _ => 0,
+                                    };
+                                    code_point_len > 0
+                                } =>
+                            {
+                                {
+                                    let arg0 = (self.__chunk_offset + self.__pos);
+                                    self.space(arg0)
+                                };
+                                self.__pos += code_point_len;
+                            }
+                            Some(b) if b & 15u8 == 10u8 => {
+                                {
+                                    let arg0 = ch;
+                                    self.masked(arg0)
+                                };
+                                self.__pos += 1;
+                            }
+                            Some(_)
+                                if {
+                                    code_point_len = match __decode_utf8(&input[self.__pos..], self.__is_last_input) {
+                                        Utf8Sequence::Invalid(len) => len,
+                                        Utf8Sequence::Blocked => return Ok(input.len() - self.__pos),
+                                        _ => 0,
+                                    };
+                                    code_point_len > 0
+                                } =>
+                            {
+                                {
+                                    let arg0 = (self.__chunk_offset + self.__pos);
+                                    self.invalid(arg0)
+                                };
+                                self.__pos += code_point_len;
+                            }
+                            Some(_) => {
+                                {
+                                    let arg0 = (self.__chunk_offset + self.__pos);
+                                    self.other(arg0)
+                                };
+                                self.__pos += 1;
+                            }
+                            Some(_) => {
+                                self.__pos += 1;
+                            }
+                            None => {
+                                return Ok(0);
+                            }
+                        }
+                    }
+                    State::ident_state => {
+                        let mut code_point_len: usize;
+                        match ch {
+                            None if !self.__is_last_input => {
+                                return Ok(0);
+                            }
+                            None => {
+                                {
+                                    let arg0 = (self.__chunk_offset + self.__pos);
+                                    self.ident_eof(arg0)
+                                };
+                                return Ok(0);
+                            }
+                            Some(_)
+                                if {
+                                    code_point_len = match __decode_utf8(&input[self.__pos..], self.__is_last_input) {
+                                        Utf8Sequence::CodePoint(c, len)
+                                            if __has_code_point(__XID_CONTINUE_RANGES, c) =>
+                                        {
+                                            len
+                                        }
+                                        Utf8Sequence::Blocked => return Ok(input.len() - self.__pos),
+                                        _ => 0,
+                                    };
+                                    code_point_len > 0
+                                } =>
+                            {
+                                self.__pos += code_point_len;
+                            }
+                            Some(_)
+                                if {
+                                    code_point_len = match __decode_utf8(&input[self.__pos..], self.__is_last_input) {
+                                        Utf8Sequence::Invalid(len) => len,
+                                        Utf8Sequence::Blocked => return Ok(input.len() - self.__pos),
+                                        _ => 0,
+                                    };
+                                    code_point_len > 0
+                                } =>
+                            {
+                                {
+                                    let arg0 = (self.__chunk_offset + self.__pos);
+                                    self.invalid(arg0)
+                                };
+                                self.__state = State::data_state;
+                                self.__state_enter = true;
+                                self.__pos += code_point_len;
+                            }
+                            Some(_) => {
+                                {
+                                    let arg0 = (self.__chunk_offset + self.__pos);
+                                    self.ident_end(arg0)
+                                };
+                                self.__state = State::data_state;
+                                self.__state_enter = true;
+                            }
+                            Some(_) => {
+                                self.__pos += 1;
+                            }
+                            None => {
+                                return Ok(0);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        fn __match_sequence(&self, input: &[u8], sequence: &[u8], ignore_case: bool) -> SequenceMatch {
+            let available = &input[self.__pos..];
+            let len = available.len().min(sequence.len());
+            let matches = if ignore_case {
+                available[..len].eq_ignore_ascii_case(&sequence[..len])
+            } else {
+                available[..len] == sequence[..len]
+            };
+            if !matches {
+                SequenceMatch::Mismatch
+            } else if len == sequence.len() {
+                SequenceMatch::Full
+            } else if self.__is_last_input {
+                SequenceMatch::Mismatch
+            } else {
+                SequenceMatch::Partial
+            }
+        }
+        fn __match_sequence_with(
+            &self,
+            input: &[u8],
+            len: usize,
+            element_matches: impl Fn(usize, u8) -> bool,
+        ) -> SequenceMatch {
+            let available = &input[self.__pos..];
+            let matches = available
+                .iter()
+                .take(len)
+                .enumerate()
+                .all(|(i, &b)| element_matches(i, b));
+            if !matches {
+                SequenceMatch::Mismatch
+            } else if available.len() >= len {
+                SequenceMatch::Full
+            } else if self.__is_last_input {
+                SequenceMatch::Mismatch
+            } else {
+                SequenceMatch::Partial
+            }
+        }
+        fn __error(&self, error: Error, state: &'static str, byte: Option<u8>) -> ParsingError {
+            ParsingError {
+                state,
+                offset: self.__chunk_offset + self.__pos,
+                byte,
+                error,
+            }
+        }
+        fn __pin_slice<'i>(&self, input: &'i [u8], pin: Option<usize>) -> Option<&'i [u8]> {
+            pin.map(|p| &input[p - self.__chunk_offset..self.__pos])
+        }
+        fn __skip_until(&self, input: &[u8], delimiters: &[u8]) -> usize {
+            __find_delimiter(&input[self.__pos..], delimiters)
+        }
+        fn __skip_by_table(&self, input: &[u8], skippable: &[bool; 256]) -> usize {
+            input[self.__pos..]
+                .iter()
+                .take_while(|&&b| skippable[b as usize])
+                .count()
+        }
+    }
+    #[doc = r" Returns the position of the first delimiter in the haystack (or the haystack's"]
+    #[doc = r" length if there are no delimiters), using the widest SIMD instruction set"]
+    #[doc = r" that is available on the CPU."]
+    #[allow(unreachable_code)]
+    fn __find_delimiter(haystack: &[u8], delimiters: &[u8]) -> usize {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx2") {
+                return unsafe { __find_delimiter_avx2(haystack, delimiters) };
+            }
+            return unsafe { __find_delimiter_sse2(haystack, delimiters) };
+        }
+        #[cfg(target_arch = "aarch64")]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") {
+                return unsafe { __find_delimiter_neon(haystack, delimiters) };
+            }
+        }
+        __find_delimiter_swar(haystack, delimiters)
+    }
+    fn __find_delimiter_swar(haystack: &[u8], delimiters: &[u8]) -> usize {
+        const LO: u64 = 0x0101_0101_0101_0101;
+        const HI: u64 = 0x8080_8080_8080_8080;
+        let has_delimiter = |word: u64| {
+            delimiters.iter().any(|&d| {
+                let x = word ^ (LO * d as u64);
+                x.wrapping_sub(LO) & !x & HI != 0
+            })
+        };
+        let mut pos = 0;
+        while pos + 8 <= haystack.len() {
+            let mut word = [0; 8];
+            word.copy_from_slice(&haystack[pos..pos + 8]);
+            if has_delimiter(u64::from_le_bytes(word)) {
+                break;
+            }
+            pos += 8;
+        }
+        while pos < haystack.len() && !delimiters.contains(&haystack[pos]) {
+            pos += 1;
+        }
+        pos
+    }
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "sse2")]
+    unsafe fn __find_delimiter_sse2(haystack: &[u8], delimiters: &[u8]) -> usize {
+        use std::arch::x86_64::*;
+        let mut pos = 0;
+        unsafe {
+            while pos + 16 <= haystack.len() {
+                let chunk = _mm_loadu_si128(haystack.as_ptr().add(pos) as *const __m128i);
+                let mut eq = _mm_setzero_si128();
+                for &d in delimiters {
+                    eq = _mm_or_si128(eq, _mm_cmpeq_epi8(chunk, _mm_set1_epi8(d as i8)));
+                }
+                let mask = _mm_movemask_epi8(eq) as u32;
+                if mask != 0 {
+                    return pos + mask.trailing_zeros() as usize;
+                }
+                pos += 16;
+            }
+        }
+        pos + __find_delimiter_swar(&haystack[pos..], delimiters)
+    }
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "avx2")]
+    unsafe fn __find_delimiter_avx2(haystack: &[u8], delimiters: &[u8]) -> usize {
+        use std::arch::x86_64::*;
+        let mut pos = 0;
+        unsafe {
+            while pos + 32 <= haystack.len() {
+                let chunk = _mm256_loadu_si256(haystack.as_ptr().add(pos) as *const __m256i);
+                let mut eq = _mm256_setzero_si256();
+                for &d in delimiters {
+                    eq = _mm256_or_si256(eq, _mm256_cmpeq_epi8(chunk, _mm256_set1_epi8(d as i8)));
+                }
+                let mask = _mm256_movemask_epi8(eq) as u32;
+                if mask != 0 {
+                    return pos + mask.trailing_zeros() as usize;
+                }
+                pos += 32;
+            }
+            pos + __find_delimiter_sse2(&haystack[pos..], delimiters)
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    #[target_feature(enable = "neon")]
+    unsafe fn __find_delimiter_neon(haystack: &[u8], delimiters: &[u8]) -> usize {
+        use std::arch::aarch64::*;
+        let mut pos = 0;
+        unsafe {
+            while pos + 16 <= haystack.len() {
+                let chunk = vld1q_u8(haystack.as_ptr().add(pos));
+                let mut eq = vdupq_n_u8(0);
+                for &d in delimiters {
+                    eq = vorrq_u8(eq, vceqq_u8(chunk, vdupq_n_u8(d)));
+                }
+                if vmaxvq_u8(eq) != 0 {
+                    break;
+                }
+                pos += 16;
+            }
+        }
+        pos + __find_delimiter_swar(&haystack[pos..], delimiters)
+    }
+}
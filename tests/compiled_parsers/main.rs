@@ -0,0 +1,14 @@
+//! Compiled parsers tested against the interpreter.
+//!
+//! Parsers are compiled from the grammars in `grammars/` and checked in to
+//! `generated/`, so they can be tested without a procedural macro crate.
+//! Run the tests with `UPDATE_GENERATED=1` to update them after changes
+//! of the grammars or the compiler.
+
+mod chunk_boundaries;
+mod delimiter_search;
+mod generated;
+mod table_dispatch;
+mod tokens;
+mod trivial_states;
+mod utf8;
@@ -0,0 +1,146 @@
+use super::generated::grammar;
+use pilot::testing::*;
+
+#[derive(Default)]
+pub struct Recorder {
+    trace: Trace,
+}
+
+impl Recorder {
+    fn record(&mut self, name: &str, args: &[&dyn std::fmt::Debug]) {
+        self.trace.push(TraceEvent::action_call(name, args));
+    }
+}
+
+impl TableDispatch::Actions for Recorder {
+    fn done(&mut self) {
+        self.record("done", &[]);
+    }
+
+    fn data_enter(&mut self) {
+        self.record("data_enter", &[]);
+    }
+
+    fn number_start(&mut self, byte: Option<u8>) {
+        self.record("number_start", &[&byte]);
+    }
+
+    fn sign(&mut self, byte: Option<u8>) {
+        self.record("sign", &[&byte]);
+    }
+
+    fn control(&mut self, byte: Option<u8>, offset: usize) {
+        self.record("control", &[&byte, &offset]);
+    }
+
+    fn lt(&mut self) {
+        self.record("lt", &[]);
+    }
+
+    fn lead_byte(&mut self, byte: Option<u8>) {
+        self.record("lead_byte", &[&byte]);
+    }
+
+    fn non_ascii(&mut self) {
+        self.record("non_ascii", &[]);
+    }
+
+    fn amp(&mut self, offset: usize) {
+        self.record("amp", &[&offset]);
+    }
+
+    fn other(&mut self, byte: Option<u8>) {
+        self.record("other", &[&byte]);
+    }
+
+    fn word_eof(&mut self, offset: usize) {
+        self.record("word_eof", &[&offset]);
+    }
+
+    fn underscore(&mut self) {
+        self.record("underscore", &[]);
+    }
+
+    fn word_end(&mut self, offset: usize) {
+        self.record("word_end", &[&offset]);
+    }
+
+    fn number_eof(&mut self) {
+        self.record("number_eof", &[]);
+    }
+
+    fn dot(&mut self, offset: usize) {
+        self.record("dot", &[&offset]);
+    }
+
+    fn hex_prefix(&mut self, byte: Option<u8>) {
+        self.record("hex_prefix", &[&byte]);
+    }
+
+    fn exponent_sign(&mut self, byte: Option<u8>) {
+        self.record("exponent_sign", &[&byte]);
+    }
+
+    fn number_end(&mut self) {
+        self.record("number_end", &[]);
+    }
+
+    fn tag_eof(&mut self) {
+        self.record("tag_eof", &[]);
+    }
+
+    fn gt(&mut self) {
+        self.record("gt", &[]);
+    }
+
+    fn tag_mask(&mut self, byte: Option<u8>) {
+        self.record("tag_mask", &[&byte]);
+    }
+}
+
+include!("generated/table_dispatch.rs");
+
+struct Parser(TableDispatch::Parser<Recorder>);
+
+impl StreamingParser for Parser {
+    type Error = TableDispatch::ParsingError;
+
+    fn run_parsing_loop(&mut self, input: &[u8], is_last: bool) -> Result<usize, Self::Error> {
+        self.0.run_parsing_loop(input, is_last)
+    }
+}
+
+fn compiled_trace(chunks: &[&[u8]]) -> Trace {
+    let mut parser = Parser(TableDispatch::Parser::new(Recorder::default()));
+    let result = feed_chunks(&mut parser, chunks);
+    let pins = *parser.0.pins();
+    let mut trace = parser.0.into_actions().trace;
+
+    if let Err(e) = result {
+        trace.push(TraceEvent::error(e.state, e.offset, e.byte));
+    }
+
+    trace.push(TraceEvent::pins(vec![("word", pins.word)]));
+
+    trace
+}
+
+#[test]
+fn table_dispatch() {
+    let grammar = grammar("table_dispatch");
+    let interpreted = |chunks: &[&[u8]]| interpreter_trace::<()>(&grammar, |_| (), chunks);
+
+    let mut test = DifferentialTest::new(&grammar);
+
+    test.iterations = 3000;
+    test.max_input_len = 64;
+
+    test.run(compiled_trace, interpreted)
+        .unwrap_or_else(|e| panic!("{}", e));
+
+    let input = b"foo_1 0x1F.5e+3 <a *b:>&\x01\xc3\xa9-x\x7f9";
+
+    ChunkInvarianceTest::new(&grammar)
+        .check(input, compiled_trace)
+        .unwrap_or_else(|e| panic!("{}", e));
+}
@@ -0,0 +1,121 @@
+use super::generated::grammar;
+use pilot::testing::*;
+
+#[derive(Default)]
+pub struct Recorder {
+    trace: Trace,
+}
+
+impl Tokenizer::Actions for Recorder {
+    fn done(&mut self) {
+        self.trace.push(TraceEvent::action_call("done", &[]));
+    }
+
+    fn on_token(&mut self, token: Tokenizer::Token) {
+        self.trace
+            .push(TraceEvent::token(token.name(), token.start(), token.end()));
+    }
+}
+
+include!("generated/tokens.rs");
+
+struct Parser(Tokenizer::Parser<Recorder>);
+
+impl StreamingParser for Parser {
+    type Error = Tokenizer::ParsingError;
+
+    fn run_parsing_loop(&mut self, input: &[u8], is_last: bool) -> Result<usize, Self::Error> {
+        self.0.run_parsing_loop(input, is_last)
+    }
+}
+
+fn compiled_trace(chunks: &[&[u8]]) -> Trace {
+    let mut parser = Parser(Tokenizer::Parser::new(Recorder::default()));
+
+    let result = feed_chunks(&mut parser, chunks);
+    let pins = *parser.0.pins();
+    let mut trace = parser.0.into_actions().trace;
+
+    if let Err(e) = result {
+        trace.push(TraceEvent::error(e.state, e.offset, e.byte));
+    }
+
+    trace.push(TraceEvent::pins(vec![("start", pins.start)]));
+    trace
+}
+
+#[test]
+fn emitted_tokens() {
+    let grammar = grammar("tokens");
+    let interpreted = |chunks: &[&[u8]]| interpreter_trace::<()>(&grammar, |_| (), chunks);
+
+    DifferentialTest::new(&grammar)
+        .run(compiled_trace, interpreted)
+        .unwrap_or_else(|e| panic!("{}", e));
+
+    ChunkInvarianceTest::new(&grammar)
+        .check(b"a <b1> <!-- c -->\t<de", compiled_trace)
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+fn iterated_tokens(
+    tokens: impl Iterator<Item = Result<Tokenizer::Token, Tokenizer::ParsingError>>,
+) -> Trace {
+    tokens
+        .map(|t| t.unwrap_or_else(|e| panic!("{}", e)))
+        .map(|t| TraceEvent::token(t.name(), t.start(), t.end()))
+        .collect()
+}
+
+#[test]
+fn tokens_iterator() {
+    let input = b"a <b1> <!-- c -->\t<de";
+
+    let mut expected = compiled_trace(&[input]);
+
+    // NOTE: the iterator yields only the tokens, without the final pins.
+    expected.pop();
+
+    let mut parser = Tokenizer::Parser::new(Recorder::default());
+
+    assert_eq!(iterated_tokens(parser.tokens(input)), expected);
+
+    let mut parser = Tokenizer::Parser::new(Recorder::default());
+
+    assert_eq!(
+        iterated_tokens(parser.tokens_from_chunks(input.chunks(1))),
+        expected
+    );
+
+    assert_eq!(parser.into_actions().trace, vec![]);
+}
+
+#[test]
+fn dropped_tokens_iterator() {
+    let mut parser = Tokenizer::Parser::new(Recorder::default());
+
+    // NOTE: the rest of the iterator's input is discarded.
+    assert_eq!(
+        iterated_tokens(parser.tokens(b"ab<c>").take(2)),
+        vec![
+            TraceEvent::token("Char", 0, 1),
+            TraceEvent::token("Char", 1, 2)
+        ]
+    );
+
+    parser.run_parsing_loop(b"<c>d", false).unwrap();
+
+    assert_eq!(
+        iterated_tokens(parser.tokens(b"<!--e-->")),
+        vec![TraceEvent::token("Comment", 6, 14)]
+    );
+
+    assert_eq!(
+        parser.into_actions().trace,
+        vec![
+            TraceEvent::token("Tag", 2, 5),
+            TraceEvent::token("Char", 5, 6),
+            TraceEvent::action_call("done", &[]),
+        ]
+    );
+}
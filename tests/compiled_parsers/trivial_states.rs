@@ -0,0 +1,91 @@
+use super::generated::grammar;
+use pilot::testing::*;
+
+#[derive(Default)]
+pub struct Recorder {
+    trace: Trace,
+}
+
+macro_rules! record_actions {
+    ($($name:ident),*) => {
+        impl TrivialStates::Actions for Recorder {
+            $(fn $name(&mut self) {
+                self.trace.push(TraceEvent::action_call(stringify!($name), &[]));
+            })*
+
+            fn back(&self) -> TrivialStates::State {
+                TrivialStates::State::tag_name_state
+            }
+        }
+    };
+}
+
+record_actions!(
+    done,
+    lt,
+    ab,
+    text,
+    tag_open,
+    tag_name_eof,
+    x,
+    gt,
+    tag_name,
+    enter,
+    unreachable,
+    jump,
+    bang_enter
+);
+
+include!("generated/trivial_states.rs");
+
+struct Parser(TrivialStates::Parser<Recorder>);
+
+impl StreamingParser for Parser {
+    type Error = TrivialStates::ParsingError;
+
+    fn run_parsing_loop(&mut self, input: &[u8], is_last: bool) -> Result<usize, Self::Error> {
+        self.0.run_parsing_loop(input, is_last)
+    }
+}
+
+fn compiled_trace(chunks: &[&[u8]]) -> Trace {
+    let mut parser = Parser(TrivialStates::Parser::new(Recorder::default()));
+
+    let result = feed_chunks(&mut parser, chunks);
+    let pins = *parser.0.pins();
+    let mut trace = parser.0.into_actions().trace;
+
+    if let Err(e) = result {
+        trace.push(TraceEvent::error(e.state, e.offset, e.byte));
+    }
+
+    trace.push(TraceEvent::pins(vec![
+        ("start", pins.start),
+        ("tag", pins.tag),
+    ]));
+
+    trace
+}
+
+#[test]
+fn trivial_states() {
+    let grammar = grammar("trivial_states");
+
+    let interpreted = |chunks: &[&[u8]]| {
+        interpreter_trace::<()>(
+            &grammar,
+            |actions| {
+                actions.add_state_getter("back", || "tag_name_state".into());
+            },
+            chunks,
+        )
+    };
+
+    DifferentialTest::new(&grammar)
+        .run(compiled_trace, interpreted)
+        .unwrap_or_else(|e| panic!("{}", e));
+
+    ChunkInvarianceTest::new(&grammar)
+        .check(b"<a x>ab!qxy<<!", compiled_trace)
+        .unwrap_or_else(|e| panic!("{}", e));
+}
@@ -0,0 +1,74 @@
+use super::generated::grammar;
+use pilot::testing::*;
+
+#[derive(Default)]
+pub struct Recorder {
+    trace: Trace,
+}
+
+macro_rules! record_actions {
+    ($($name:ident($($arg:ident: $ty:ty),*)),*) => {
+        impl Utf8::Actions for Recorder {
+            $(fn $name(&mut self, $($arg: $ty),*) {
+                self.trace.push(TraceEvent::action_call(stringify!($name), &[$(&$arg),*]));
+            })*
+        }
+    };
+}
+
+record_actions!(
+    done(),
+    ident_start(offset: usize),
+    space(offset: usize),
+    masked(byte: Option<u8>),
+    invalid(offset: usize),
+    other(offset: usize),
+    ident_eof(offset: usize),
+    ident_end(offset: usize)
+);
+
+include!("generated/utf8.rs");
+
+struct Parser(Utf8::Parser<Recorder>);
+
+impl StreamingParser for Parser {
+    type Error = Utf8::ParsingError;
+
+    fn run_parsing_loop(&mut self, input: &[u8], is_last: bool) -> Result<usize, Self::Error> {
+        self.0.run_parsing_loop(input, is_last)
+    }
+}
+
+fn compiled_trace(chunks: &[&[u8]]) -> Trace {
+    let mut parser = Parser(Utf8::Parser::new(Recorder::default()));
+
+    let result = feed_chunks(&mut parser, chunks);
+    let mut trace = parser.0.into_actions().trace;
+
+    if let Err(e) = result {
+        trace.push(TraceEvent::error(e.state, e.offset, e.byte));
+    }
+
+    trace.push(TraceEvent::pins(vec![]));
+    trace
+}
+
+#[test]
+fn unicode_and_invalid_utf8() {
+    let grammar = grammar("utf8");
+    let interpreted = |chunks: &[&[u8]]| interpreter_trace::<()>(&grammar, |_| (), chunks);
+
+    DifferentialTest::new(&grammar)
+        .run(compiled_trace, interpreted)
+        .unwrap_or_else(|e| panic!("{}", e));
+
+    // NOTE: multi-byte code points, a truncated sequence (`\xE2\x82`),
+    // a surrogate (`\xED\xA0\x80`) and a stray continuation byte (`\x80`).
+    let mut input = "straße ñandú\u{3000}日本語 𝔘x-*:".as_bytes().to_vec();
+
+    input.extend_from_slice(b"\xE2\x82 a\xED\xA0\x80b\x80\xF0\x9F\x98\x80");
+
+    ChunkInvarianceTest::new(&grammar)
+        .check(&input, compiled_trace)
+        .unwrap_or_else(|e| panic!("{}", e));
+}